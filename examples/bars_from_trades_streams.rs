@@ -0,0 +1,63 @@
+use barter_data::{
+    aggregator::bar::{BarThreshold, MarketBarAggregator},
+    exchange::{binance::spot::BinanceSpot, coinbase::Coinbase},
+    number::Number,
+    streams::Streams,
+    subscription::trade::PublicTrades,
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use futures::StreamExt;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise PublicTrades Streams for various exchanges
+    // '--> each call to StreamBuilder::subscribe() initialises a separate WebSocket connection
+    let streams = Streams::<PublicTrades>::builder()
+        .subscribe([
+            (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades),
+            (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, PublicTrades),
+        ])
+        .subscribe([
+            (Coinbase, "btc", "usd", InstrumentKind::Spot, PublicTrades),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Build a tick, volume and dollar Bar per Instrument from the joined PublicTrades stream
+    let mut tick_bars = MarketBarAggregator::new(BarThreshold::Tick(100));
+    let mut volume_bars = MarketBarAggregator::new(BarThreshold::Volume(Number::from(10)));
+    let mut dollar_bars = MarketBarAggregator::new(BarThreshold::Dollar(Number::from(1_000_000)));
+
+    let mut joined_stream = streams.join_map().await;
+
+    while let Some((exchange, trade)) = joined_stream.next().await {
+        for aggregator in [&mut tick_bars, &mut volume_bars, &mut dollar_bars] {
+            if let Some(bar) = aggregator.update(&trade) {
+                info!("Exchange: {exchange}, MarketEvent<Bar>: {bar:?}");
+            }
+        }
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
@@ -0,0 +1,68 @@
+use barter_data::{
+    aggregator::candle::{CandleAggregatorConfig, CandleClock},
+    exchange::{bitmex::Bitmex, coinbase::Coinbase},
+    streams::Streams,
+    subscription::{
+        candle::{CandleInterval, Candles},
+        trade::PublicTrades,
+    },
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use futures::StreamExt;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Coinbase does not offer native Candles, so they are aggregated from the trades channel
+    // '--> Candles are closed on exchange-time boundaries once a later trade is received
+    let candle_streams = Streams::<Candles>::builder()
+        .subscribe([
+            (Coinbase, "btc", "usd", InstrumentKind::Spot, Candles::new(CandleInterval::Minute1)),
+            (Coinbase, "eth", "usd", InstrumentKind::Spot, Candles::in_progress(CandleInterval::Minute1)),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Alternatively, aggregate any PublicTrades Streams into Candles
+    // '--> Candles are closed on wall-clock boundaries, emitting empty Candles if no trades occur
+    let trade_candle_streams = Streams::<PublicTrades>::builder()
+        .subscribe([
+            (Bitmex, "xbt", "usd", InstrumentKind::Perpetual, PublicTrades),
+        ])
+        .init()
+        .await
+        .unwrap()
+        .candles(CandleAggregatorConfig::new(CandleInterval::Minute1, CandleClock::WallClock).with_empty());
+
+    // Join all exchange Candle streams into a single stream
+    let mut joined_stream = futures::stream::select(
+        candle_streams.join_map().await,
+        trade_candle_streams.join_map().await,
+    );
+
+    while let Some((exchange, candle)) = joined_stream.next().await {
+        info!("Exchange: {exchange}, MarketEvent<Candle>: {candle:?}");
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
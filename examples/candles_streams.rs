@@ -0,0 +1,61 @@
+use barter_data::{
+    exchange::{binance::spot::BinanceSpot, ExchangeId},
+    streams::Streams,
+    subscription::candle::{CandleInterval, Candles},
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise Candles Streams for BinanceSpot only
+    // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection
+    let mut streams = Streams::<Candles>::builder()
+
+        // Closed 1 minute & 1 hour Candles share a WebSocket connection
+        .subscribe([
+            (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, Candles::new(CandleInterval::Minute1)),
+            (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, Candles::new(CandleInterval::Hour1)),
+        ])
+
+        // Opt-in to in-progress 1 minute Candle updates on a separate WebSocket connection
+        .subscribe([
+            (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, Candles::in_progress(CandleInterval::Minute1)),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Select the ExchangeId::BinanceSpot stream
+    // Notes:
+    //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
+    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    let mut binance_stream = streams
+        .select(ExchangeId::BinanceSpot)
+        .unwrap();
+
+    while let Some(candle) = binance_stream.recv().await {
+        info!("MarketEvent<Candle>: {candle:?}");
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
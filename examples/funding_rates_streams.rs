@@ -0,0 +1,73 @@
+use barter_data::{
+    exchange::{
+        binance::futures::BinanceFuturesUsd, bitmex::Bitmex, bybit::futures::BybitPerpetualsUsd,
+        gateio::perpetual::GateioPerpetualsUsd, okx::Okx,
+    },
+    streams::Streams,
+    subscription::funding::FundingRates,
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise FundingRates Streams for various perpetual exchanges
+    // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection
+    let streams = Streams::<FundingRates>::builder()
+        .subscribe([
+            (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, FundingRates),
+        ])
+        .subscribe([
+            (BybitPerpetualsUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, FundingRates),
+        ])
+        .subscribe([
+            (Okx, "btc", "usdt", InstrumentKind::Perpetual, FundingRates),
+        ])
+        .subscribe([
+            (Bitmex, "xbt", "usd", InstrumentKind::Perpetual, FundingRates),
+        ])
+        .subscribe([
+            (GateioPerpetualsUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, FundingRates),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Join all exchange FundingRates streams into a single mpsc::UnboundedReceiver
+    // Notes:
+    //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
+    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    let mut joined_stream = streams.join().await;
+
+    while let Some(funding_rate) = joined_stream.recv().await {
+        info!(
+            exchange = %funding_rate.exchange,
+            instrument = %funding_rate.instrument,
+            rate = %funding_rate.kind.rate,
+            predicted_rate = ?funding_rate.kind.predicted_rate,
+            next_funding_time = ?funding_rate.kind.next_funding_time,
+            "MarketEvent<FundingRate>",
+        );
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
@@ -0,0 +1,76 @@
+use barter_data::{
+    exchange::{
+        binance::futures::BinanceFuturesUsd, bitmex::Bitmex, bybit::futures::BybitPerpetualsUsd,
+        gateio::perpetual::GateioPerpetualsUsd, okx::Okx,
+    },
+    streams::Streams,
+    subscription::liquidation::Liquidations,
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise Liquidations Streams for various perpetual exchanges
+    // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection
+    let streams = Streams::<Liquidations>::builder()
+        .subscribe([
+            (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, Liquidations),
+        ])
+        .subscribe([
+            (Okx, "btc", "usdt", InstrumentKind::Perpetual, Liquidations),
+            (Okx, "eth", "usdt", InstrumentKind::Perpetual, Liquidations),
+        ])
+        .subscribe([
+            (BybitPerpetualsUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, Liquidations),
+        ])
+        .subscribe([
+            (Bitmex, "xbt", "usd", InstrumentKind::Perpetual, Liquidations),
+        ])
+        .subscribe([
+            (GateioPerpetualsUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, Liquidations),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Join all exchange Liquidations streams into a single mpsc::UnboundedReceiver
+    // Notes:
+    //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
+    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    let mut joined_stream = streams.join().await;
+
+    while let Some(liquidation) = joined_stream.recv().await {
+        info!(
+            exchange = %liquidation.exchange,
+            instrument = %liquidation.instrument,
+            side = ?liquidation.kind.side,
+            price = %liquidation.kind.price,
+            quantity = %liquidation.kind.quantity,
+            notional = %liquidation.kind.notional(),
+            status = ?liquidation.kind.status,
+            "MarketEvent<Liquidation>",
+        );
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
@@ -0,0 +1,93 @@
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    exchange::{
+        binance::futures::BinanceFuturesUsd, bybit::futures::BybitPerpetualsUsd,
+        gateio::perpetual::GateioPerpetualsUsd, okx::Okx,
+    },
+    streams::Streams,
+    subscription::{funding::FundingRates, mark_price::MarkPrices},
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use tokio_stream::StreamExt;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise MarketEvent<DataKind> Streams combining MarkPrices & FundingRates
+    let streams: Streams<MarketEvent<DataKind>> = Streams::builder_multi()
+
+        // Add MarkPrices Streams for various perpetual exchanges
+        .add(Streams::<MarkPrices>::builder()
+            .subscribe([
+                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, MarkPrices),
+            ])
+            .subscribe([
+                (BybitPerpetualsUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, MarkPrices),
+            ])
+            .subscribe([
+                (Okx, "btc", "usdt", InstrumentKind::Perpetual, MarkPrices),
+            ])
+            .subscribe([
+                (GateioPerpetualsUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, MarkPrices),
+            ])
+        )
+
+        // Add FundingRates Streams for the same perpetual exchanges
+        .add(Streams::<FundingRates>::builder()
+            .subscribe([
+                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, FundingRates),
+            ])
+            .subscribe([
+                (Okx, "btc", "usdt", InstrumentKind::Perpetual, FundingRates),
+            ])
+        )
+        .init()
+        .await
+        .unwrap();
+
+    // Join all exchange Streams into a single tokio_stream::StreamMap
+    // Notes:
+    //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
+    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    let mut joined_stream = streams.join_map().await;
+
+    while let Some((exchange, event)) = joined_stream.next().await {
+        match event.kind {
+            DataKind::MarkPrice(mark_price) => info!(
+                %exchange,
+                mark = %mark_price.mark,
+                index = ?mark_price.index,
+                estimated_settle = ?mark_price.estimated_settle,
+                "MarketEvent<MarkPrice>",
+            ),
+            DataKind::FundingRate(funding_rate) => info!(
+                %exchange,
+                rate = %funding_rate.rate,
+                next_funding_time = ?funding_rate.next_funding_time,
+                "MarketEvent<FundingRate>",
+            ),
+            _ => {}
+        }
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
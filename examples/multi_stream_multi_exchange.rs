@@ -59,10 +59,10 @@ async fn main() {
         // Add OrderBooksL2 Stream for various exchanges
         .add(Streams::<OrderBooksL2>::builder()
             .subscribe([
-                (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBooksL2),
+                (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
             ])
             .subscribe([
-                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, OrderBooksL2),
+                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, OrderBooksL2::default()),
             ])
         )
         .init()
@@ -0,0 +1,70 @@
+use barter_data::{
+    exchange::{
+        binance::futures::BinanceFuturesUsd, bitmex::Bitmex, bybit::futures::BybitPerpetualsUsd,
+        okx::Okx,
+    },
+    streams::Streams,
+    subscription::open_interest::OpenInterests,
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise OpenInterests Streams for various derivative exchanges
+    // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection, or a
+    //      separate HTTP polling task for BinanceFuturesUsd
+    let streams = Streams::<OpenInterests>::builder()
+        .subscribe([
+            (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, OpenInterests),
+        ])
+        .subscribe([
+            (BybitPerpetualsUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, OpenInterests),
+        ])
+        .subscribe([
+            (Okx, "btc", "usdt", InstrumentKind::Perpetual, OpenInterests),
+        ])
+        .subscribe([
+            (Bitmex, "xbt", "usd", InstrumentKind::Perpetual, OpenInterests),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Join all exchange OpenInterests streams into a single mpsc::UnboundedReceiver
+    // Notes:
+    //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
+    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    let mut joined_stream = streams.join().await;
+
+    while let Some(open_interest) = joined_stream.recv().await {
+        info!(
+            exchange = %open_interest.exchange,
+            instrument = %open_interest.instrument,
+            contracts = %open_interest.kind.contracts,
+            notional = ?open_interest.kind.notional,
+            "MarketEvent<OpenInterest>",
+        );
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
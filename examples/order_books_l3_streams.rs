@@ -0,0 +1,79 @@
+use barter_data::{
+    exchange::{bitfinex::Bitfinex, coinbase::Coinbase},
+    streams::Streams,
+    subscription::book::{OrderBookL3Event, OrderBooksL3},
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use std::collections::HashMap;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise OrderBooksL3 Streams for Coinbase & Bitfinex
+    // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection
+    let streams = Streams::<OrderBooksL3>::builder()
+        .subscribe([
+            (Coinbase, "btc", "usd", InstrumentKind::Spot, OrderBooksL3),
+        ])
+        .subscribe([
+            (Bitfinex, "btc", "usd", InstrumentKind::Spot, OrderBooksL3),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Join all exchange OrderBooksL3 streams into a single mpsc::UnboundedReceiver
+    // Notes:
+    //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
+    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    let mut joined_stream = streams.join().await;
+
+    // Maintain a local OrderBookL3 per exchange instrument by applying each OrderBookL3Event
+    let mut books = HashMap::new();
+
+    while let Some(event) = joined_stream.recv().await {
+        let key = (event.exchange.clone(), event.instrument.clone());
+        let book = match event.kind {
+            OrderBookL3Event::Snapshot(snapshot) => {
+                books.insert(key, snapshot);
+                continue;
+            }
+            OrderBookL3Event::Update(delta) => {
+                let book = books.entry(key).or_default();
+                book.apply(delta);
+                book
+            }
+        };
+
+        // Aggregate the OrderBookL3 into a level 2 OrderBook
+        let order_book_l2 = book.l2();
+        info!(
+            exchange = %event.exchange,
+            orders = book.orders.len(),
+            best_bid = ?order_book_l2.bids.best(),
+            best_ask = ?order_book_l2.asks.best(),
+            "MarketEvent<OrderBookL3Event>",
+        );
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
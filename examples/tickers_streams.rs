@@ -0,0 +1,81 @@
+use barter_data::{
+    exchange::{
+        binance::spot::BinanceSpot, bitfinex::Bitfinex, bybit::spot::BybitSpot, coinbase::Coinbase,
+        gateio::spot::GateioSpot, kraken::Kraken, okx::Okx,
+    },
+    streams::Streams,
+    subscription::ticker::Tickers,
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise Tickers Streams for various exchanges
+    // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection
+    let streams = Streams::<Tickers>::builder()
+        .subscribe([
+            (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, Tickers),
+        ])
+        .subscribe([
+            (Kraken, "xbt", "usd", InstrumentKind::Spot, Tickers),
+        ])
+        .subscribe([
+            (Coinbase, "btc", "usd", InstrumentKind::Spot, Tickers),
+        ])
+        .subscribe([
+            (Okx, "btc", "usdt", InstrumentKind::Spot, Tickers),
+        ])
+        .subscribe([
+            (BybitSpot::default(), "btc", "usdt", InstrumentKind::Spot, Tickers),
+        ])
+        .subscribe([
+            (Bitfinex, "btc", "usd", InstrumentKind::Spot, Tickers),
+        ])
+        .subscribe([
+            (GateioSpot::default(), "btc", "usdt", InstrumentKind::Spot, Tickers),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Join all exchange Tickers streams into a single mpsc::UnboundedReceiver
+    // Notes:
+    //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
+    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    let mut joined_stream = streams.join().await;
+
+    while let Some(ticker) = joined_stream.recv().await {
+        info!(
+            exchange = %ticker.exchange,
+            instrument = %ticker.instrument,
+            last = %ticker.kind.last,
+            high = %ticker.kind.high,
+            low = %ticker.kind.low,
+            volume_base = %ticker.kind.volume_base,
+            change_percent = ?ticker.kind.change_percent,
+            "MarketEvent<Ticker>",
+        );
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
@@ -0,0 +1,366 @@
+use crate::{
+    event::MarketEvent,
+    number::{Number, ZERO},
+    subscription::{candle::Candle, trade::PublicTrade},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Threshold that triggers the close of an information-driven [`Bar`].
+///
+/// A [`Bar`] is closed by the trade that reaches the threshold, and any excess is not carried
+/// over to the next [`Bar`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BarThreshold {
+    /// Close a [`Bar`] every N trades.
+    Tick(u64),
+    /// Close a [`Bar`] every N base asset units traded.
+    Volume(Number),
+    /// Close a [`Bar`] every N quote asset units (price * amount) traded.
+    Dollar(Number),
+}
+
+/// Information-driven OHLCV [`Bar`] built from [`PublicTrade`]s.
+///
+/// The OHLCV data is a [`Candle`] without an interval, alongside the [`BarThreshold`] that
+/// triggered the [`Bar`] close.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Bar {
+    pub candle: Candle,
+    pub threshold: BarThreshold,
+}
+
+impl Bar {
+    /// Determine if this [`Bar`] has reached it's [`BarThreshold`], given the quote asset units
+    /// traded within it.
+    fn is_complete(&self, notional: Number) -> bool {
+        match self.threshold {
+            BarThreshold::Tick(ticks) => self.candle.trade_count >= ticks,
+            BarThreshold::Volume(volume) => self.candle.volume >= volume,
+            BarThreshold::Dollar(dollar) => notional >= dollar,
+        }
+    }
+}
+
+/// Aggregates the [`PublicTrade`]s of a single [`Instrument`] into information-driven [`Bar`]s.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BarAggregator {
+    threshold: BarThreshold,
+    /// [`Bar`] that is currently forming.
+    bar: Option<Bar>,
+    /// Quote asset units traded within the forming [`Bar`].
+    notional: Number,
+}
+
+impl BarAggregator {
+    /// Construct a new [`Self`] that closes [`Bar`]s using the provided [`BarThreshold`].
+    pub fn new(threshold: BarThreshold) -> Self {
+        Self {
+            threshold,
+            bar: None,
+            notional: ZERO,
+        }
+    }
+
+    /// Return the [`Bar`] that is currently forming, if any trades have been received since the
+    /// last [`Bar`] was closed.
+    pub fn current(&self) -> Option<&Bar> {
+        self.bar.as_ref()
+    }
+
+    /// Update the [`BarAggregator`] with a [`PublicTrade`] that occurred at the provided time,
+    /// returning the closed [`Bar`] if the trade reached the [`BarThreshold`].
+    pub fn update(&mut self, time: DateTime<Utc>, trade: &PublicTrade) -> Option<Bar> {
+        let bar = self.bar.get_or_insert(Bar {
+            candle: Candle {
+                interval: None,
+                open_time: time,
+                close_time: time,
+                open: trade.price,
+                high: trade.price,
+                low: trade.price,
+                close: trade.price,
+                volume: ZERO,
+                trade_count: 0,
+            },
+            threshold: self.threshold,
+        });
+
+        let candle = &mut bar.candle;
+        candle.close_time = time;
+        candle.high = candle.high.max(trade.price);
+        candle.low = candle.low.min(trade.price);
+        candle.close = trade.price;
+        candle.volume += trade.amount;
+        candle.trade_count += 1;
+        self.notional += trade.price * trade.amount;
+
+        if !bar.is_complete(self.notional) {
+            return None;
+        }
+
+        self.notional = ZERO;
+        self.bar.take()
+    }
+}
+
+/// Aggregates [`MarketEvent<PublicTrade>`]s from any number of [`Exchange`] [`Instrument`]s into
+/// [`MarketEvent<Bar>`]s, maintaining a [`BarAggregator`] per [`Exchange`] [`Instrument`].
+///
+/// Suitable for consuming the joined output of
+/// [`Streams::join_map`](crate::streams::Streams::join_map).
+#[derive(Clone, PartialEq, Debug)]
+pub struct MarketBarAggregator {
+    threshold: BarThreshold,
+    aggregators: HashMap<(Exchange, Instrument), BarAggregator>,
+}
+
+impl MarketBarAggregator {
+    /// Construct a new [`Self`] that closes [`Bar`]s using the provided [`BarThreshold`].
+    pub fn new(threshold: BarThreshold) -> Self {
+        Self {
+            threshold,
+            aggregators: HashMap::new(),
+        }
+    }
+
+    /// Update the [`BarAggregator`] associated with the [`MarketEvent<PublicTrade>`]
+    /// [`Exchange`] [`Instrument`], returning the closed [`MarketEvent<Bar>`] if the trade reached
+    /// the [`BarThreshold`].
+    pub fn update(&mut self, trade: &MarketEvent<PublicTrade>) -> Option<MarketEvent<Bar>> {
+        let threshold = self.threshold;
+        let bar = self
+            .aggregators
+            .entry((trade.exchange.clone(), trade.instrument.clone()))
+            .or_insert_with(|| BarAggregator::new(threshold))
+            .update(trade.exchange_time, &trade.kind)?;
+
+        Some(MarketEvent {
+            exchange_time: bar.candle.close_time,
+            received_time: trade.received_time,
+            exchange: trade.exchange.clone(),
+            instrument: trade.instrument.clone(),
+            kind: bar,
+        })
+    }
+
+    /// Return the [`Bar`] that is currently forming for the provided [`Exchange`]
+    /// [`Instrument`], if any.
+    pub fn current(&self, exchange: &Exchange, instrument: &Instrument) -> Option<&Bar> {
+        self.aggregators
+            .get(&(exchange.clone(), instrument.clone()))
+            .and_then(BarAggregator::current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::TimeZone;
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(secs, 0).unwrap()
+    }
+
+    fn trade(price: Number, amount: Number) -> PublicTrade {
+        PublicTrade {
+            id: "id".to_string(),
+            price,
+            amount,
+            side: Side::Buy,
+        }
+    }
+
+    fn bar(
+        threshold: BarThreshold,
+        times: (i64, i64),
+        ohlc: [Number; 4],
+        volume: Number,
+        trade_count: u64,
+    ) -> Bar {
+        Bar {
+            candle: Candle {
+                interval: None,
+                open_time: time(times.0),
+                close_time: time(times.1),
+                open: ohlc[0],
+                high: ohlc[1],
+                low: ohlc[2],
+                close: ohlc[3],
+                volume,
+                trade_count,
+            },
+            threshold,
+        }
+    }
+
+    #[test]
+    fn test_bar_aggregator_update() {
+        struct TestCase {
+            threshold: BarThreshold,
+            trades: Vec<(i64, PublicTrade)>,
+            expected: Vec<Bar>,
+            expected_current: Option<Bar>,
+        }
+
+        let tick = BarThreshold::Tick(2);
+        let volume = BarThreshold::Volume(num!(3.0));
+        let dollar = BarThreshold::Dollar(num!(1000.0));
+
+        let tests = vec![
+            TestCase {
+                // TC0: tick bar closes every N trades
+                threshold: tick,
+                trades: vec![
+                    (0, trade(num!(100.0), num!(1.0))),
+                    (1, trade(num!(110.0), num!(1.0))),
+                    (2, trade(num!(90.0), num!(1.0))),
+                ],
+                expected: vec![bar(
+                    tick,
+                    (0, 1),
+                    [num!(100.0), num!(110.0), num!(100.0), num!(110.0)],
+                    num!(2.0),
+                    2,
+                )],
+                expected_current: Some(bar(
+                    tick,
+                    (2, 2),
+                    [num!(90.0), num!(90.0), num!(90.0), num!(90.0)],
+                    num!(1.0),
+                    1,
+                )),
+            },
+            TestCase {
+                // TC1: volume bar closes on the trade that reaches the threshold
+                threshold: volume,
+                trades: vec![
+                    (0, trade(num!(100.0), num!(1.0))),
+                    (1, trade(num!(95.0), num!(1.5))),
+                    (2, trade(num!(105.0), num!(2.0))),
+                ],
+                expected: vec![bar(
+                    volume,
+                    (0, 2),
+                    [num!(100.0), num!(105.0), num!(95.0), num!(105.0)],
+                    num!(4.5),
+                    3,
+                )],
+                expected_current: None,
+            },
+            TestCase {
+                // TC2: dollar bar closes once the traded notional reaches the threshold
+                threshold: dollar,
+                trades: vec![
+                    (0, trade(num!(100.0), num!(5.0))),
+                    (1, trade(num!(100.0), num!(4.0))),
+                    (2, trade(num!(200.0), num!(1.0))),
+                    (3, trade(num!(200.0), num!(1.0))),
+                ],
+                expected: vec![bar(
+                    dollar,
+                    (0, 2),
+                    [num!(100.0), num!(200.0), num!(100.0), num!(200.0)],
+                    num!(10.0),
+                    3,
+                )],
+                expected_current: Some(bar(
+                    dollar,
+                    (3, 3),
+                    [num!(200.0), num!(200.0), num!(200.0), num!(200.0)],
+                    num!(1.0),
+                    1,
+                )),
+            },
+            TestCase {
+                // TC3: single trade exceeding the threshold closes a bar on it's own
+                threshold: volume,
+                trades: vec![
+                    (0, trade(num!(100.0), num!(10.0))),
+                    (1, trade(num!(101.0), num!(1.0))),
+                ],
+                expected: vec![bar(
+                    volume,
+                    (0, 0),
+                    [num!(100.0), num!(100.0), num!(100.0), num!(100.0)],
+                    num!(10.0),
+                    1,
+                )],
+                expected_current: Some(bar(
+                    volume,
+                    (1, 1),
+                    [num!(101.0), num!(101.0), num!(101.0), num!(101.0)],
+                    num!(1.0),
+                    1,
+                )),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut aggregator = BarAggregator::new(test.threshold);
+
+            let actual = test
+                .trades
+                .iter()
+                .filter_map(|(secs, trade)| aggregator.update(time(*secs), trade))
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+            assert_eq!(
+                aggregator.current(),
+                test.expected_current.as_ref(),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_market_bar_aggregator_per_instrument() {
+        let threshold = BarThreshold::Tick(2);
+        let mut aggregator = MarketBarAggregator::new(threshold);
+
+        let event = |instrument: &Instrument, secs, price| MarketEvent {
+            exchange_time: time(secs),
+            received_time: time(secs),
+            exchange: Exchange::from("exchange"),
+            instrument: instrument.clone(),
+            kind: trade(price, num!(1.0)),
+        };
+
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        // Interleaved trades must not close a Bar until each Instrument reaches the threshold
+        assert_eq!(aggregator.update(&event(&btc, 0, num!(100.0))), None);
+        assert_eq!(aggregator.update(&event(&eth, 1, num!(10.0))), None);
+
+        let actual = aggregator.update(&event(&btc, 2, num!(101.0))).unwrap();
+        assert_eq!(actual.instrument, btc);
+        assert_eq!(
+            actual.kind,
+            bar(
+                threshold,
+                (0, 2),
+                [num!(100.0), num!(101.0), num!(100.0), num!(101.0)],
+                num!(2.0),
+                2
+            )
+        );
+        assert_eq!(
+            aggregator.current(&Exchange::from("exchange"), &eth),
+            Some(&bar(
+                threshold,
+                (1, 1),
+                [num!(10.0), num!(10.0), num!(10.0), num!(10.0)],
+                num!(1.0),
+                1
+            ))
+        );
+    }
+}
@@ -0,0 +1,515 @@
+use crate::exchange::ExchangeId;
+use crate::{
+    event::MarketEvent,
+    number::{total_cmp, Number, ZERO},
+    subscription::book::{Level, LevelKey, OrderBook, OrderBookL1, OrderBookSide},
+    transformer::book::OrderBookL1Synthesiser,
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, symbol::Symbol},
+    Exchange, Side,
+};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::UnboundedReceiverStream, StreamMap};
+
+/// Consolidated view of the latest [`OrderBook`] of one base/quote pair across many venues.
+///
+/// Each [`Venue`] [`OrderBook`] is replaced whenever a new [`MarketEvent<OrderBook>`] is received
+/// from that [`Exchange`] [`InstrumentKind`], and the consolidated [`ConsolidatedLevel`]s are
+/// aggregated by price on demand, carrying the amount contributed by each [`Venue`].
+///
+/// ### Notes
+/// Venue [`OrderBook`]s are matched by base & quote only, so the same pair of different
+/// [`InstrumentKind`]s (eg/ spot & perpetual) can be consolidated, with each [`InstrumentKind`] of
+/// an [`Exchange`] being a distinct [`Venue`].
+///
+/// Venue [`OrderBook`]s are shared via an [`Arc`], so cloning a [`ConsolidatedOrderBook`] is
+/// cheap regardless of [`OrderBook`] depth.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct ConsolidatedOrderBook {
+    pub base: Symbol,
+    pub quote: Symbol,
+    pub books: BTreeMap<Venue, Arc<OrderBook>>,
+}
+
+/// Venue of a [`ConsolidatedOrderBook`], identifying the [`Exchange`] & [`InstrumentKind`] of a
+/// venue [`OrderBook`].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize)]
+pub struct Venue {
+    pub exchange: Exchange,
+    pub kind: InstrumentKind,
+}
+
+impl<E> From<(E, InstrumentKind)> for Venue
+where
+    E: Into<Exchange>,
+{
+    fn from((exchange, kind): (E, InstrumentKind)) -> Self {
+        Self {
+            exchange: exchange.into(),
+            kind,
+        }
+    }
+}
+
+/// Consolidated [`OrderBook`] price level aggregating the amount of every venue at that price.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ConsolidatedLevel {
+    pub price: Number,
+    pub amount: Number,
+    pub contributions: Vec<VenueContribution>,
+}
+
+/// Amount contributed to a [`ConsolidatedLevel`] by a single venue.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct VenueContribution {
+    pub venue: Venue,
+    pub amount: Number,
+}
+
+/// Best [`Level`] of one side of a [`ConsolidatedOrderBook`], and the venue offering it.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct VenueLevel {
+    pub venue: Venue,
+    pub level: Level,
+}
+
+/// Crossed [`ConsolidatedOrderBook`], where the best bid of one venue is priced above the best
+/// ask of another venue.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct CrossedMarket {
+    pub best_bid: VenueLevel,
+    pub best_ask: VenueLevel,
+}
+
+impl ConsolidatedOrderBook {
+    /// Construct a new empty [`Self`] for the provided base & quote [`Symbol`]s.
+    pub fn new<S>(base: S, quote: S) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        Self {
+            base: base.into(),
+            quote: quote.into(),
+            books: BTreeMap::new(),
+        }
+    }
+
+    /// Replace the [`Venue`] [`OrderBook`] with the one from the provided
+    /// [`MarketEvent<OrderBook>`].
+    ///
+    /// Returns false if the [`MarketEvent`] is not for this base/quote pair, and is ignored.
+    pub fn update(&mut self, event: MarketEvent<OrderBook>) -> bool {
+        if event.instrument.base != self.base || event.instrument.quote != self.quote {
+            return false;
+        }
+
+        let venue = Venue::from((event.exchange, event.instrument.kind));
+        self.books.insert(venue, Arc::new(event.kind));
+        true
+    }
+
+    /// Remove the [`OrderBook`] of the provided [`Venue`] (eg/ after it disconnects).
+    pub fn remove(&mut self, venue: &Venue) -> Option<Arc<OrderBook>> {
+        self.books.remove(venue)
+    }
+
+    /// Return the most recent `last_update_time` of all venue [`OrderBook`]s.
+    pub fn last_update_time(&self) -> Option<DateTime<Utc>> {
+        self.books.values().map(|book| book.last_update_time).max()
+    }
+
+    /// Return the highest bid [`Level`] across all venues.
+    pub fn best_bid(&self) -> Option<VenueLevel> {
+        self.best(Side::Buy)
+    }
+
+    /// Return the lowest ask [`Level`] across all venues.
+    pub fn best_ask(&self) -> Option<VenueLevel> {
+        self.best(Side::Sell)
+    }
+
+    /// Determine if the [`ConsolidatedOrderBook`] is crossed, ie/ the best bid across all venues
+    /// is priced above the best ask across all venues.
+    ///
+    /// Returns the crossing best bid & ask if so.
+    pub fn crossed(&self) -> Option<CrossedMarket> {
+        let (best_bid, best_ask) = (self.best_bid()?, self.best_ask()?);
+
+        (best_bid.level.price > best_ask.level.price)
+            .then_some(CrossedMarket { best_bid, best_ask })
+    }
+
+    /// Generate the consolidated bid [`ConsolidatedLevel`]s, best first.
+    pub fn bids(&self) -> Vec<ConsolidatedLevel> {
+        self.consolidate(Side::Buy)
+    }
+
+    /// Generate the consolidated ask [`ConsolidatedLevel`]s, best first.
+    pub fn asks(&self) -> Vec<ConsolidatedLevel> {
+        self.consolidate(Side::Sell)
+    }
+
+    /// Return the [`OrderBookSide`] of the provided [`Side`] for every [`Venue`].
+    fn sides(&self, side: Side) -> impl Iterator<Item = (&Venue, &OrderBookSide)> {
+        self.books.iter().map(move |(venue, book)| match side {
+            Side::Buy => (venue, &book.bids),
+            Side::Sell => (venue, &book.asks),
+        })
+    }
+
+    /// Return the best [`Level`] of the provided [`Side`] across all venues. If venues share the
+    /// best price, the venue offering the largest amount is returned.
+    fn best(&self, side: Side) -> Option<VenueLevel> {
+        self.sides(side)
+            .filter_map(|(venue, book_side)| {
+                book_side.best().map(|level| VenueLevel {
+                    venue: venue.clone(),
+                    level: *level,
+                })
+            })
+            .min_by(|a, b| {
+                LevelKey::new(side, a.level.price)
+                    .cmp(&LevelKey::new(side, b.level.price))
+                    .then_with(|| total_cmp(&b.level.amount, &a.level.amount))
+            })
+    }
+
+    /// Aggregate the [`Level`]s of the provided [`Side`] of every venue by price, best first.
+    fn consolidate(&self, side: Side) -> Vec<ConsolidatedLevel> {
+        let mut levels = BTreeMap::<LevelKey, ConsolidatedLevel>::new();
+
+        for (venue, book_side) in self.sides(side) {
+            for level in book_side.levels() {
+                let consolidated = levels
+                    .entry(LevelKey::new(side, level.price))
+                    .or_insert_with(|| ConsolidatedLevel {
+                        price: level.price,
+                        amount: ZERO,
+                        contributions: vec![],
+                    });
+
+                consolidated.amount += level.amount;
+                consolidated.contributions.push(VenueContribution {
+                    venue: venue.clone(),
+                    amount: level.amount,
+                });
+            }
+        }
+
+        levels.into_values().collect()
+    }
+}
+
+/// Consolidate the [`MarketEvent<OrderBook>`] streams of many exchanges into
+/// [`ConsolidatedOrderBook`]s for the provided base/quote pair.
+///
+/// The latest [`ConsolidatedOrderBook`] is yielded after every venue update, via the
+/// [`mpsc::UnboundedSender`] of the [`ExchangeId`] that sent the update. Exchanges whose
+/// [`mpsc::UnboundedReceiver`] has been dropped still contribute to the [`ConsolidatedOrderBook`].
+pub async fn consolidate_books(
+    base: Symbol,
+    quote: Symbol,
+    mut book_streams: StreamMap<ExchangeId, UnboundedReceiverStream<MarketEvent<OrderBook>>>,
+    mut consolidated_txs: HashMap<ExchangeId, mpsc::UnboundedSender<ConsolidatedOrderBook>>,
+) {
+    let mut consolidated = ConsolidatedOrderBook::new(base, quote);
+
+    while let Some((exchange, event)) = book_streams.next().await {
+        if !consolidated.update(event) {
+            continue;
+        }
+
+        let Some(consolidated_tx) = consolidated_txs.get(&exchange) else {
+            continue;
+        };
+
+        if consolidated_tx.send(consolidated.clone()).is_err() {
+            consolidated_txs.remove(&exchange);
+            if consolidated_txs.is_empty() {
+                break;
+            }
+        }
+    }
+}
+
+/// Synthesise a stream of [`MarketEvent<OrderBook>`] into a stream of
+/// [`MarketEvent<OrderBookL1>`], only yielding when the best bid or ask of an [`Exchange`]
+/// [`Instrument`](barter_integration::model::instrument::Instrument) changes.
+pub async fn synthesise_books_l1(
+    mut book_rx: mpsc::UnboundedReceiver<MarketEvent<OrderBook>>,
+    l1_tx: mpsc::UnboundedSender<MarketEvent<OrderBookL1>>,
+) {
+    let mut synthesiser = OrderBookL1Synthesiser::default();
+
+    while let Some(event) = book_rx.recv().await {
+        let Some(l1) = synthesiser.synthesise(event) else {
+            continue;
+        };
+
+        if l1_tx.send(l1).is_err() {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+    use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+
+    fn event(exchange: &'static str, bids: Vec<Level>, asks: Vec<Level>) -> MarketEvent<OrderBook> {
+        MarketEvent {
+            exchange_time: Default::default(),
+            received_time: Default::default(),
+            exchange: Exchange::from(exchange),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: OrderBook {
+                last_update_time: Default::default(),
+                bids: OrderBookSide::new(Side::Buy, bids),
+                asks: OrderBookSide::new(Side::Sell, asks),
+            },
+        }
+    }
+
+    fn book() -> ConsolidatedOrderBook {
+        let mut book = ConsolidatedOrderBook::new("btc", "usdt");
+        book.update(event(
+            "binance_spot",
+            vec![
+                Level::new(num!(100), num!(1)),
+                Level::new(num!(99), num!(2)),
+            ],
+            vec![
+                Level::new(num!(102), num!(1)),
+                Level::new(num!(103), num!(2)),
+            ],
+        ));
+        book.update(event(
+            "kraken",
+            vec![
+                Level::new(num!(100), num!(3)),
+                Level::new(num!(98), num!(1)),
+            ],
+            vec![
+                Level::new(num!(101), num!(2)),
+                Level::new(num!(103), num!(1)),
+            ],
+        ));
+        book
+    }
+
+    #[test]
+    fn test_update() {
+        struct TestCase {
+            input: MarketEvent<OrderBook>,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: matching base/quote
+                input: event("okx", vec![], vec![]),
+                expected: true,
+            },
+            TestCase {
+                // TC1: different base/quote is ignored
+                input: MarketEvent {
+                    instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+                    ..event("okx", vec![], vec![])
+                },
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut book = ConsolidatedOrderBook::new("btc", "usdt");
+            assert_eq!(book.update(test.input), test.expected, "TC{index} failed");
+            assert_eq!(book.books.len(), test.expected as usize, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_update_keyed_by_venue() {
+        struct TestCase {
+            input: MarketEvent<OrderBook>,
+            expected: Vec<Venue>,
+        }
+
+        let perpetual = |exchange| MarketEvent {
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            ..event(exchange, vec![], vec![])
+        };
+
+        let mut book = ConsolidatedOrderBook::new("btc", "usdt");
+
+        let tests = vec![
+            TestCase {
+                // TC0: first venue
+                input: event("okx", vec![], vec![]),
+                expected: vec![Venue::from(("okx", InstrumentKind::Spot))],
+            },
+            TestCase {
+                // TC1: same exchange w/ different InstrumentKind is a distinct venue
+                input: perpetual("okx"),
+                expected: vec![
+                    Venue::from(("okx", InstrumentKind::Spot)),
+                    Venue::from(("okx", InstrumentKind::Perpetual)),
+                ],
+            },
+            TestCase {
+                // TC2: same exchange & InstrumentKind replaces the existing venue OrderBook
+                input: perpetual("okx"),
+                expected: vec![
+                    Venue::from(("okx", InstrumentKind::Spot)),
+                    Venue::from(("okx", InstrumentKind::Perpetual)),
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert!(book.update(test.input), "TC{index} failed");
+            assert_eq!(
+                book.books.keys().cloned().collect::<Vec<_>>(),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_best_bid_and_ask() {
+        let book = book();
+
+        // Best bid price shared across venues, so venue with largest amount is best
+        assert_eq!(
+            book.best_bid(),
+            Some(VenueLevel {
+                venue: Venue::from(("kraken", InstrumentKind::Spot)),
+                level: Level::new(num!(100), num!(3)),
+            })
+        );
+        assert_eq!(
+            book.best_ask(),
+            Some(VenueLevel {
+                venue: Venue::from(("kraken", InstrumentKind::Spot)),
+                level: Level::new(num!(101), num!(2)),
+            })
+        );
+        assert_eq!(ConsolidatedOrderBook::new("btc", "usdt").best_bid(), None);
+    }
+
+    #[test]
+    fn test_consolidated_levels() {
+        let book = book();
+
+        assert_eq!(
+            book.bids(),
+            vec![
+                ConsolidatedLevel {
+                    price: num!(100.0),
+                    amount: num!(4.0),
+                    contributions: vec![
+                        VenueContribution {
+                            venue: Venue::from(("binance_spot", InstrumentKind::Spot)),
+                            amount: num!(1.0),
+                        },
+                        VenueContribution {
+                            venue: Venue::from(("kraken", InstrumentKind::Spot)),
+                            amount: num!(3.0),
+                        },
+                    ],
+                },
+                ConsolidatedLevel {
+                    price: num!(99.0),
+                    amount: num!(2.0),
+                    contributions: vec![VenueContribution {
+                        venue: Venue::from(("binance_spot", InstrumentKind::Spot)),
+                        amount: num!(2.0),
+                    }],
+                },
+                ConsolidatedLevel {
+                    price: num!(98.0),
+                    amount: num!(1.0),
+                    contributions: vec![VenueContribution {
+                        venue: Venue::from(("kraken", InstrumentKind::Spot)),
+                        amount: num!(1.0),
+                    }],
+                },
+            ]
+        );
+
+        let asks = book.asks();
+        assert_eq!(
+            asks.iter()
+                .map(|level| (level.price, level.amount))
+                .collect::<Vec<_>>(),
+            vec![
+                (num!(101.0), num!(2.0)),
+                (num!(102.0), num!(1.0)),
+                (num!(103.0), num!(3.0))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_crossed() {
+        struct TestCase {
+            input: ConsolidatedOrderBook,
+            expected: Option<CrossedMarket>,
+        }
+
+        let mut crossed = book();
+        crossed.update(event(
+            "okx",
+            vec![Level::new(num!(102), num!(5))],
+            vec![Level::new(num!(104), num!(1))],
+        ));
+
+        let mut locked = book();
+        locked.update(event(
+            "okx",
+            vec![Level::new(num!(101), num!(5))],
+            vec![Level::new(num!(104), num!(1))],
+        ));
+
+        let tests = vec![
+            TestCase {
+                // TC0: best bid below best ask across venues
+                input: book(),
+                expected: None,
+            },
+            TestCase {
+                // TC1: best bid equal to best ask across venues is locked, not crossed
+                input: locked,
+                expected: None,
+            },
+            TestCase {
+                // TC2: best bid above best ask across venues
+                input: crossed,
+                expected: Some(CrossedMarket {
+                    best_bid: VenueLevel {
+                        venue: Venue::from(("okx", InstrumentKind::Spot)),
+                        level: Level::new(num!(102), num!(5)),
+                    },
+                    best_ask: VenueLevel {
+                        venue: Venue::from(("kraken", InstrumentKind::Spot)),
+                        level: Level::new(num!(101), num!(2)),
+                    },
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(test.input.crossed(), test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,506 @@
+use crate::{
+    event::MarketEvent,
+    number::{Number, ZERO},
+    subscription::{
+        candle::{Candle, CandleInterval},
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// Clock used to assign [`PublicTrade`]s to [`CandleInterval`] periods.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum CandleClock {
+    /// Assign trades using the [`MarketEvent`] `exchange_time`. A [`Candle`] is closed once a
+    /// trade belonging to a later period is received.
+    #[default]
+    Exchange,
+    /// Assign trades using the [`MarketEvent`] `received_time`. A [`Candle`] is closed as soon
+    /// as the local wall-clock passes it's close time, even if no further trades are received.
+    WallClock,
+}
+
+/// Configuration for a [`CandleAggregator`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CandleAggregatorConfig {
+    pub interval: CandleInterval,
+    pub clock: CandleClock,
+    /// Emit empty [`Candle`]s (zero volume, OHLC equal to the previous close) for periods that
+    /// contain no trades.
+    pub emit_empty: bool,
+}
+
+impl CandleAggregatorConfig {
+    /// Construct a new [`Self`] using the provided [`CandleInterval`] and [`CandleClock`] that
+    /// does not emit empty [`Candle`]s.
+    pub fn new(interval: CandleInterval, clock: CandleClock) -> Self {
+        Self {
+            interval,
+            clock,
+            emit_empty: false,
+        }
+    }
+
+    /// Configure [`Self`] to emit empty [`Candle`]s for periods that contain no trades.
+    pub fn with_empty(self) -> Self {
+        Self {
+            emit_empty: true,
+            ..self
+        }
+    }
+}
+
+/// Aggregates the [`PublicTrade`]s of a single [`Instrument`] into [`Candle`]s.
+///
+/// ### Notes
+/// - Empty [`Candle`]s are only emitted after the first trade has been received, since there is
+///   no price to open them at before then.
+/// - Trades belonging to a period that has already been closed are ignored.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CandleAggregator {
+    config: CandleAggregatorConfig,
+    /// Open time of the earliest period that has not yet been closed.
+    cursor: Option<DateTime<Utc>>,
+    /// [`Candle`] that is currently forming.
+    candle: Option<Candle>,
+    /// Close price of the last closed [`Candle`], used to open empty [`Candle`]s.
+    last_close: Option<Number>,
+}
+
+impl CandleAggregator {
+    /// Construct a new [`Self`] using the provided [`CandleAggregatorConfig`].
+    pub fn new(config: CandleAggregatorConfig) -> Self {
+        Self {
+            config,
+            cursor: None,
+            candle: None,
+            last_close: None,
+        }
+    }
+
+    /// Return the [`CandleAggregatorConfig`] this [`CandleAggregator`] was constructed with.
+    pub fn config(&self) -> &CandleAggregatorConfig {
+        &self.config
+    }
+
+    /// Return the [`Candle`] that is currently forming, if any trades have been received for the
+    /// current period.
+    pub fn current(&self) -> Option<&Candle> {
+        self.candle.as_ref()
+    }
+
+    /// Update the [`CandleAggregator`] with a [`PublicTrade`] that occurred at the provided time,
+    /// returning any [`Candle`]s closed before the trade's period.
+    pub fn update(&mut self, time: DateTime<Utc>, trade: &PublicTrade) -> Vec<Candle> {
+        let open_time = self.config.interval.open_time(time);
+
+        // Ignore trades belonging to an already closed period
+        if self.cursor.is_some_and(|cursor| open_time < cursor) {
+            return vec![];
+        }
+
+        let closed = self.close_until(open_time);
+        self.cursor = Some(open_time);
+
+        match &mut self.candle {
+            Some(candle) => {
+                candle.high = candle.high.max(trade.price);
+                candle.low = candle.low.min(trade.price);
+                candle.close = trade.price;
+                candle.volume += trade.amount;
+                candle.trade_count += 1;
+            }
+            None => {
+                self.candle = Some(Candle {
+                    interval: Some(self.config.interval),
+                    open_time,
+                    close_time: self.config.interval.close_time(open_time),
+                    open: trade.price,
+                    high: trade.price,
+                    low: trade.price,
+                    close: trade.price,
+                    volume: trade.amount,
+                    trade_count: 1,
+                })
+            }
+        }
+
+        closed
+    }
+
+    /// Close every [`Candle`] whose period ended before the provided time.
+    ///
+    /// Used to drive [`CandleClock::WallClock`] aggregation when no trades are received.
+    pub fn poll(&mut self, now: DateTime<Utc>) -> Vec<Candle> {
+        self.close_until(self.config.interval.open_time(now))
+    }
+
+    /// Close every period that opened before the provided period open time, emitting the
+    /// forming [`Candle`] and any configured empty [`Candle`]s.
+    fn close_until(&mut self, open_time: DateTime<Utc>) -> Vec<Candle> {
+        let mut closed = Vec::new();
+
+        while let Some(cursor) = self.cursor.filter(|cursor| *cursor < open_time) {
+            match self.candle.take() {
+                Some(candle) => {
+                    self.last_close = Some(candle.close);
+                    closed.push(candle);
+                }
+                None if !self.config.emit_empty => {
+                    // No empty Candles to emit, so skip straight to the provided period
+                    self.cursor = Some(open_time);
+                    break;
+                }
+                None => {
+                    if let Some(close) = self.last_close {
+                        closed.push(Candle {
+                            interval: Some(self.config.interval),
+                            open_time: cursor,
+                            close_time: self.config.interval.close_time(cursor),
+                            open: close,
+                            high: close,
+                            low: close,
+                            close,
+                            volume: ZERO,
+                            trade_count: 0,
+                        });
+                    }
+                }
+            }
+
+            self.cursor = Some(self.config.interval.next_open_time(cursor));
+        }
+
+        closed
+    }
+}
+
+/// Aggregate a stream of [`MarketEvent<PublicTrade>`] into a stream of closed
+/// [`MarketEvent<Candle>`], maintaining a [`CandleAggregator`] per [`Exchange`] [`Instrument`].
+///
+/// For [`CandleClock::WallClock`] aggregation, [`Candle`]s are also closed at every period
+/// boundary regardless of whether further trades are received.
+pub async fn aggregate_candles(
+    config: CandleAggregatorConfig,
+    mut trade_rx: mpsc::UnboundedReceiver<MarketEvent<PublicTrade>>,
+    candle_tx: mpsc::UnboundedSender<MarketEvent<Candle>>,
+) {
+    let mut aggregators = HashMap::<(Exchange, Instrument), CandleAggregator>::new();
+
+    loop {
+        let next_boundary = config.interval.next_open_time(Utc::now());
+        let until_boundary = (next_boundary - Utc::now()).to_std().unwrap_or_default();
+
+        tokio::select! {
+            trade = trade_rx.recv() => {
+                let trade = match trade {
+                    Some(trade) => trade,
+                    None => break,
+                };
+
+                let time = match config.clock {
+                    CandleClock::Exchange => trade.exchange_time,
+                    CandleClock::WallClock => trade.received_time,
+                };
+
+                let key = (trade.exchange, trade.instrument);
+                let closed = aggregators
+                    .entry(key.clone())
+                    .or_insert_with(|| CandleAggregator::new(config))
+                    .update(time, &trade.kind);
+
+                if !send_candles(&candle_tx, &key, closed) {
+                    break;
+                }
+            },
+            _ = tokio::time::sleep(until_boundary), if config.clock == CandleClock::WallClock => {
+                let now = Utc::now();
+                for (key, aggregator) in aggregators.iter_mut() {
+                    if !send_candles(&candle_tx, key, aggregator.poll(now)) {
+                        return;
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Send closed [`Candle`]s to the provided [`mpsc::UnboundedSender`] as [`MarketEvent<Candle>`]s,
+/// returning false if the receiver has been dropped.
+fn send_candles(
+    candle_tx: &mpsc::UnboundedSender<MarketEvent<Candle>>,
+    (exchange, instrument): &(Exchange, Instrument),
+    candles: Vec<Candle>,
+) -> bool {
+    candles.into_iter().all(|candle| {
+        candle_tx
+            .send(MarketEvent {
+                exchange_time: candle.close_time,
+                received_time: Utc::now(),
+                exchange: exchange.clone(),
+                instrument: instrument.clone(),
+                kind: candle,
+            })
+            .is_ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+    use barter_integration::model::Side;
+    use chrono::TimeZone;
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(secs, 0).unwrap()
+    }
+
+    fn trade(price: Number, amount: Number) -> PublicTrade {
+        PublicTrade {
+            id: "id".to_string(),
+            price,
+            amount,
+            side: Side::Buy,
+        }
+    }
+
+    fn candle(open_secs: i64, ohlc: [Number; 4], volume: Number, trade_count: u64) -> Candle {
+        Candle {
+            interval: Some(CandleInterval::Minute1),
+            open_time: time(open_secs),
+            close_time: time(open_secs + 60) - chrono::Duration::milliseconds(1),
+            open: ohlc[0],
+            high: ohlc[1],
+            low: ohlc[2],
+            close: ohlc[3],
+            volume,
+            trade_count,
+        }
+    }
+
+    #[test]
+    fn test_candle_aggregator_update() {
+        struct TestCase {
+            emit_empty: bool,
+            trades: Vec<(i64, PublicTrade)>,
+            expected: Vec<Candle>,
+            expected_current: Option<Candle>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades within a single period only update the forming candle
+                emit_empty: false,
+                trades: vec![
+                    (0, trade(num!(100.0), num!(1.0))),
+                    (10, trade(num!(110.0), num!(2.0))),
+                    (59, trade(num!(90.0), num!(1.0))),
+                ],
+                expected: vec![],
+                expected_current: Some(candle(
+                    0,
+                    [num!(100.0), num!(110.0), num!(90.0), num!(90.0)],
+                    num!(4.0),
+                    3,
+                )),
+            },
+            TestCase {
+                // TC1: trade in the next period closes the forming candle
+                emit_empty: false,
+                trades: vec![
+                    (0, trade(num!(100.0), num!(1.0))),
+                    (30, trade(num!(105.0), num!(1.0))),
+                    (60, trade(num!(95.0), num!(2.0))),
+                ],
+                expected: vec![candle(
+                    0,
+                    [num!(100.0), num!(105.0), num!(100.0), num!(105.0)],
+                    num!(2.0),
+                    2,
+                )],
+                expected_current: Some(candle(
+                    60,
+                    [num!(95.0), num!(95.0), num!(95.0), num!(95.0)],
+                    num!(2.0),
+                    1,
+                )),
+            },
+            TestCase {
+                // TC2: gap without emit_empty only closes the forming candle
+                emit_empty: false,
+                trades: vec![
+                    (0, trade(num!(100.0), num!(1.0))),
+                    (185, trade(num!(101.0), num!(1.0))),
+                ],
+                expected: vec![candle(
+                    0,
+                    [num!(100.0), num!(100.0), num!(100.0), num!(100.0)],
+                    num!(1.0),
+                    1,
+                )],
+                expected_current: Some(candle(
+                    180,
+                    [num!(101.0), num!(101.0), num!(101.0), num!(101.0)],
+                    num!(1.0),
+                    1,
+                )),
+            },
+            TestCase {
+                // TC3: gap with emit_empty fills empty candles at the previous close
+                emit_empty: true,
+                trades: vec![
+                    (0, trade(num!(100.0), num!(1.0))),
+                    (185, trade(num!(101.0), num!(1.0))),
+                ],
+                expected: vec![
+                    candle(
+                        0,
+                        [num!(100.0), num!(100.0), num!(100.0), num!(100.0)],
+                        num!(1.0),
+                        1,
+                    ),
+                    candle(
+                        60,
+                        [num!(100.0), num!(100.0), num!(100.0), num!(100.0)],
+                        num!(0.0),
+                        0,
+                    ),
+                    candle(
+                        120,
+                        [num!(100.0), num!(100.0), num!(100.0), num!(100.0)],
+                        num!(0.0),
+                        0,
+                    ),
+                ],
+                expected_current: Some(candle(
+                    180,
+                    [num!(101.0), num!(101.0), num!(101.0), num!(101.0)],
+                    num!(1.0),
+                    1,
+                )),
+            },
+            TestCase {
+                // TC4: trade belonging to an already closed period is ignored
+                emit_empty: false,
+                trades: vec![
+                    (0, trade(num!(100.0), num!(1.0))),
+                    (60, trade(num!(101.0), num!(1.0))),
+                    (59, trade(num!(200.0), num!(1.0))),
+                ],
+                expected: vec![candle(
+                    0,
+                    [num!(100.0), num!(100.0), num!(100.0), num!(100.0)],
+                    num!(1.0),
+                    1,
+                )],
+                expected_current: Some(candle(
+                    60,
+                    [num!(101.0), num!(101.0), num!(101.0), num!(101.0)],
+                    num!(1.0),
+                    1,
+                )),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut config =
+                CandleAggregatorConfig::new(CandleInterval::Minute1, CandleClock::Exchange);
+            config.emit_empty = test.emit_empty;
+            let mut aggregator = CandleAggregator::new(config);
+
+            let actual = test
+                .trades
+                .iter()
+                .flat_map(|(secs, trade)| aggregator.update(time(*secs), trade))
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+            assert_eq!(
+                aggregator.current(),
+                test.expected_current.as_ref(),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_candle_aggregator_poll() {
+        struct TestCase {
+            emit_empty: bool,
+            trades: Vec<(i64, PublicTrade)>,
+            now: i64,
+            expected: Vec<Candle>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: poll before close time does not close the forming candle
+                emit_empty: true,
+                trades: vec![(0, trade(num!(100.0), num!(1.0)))],
+                now: 59,
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: poll after close time closes the forming candle
+                emit_empty: false,
+                trades: vec![(0, trade(num!(100.0), num!(1.0)))],
+                now: 150,
+                expected: vec![candle(
+                    0,
+                    [num!(100.0), num!(100.0), num!(100.0), num!(100.0)],
+                    num!(1.0),
+                    1,
+                )],
+            },
+            TestCase {
+                // TC2: poll after close time with emit_empty also emits elapsed empty candles
+                emit_empty: true,
+                trades: vec![(0, trade(num!(100.0), num!(1.0)))],
+                now: 150,
+                expected: vec![
+                    candle(
+                        0,
+                        [num!(100.0), num!(100.0), num!(100.0), num!(100.0)],
+                        num!(1.0),
+                        1,
+                    ),
+                    candle(
+                        60,
+                        [num!(100.0), num!(100.0), num!(100.0), num!(100.0)],
+                        num!(0.0),
+                        0,
+                    ),
+                ],
+            },
+            TestCase {
+                // TC3: poll before any trades emits nothing
+                emit_empty: true,
+                trades: vec![],
+                now: 150,
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut config =
+                CandleAggregatorConfig::new(CandleInterval::Minute1, CandleClock::WallClock);
+            config.emit_empty = test.emit_empty;
+            let mut aggregator = CandleAggregator::new(config);
+
+            for (secs, trade) in &test.trades {
+                aggregator.update(time(*secs), trade);
+            }
+
+            let actual = aggregator.poll(time(test.now));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
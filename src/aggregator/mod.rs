@@ -0,0 +1,16 @@
+/// [`CandleAggregator`](candle::CandleAggregator) that builds time based
+/// [`Candle`](crate::subscription::candle::Candle)s from
+/// [`PublicTrade`](crate::subscription::trade::PublicTrade)s, for exchanges that do not offer
+/// native candle streams.
+pub mod candle;
+
+/// [`BarAggregator`](bar::BarAggregator) that builds information-driven tick, volume and dollar
+/// [`Bar`](bar::Bar)s from [`PublicTrade`](crate::subscription::trade::PublicTrade)s.
+pub mod bar;
+
+/// [`ConsolidatedOrderBook`](book::ConsolidatedOrderBook) that aggregates the
+/// [`OrderBook`](crate::subscription::book::OrderBook)s of one base/quote pair across many
+/// exchanges, with per-exchange [`Level`](crate::subscription::book::Level) contributions, and
+/// the synthesis of [`OrderBookL1`](crate::subscription::book::OrderBookL1)s from any
+/// [`OrderBook`](crate::subscription::book::OrderBook) stream.
+pub mod book;
@@ -17,6 +17,9 @@ pub enum DataError {
         prev_last_update_id: u64,
         first_update_id: u64,
     },
+
+    #[error("InvalidChecksum: expected {expected} but local OrderBook checksum is {actual}")]
+    InvalidChecksum { expected: u32, actual: u32 },
 }
 
 impl DataError {
@@ -24,7 +27,7 @@ impl DataError {
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_terminal(&self) -> bool {
         match self {
-            DataError::InvalidSequence { .. } => true,
+            DataError::InvalidSequence { .. } | DataError::InvalidChecksum { .. } => true,
             _ => false,
         }
     }
@@ -51,7 +54,15 @@ mod tests {
                 expected: true,
             },
             TestCase {
-                // TC1: is not terminal w/ DataError::Socket
+                // TC1: is terminal w/ DataError::InvalidChecksum
+                input: DataError::InvalidChecksum {
+                    expected: 0,
+                    actual: 1,
+                },
+                expected: true,
+            },
+            TestCase {
+                // TC2: is not terminal w/ DataError::Socket
                 input: DataError::Socket(SocketError::Sink),
                 expected: false,
             },
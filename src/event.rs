@@ -3,7 +3,11 @@ use crate::{
     subscription::{
         book::{OrderBook, OrderBookL1},
         candle::Candle,
+        funding::FundingRate,
         liquidation::Liquidation,
+        mark_price::MarkPrice,
+        open_interest::OpenInterest,
+        ticker::Ticker,
         trade::PublicTrade,
     },
 };
@@ -60,6 +64,10 @@ pub enum DataKind {
     OrderBook(OrderBook),
     Candle(Candle),
     Liquidation(Liquidation),
+    FundingRate(FundingRate),
+    OpenInterest(OpenInterest),
+    MarkPrice(MarkPrice),
+    Ticker(Ticker),
 }
 
 impl From<MarketEvent<PublicTrade>> for MarketEvent<DataKind> {
@@ -121,3 +129,51 @@ impl From<MarketEvent<Liquidation>> for MarketEvent<DataKind> {
         }
     }
 }
+
+impl From<MarketEvent<FundingRate>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<FundingRate>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::FundingRate(event.kind),
+        }
+    }
+}
+
+impl From<MarketEvent<OpenInterest>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<OpenInterest>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::OpenInterest(event.kind),
+        }
+    }
+}
+
+impl From<MarketEvent<MarkPrice>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<MarkPrice>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::MarkPrice(event.kind),
+        }
+    }
+}
+
+impl From<MarketEvent<Ticker>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<Ticker>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::Ticker(event.kind),
+        }
+    }
+}
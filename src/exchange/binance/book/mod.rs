@@ -1,4 +1,4 @@
-use crate::subscription::book::Level;
+use crate::{number::Number, subscription::book::Level};
 use serde::{Deserialize, Serialize};
 
 /// Level 1 OrderBook types (top of book).
@@ -17,9 +17,9 @@ pub mod l2;
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct BinanceLevel {
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Number,
 }
 
 impl From<BinanceLevel> for Level {
@@ -34,6 +34,7 @@ impl From<BinanceLevel> for Level {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::number::num;
 
     mod de {
         use super::*;
@@ -44,8 +45,8 @@ mod tests {
             assert_eq!(
                 serde_json::from_str::<BinanceLevel>(input).unwrap(),
                 BinanceLevel {
-                    price: 4.00000200,
-                    amount: 12.0
+                    price: num!(4.00000200),
+                    amount: num!(12.0)
                 },
             )
         }
@@ -0,0 +1,215 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    number::Number,
+    subscription::candle::{Candle, CandleInterval},
+    transformer::candle::CandleStatus,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::Binance) kline/candlestick message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+/// ```json
+/// {
+///     "e": "kline",
+///     "E": 1672515782136,
+///     "s": "BTCUSDT",
+///     "k": {
+///         "t": 1672515780000,
+///         "T": 1672515839999,
+///         "s": "BTCUSDT",
+///         "i": "1m",
+///         "f": 100,
+///         "L": 200,
+///         "o": "16541.10",
+///         "c": "16544.30",
+///         "h": "16545.00",
+///         "l": "16540.20",
+///         "v": "12.345",
+///         "n": 101,
+///         "x": false,
+///         "q": "204212.10",
+///         "V": "6.100",
+///         "Q": "100913.42",
+///         "B": "0"
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceKline {
+    #[serde(alias = "k")]
+    pub kline: BinanceKlineCandle,
+}
+
+/// [`Binance`](super::Binance) kline/candlestick.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// {
+///     "t": 1672515780000,
+///     "T": 1672515839999,
+///     "s": "BTCUSDT",
+///     "i": "1m",
+///     "f": 100,
+///     "L": 200,
+///     "o": "16541.10",
+///     "c": "16544.30",
+///     "h": "16545.00",
+///     "l": "16540.20",
+///     "v": "12.345",
+///     "n": 101,
+///     "x": false,
+///     "q": "204212.10",
+///     "V": "6.100",
+///     "Q": "100913.42",
+///     "B": "0"
+/// }
+/// ```
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceKlineCandle {
+    #[serde(alias = "s")]
+    pub market: String,
+    #[serde(alias = "i")]
+    pub interval: CandleInterval,
+    #[serde(
+        alias = "t",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub open_time: DateTime<Utc>,
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub close_time: DateTime<Utc>,
+    #[serde(alias = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: Number,
+    #[serde(alias = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: Number,
+    #[serde(alias = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: Number,
+    #[serde(alias = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub close: Number,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: Number,
+    #[serde(alias = "n")]
+    pub trade_count: u64,
+    #[serde(alias = "x")]
+    pub closed: bool,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceKline {
+    fn id(&self) -> Option<SubscriptionId> {
+        // Notes:
+        // - Binance interval names are identical to the normalised CandleInterval names.
+        // - Must match the "@kline_{interval}" BinanceChannel::candles naming.
+        // eg/ "@kline_1m|BTCUSDT"
+        Some(SubscriptionId::from(format!(
+            "@kline_{}|{}",
+            self.kline.interval, self.kline.market
+        )))
+    }
+}
+
+impl CandleStatus for BinanceKline {
+    fn is_closed(&self) -> bool {
+        self.kline.closed
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceKline)> for MarketIter<Candle> {
+    fn from((exchange_id, instrument, kline): (ExchangeId, Instrument, BinanceKline)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: kline.kline.close_time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Candle {
+                interval: Some(kline.kline.interval),
+                open_time: kline.kline.open_time,
+                close_time: kline.kline.close_time,
+                open: kline.kline.open,
+                high: kline.kline.high,
+                low: kline.kline.low,
+                close: kline.kline.close,
+                volume: kline.kline.volume,
+                trade_count: kline.kline.trade_count,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_kline() {
+            let input = r#"
+            {
+                "e": "kline",
+                "E": 1672515782136,
+                "s": "BTCUSDT",
+                "k": {
+                    "t": 1672515780000,
+                    "T": 1672515839999,
+                    "s": "BTCUSDT",
+                    "i": "1m",
+                    "f": 100,
+                    "L": 200,
+                    "o": "16541.10",
+                    "c": "16544.30",
+                    "h": "16545.00",
+                    "l": "16540.20",
+                    "v": "12.345",
+                    "n": 101,
+                    "x": true,
+                    "q": "204212.10",
+                    "V": "6.100",
+                    "Q": "100913.42",
+                    "B": "0"
+                }
+            }
+            "#;
+
+            let actual = serde_json::from_str::<BinanceKline>(input).unwrap();
+
+            assert_eq!(
+                actual,
+                BinanceKline {
+                    kline: BinanceKlineCandle {
+                        market: "BTCUSDT".to_string(),
+                        interval: CandleInterval::Minute1,
+                        open_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515780000,
+                        )),
+                        close_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515839999,
+                        )),
+                        open: num!(16541.10),
+                        high: num!(16545.00),
+                        low: num!(16540.20),
+                        close: num!(16544.30),
+                        volume: num!(12.345),
+                        trade_count: 101,
+                        closed: true,
+                    },
+                }
+            );
+            assert_eq!(actual.id(), Some(SubscriptionId::from("@kline_1m|BTCUSDT")));
+        }
+    }
+}
@@ -1,8 +1,13 @@
 use super::{futures::BinanceFuturesUsd, Binance};
 use crate::{
     subscription::{
-        book::{OrderBooksL1, OrderBooksL2},
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        candle::{CandleInterval, Candles},
+        funding::FundingRates,
         liquidation::Liquidations,
+        mark_price::MarkPrices,
+        open_interest::OpenInterests,
+        ticker::Tickers,
         trade::PublicTrades,
         Subscription,
     },
@@ -42,10 +47,65 @@ impl BinanceChannel {
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
     pub const ORDER_BOOK_L2: Self = Self("@depth@100ms");
 
+    /// [`Binance`](super::Binance) rolling 24h ticker channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-ticker-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-ticker-streams>
+    pub const TICKERS: Self = Self("@ticker");
+
     /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) liquidation orders channel name.
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
     pub const LIQUIDATIONS: Self = Self("@forceOrder");
+
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) mark price & funding rate channel
+    /// name (1s updates).
+    ///
+    /// Note:
+    /// Used for both [`FundingRates`] & [`MarkPrices`], since the payload does not identify the
+    /// update speed it was subscribed with.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+    pub const MARK_PRICE: Self = Self("@markPrice@1s");
+
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) open interest "channel" name.
+    ///
+    /// Note:
+    /// Open interest is only served over HTTP, so this is never subscribed to. It is polled via
+    /// the [`BinanceOpenInterestPoller`](super::futures::open_interest::BinanceOpenInterestPoller).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#open-interest>
+    pub const OPEN_INTEREST: Self = Self("openInterest");
+
+    /// [`Binance`](super::Binance) kline/candlestick channel name for the provided
+    /// [`CandleInterval`].
+    ///
+    /// Note:
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) does not support
+    /// [`CandleInterval::Second1`], see [`StreamSelector::validate_kind`](crate::exchange::StreamSelector::validate_kind).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+    pub fn candles(interval: CandleInterval) -> Self {
+        match interval {
+            CandleInterval::Second1 => Self("@kline_1s"),
+            CandleInterval::Minute1 => Self("@kline_1m"),
+            CandleInterval::Minute3 => Self("@kline_3m"),
+            CandleInterval::Minute5 => Self("@kline_5m"),
+            CandleInterval::Minute15 => Self("@kline_15m"),
+            CandleInterval::Minute30 => Self("@kline_30m"),
+            CandleInterval::Hour1 => Self("@kline_1h"),
+            CandleInterval::Hour2 => Self("@kline_2h"),
+            CandleInterval::Hour4 => Self("@kline_4h"),
+            CandleInterval::Hour6 => Self("@kline_6h"),
+            CandleInterval::Hour8 => Self("@kline_8h"),
+            CandleInterval::Hour12 => Self("@kline_12h"),
+            CandleInterval::Day1 => Self("@kline_1d"),
+            CandleInterval::Day3 => Self("@kline_3d"),
+            CandleInterval::Week1 => Self("@kline_1w"),
+            CandleInterval::Month1 => Self("@kline_1M"),
+        }
+    }
 }
 
 impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, PublicTrades> {
@@ -66,12 +126,48 @@ impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderB
     }
 }
 
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderBooksL2Delta> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, Candles> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::candles(self.kind.interval)
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, Tickers> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::TICKERS
+    }
+}
+
 impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, Liquidations> {
     fn id(&self) -> BinanceChannel {
         BinanceChannel::LIQUIDATIONS
     }
 }
 
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, FundingRates> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::MARK_PRICE
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, MarkPrices> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::MARK_PRICE
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, OpenInterests> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::OPEN_INTEREST
+    }
+}
+
 impl AsRef<str> for BinanceChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,8 +1,10 @@
 use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
 use crate::{
     error::DataError,
-    subscription::book::OrderBook,
-    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    subscription::book::{OrderBook, OrderBookDelta},
+    transformer::book::{
+        InstrumentOrderBook, OrderBookChange, OrderBookDeltaUpdater, OrderBookUpdater,
+    },
     Identifier,
 };
 use async_trait::async_trait;
@@ -20,6 +22,20 @@ use tokio::sync::mpsc;
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#order-book>
 pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT: &str = "https://fapi.binance.com/fapi/v1/depth";
 
+/// Default number of levels requested in the [`BinanceFuturesUsd`](super::BinanceFuturesUsd) HTTP
+/// OrderBook L2 snapshot if the [`OrderBooksL2`](crate::subscription::book::OrderBooksL2)
+/// subscription does not configure a `snapshot_depth`.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_DEFAULT_DEPTH_BINANCE_FUTURES_USD: usize = 100;
+
+/// Valid number of levels that can be requested in the
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) HTTP OrderBook L2 snapshot.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_DEPTHS_BINANCE_FUTURES_USD: [usize; 7] =
+    [5, 10, 20, 50, 100, 500, 1000];
+
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) OrderBook Level2 deltas WebSocket message.
 ///
 /// ### Raw Payload Examples
@@ -163,6 +179,7 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
     async fn init<Exchange, Kind>(
         _: mpsc::UnboundedSender<WsMessage>,
         instrument: Instrument,
+        snapshot_depth: Option<usize>,
     ) -> Result<InstrumentOrderBook<Self>, DataError>
     where
         Exchange: Send,
@@ -170,10 +187,11 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
     {
         // Construct initial OrderBook snapshot GET url
         let snapshot_url = format!(
-            "{}?symbol={}{}&limit=100",
+            "{}?symbol={}{}&limit={}",
             HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
             instrument.base.as_ref().to_uppercase(),
-            instrument.quote.as_ref().to_uppercase()
+            instrument.quote.as_ref().to_uppercase(),
+            snapshot_depth.unwrap_or(HTTP_BOOK_L2_SNAPSHOT_DEFAULT_DEPTH_BINANCE_FUTURES_USD)
         );
 
         // Fetch initial OrderBook snapshot via HTTP
@@ -190,12 +208,14 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
             book: OrderBook::from(snapshot),
         })
     }
+}
 
-    fn update(
+impl OrderBookDeltaUpdater for BinanceFuturesBookUpdater {
+    fn update_delta(
         &mut self,
-        book: &mut Self::OrderBook,
+        book: &mut OrderBook,
         update: Self::Update,
-    ) -> Result<Option<Self::OrderBook>, DataError> {
+    ) -> Result<Option<OrderBookChange>, DataError> {
         // BinanceFuturesUsd: How To Manage A Local OrderBook Correctly
         // See Self's Rust Docs for more information on each numbered step
         // See docs: <https://binance-docs.github.io/apidocs/futures/en/#how-to-manage-a-local-order-book-correctly>
@@ -217,20 +237,28 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         // 7. The data in each event is the absolute quantity for a price level.
         // 8. If the quantity is 0, remove the price level.
         book.last_update_time = Utc::now();
-        book.bids.upsert(update.bids);
-        book.asks.upsert(update.asks);
+        let bids = book.bids.upsert_delta(update.bids);
+        let asks = book.asks.upsert_delta(update.asks);
 
         // Update OrderBookUpdater metadata
         self.updates_processed += 1;
+        let prev_last_update_id = self.last_update_id;
         self.last_update_id = update.last_update_id;
 
-        Ok(Some(book.snapshot()))
+        Ok(Some(OrderBookChange::Delta(OrderBookDelta {
+            last_update_time: book.last_update_time,
+            prev_sequence: Some(prev_last_update_id),
+            sequence: Some(self.last_update_id),
+            bids,
+            asks,
+        })))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::number::num;
 
     mod de {
         use super::*;
@@ -269,12 +297,12 @@ mod tests {
                     last_update_id: 160,
                     prev_last_update_id: 149,
                     bids: vec![BinanceLevel {
-                        price: 0.0024,
-                        amount: 10.0
+                        price: num!(0.0024),
+                        amount: num!(10.0)
                     },],
                     asks: vec![BinanceLevel {
-                        price: 0.0026,
-                        amount: 100.0
+                        price: num!(0.0026),
+                        amount: num!(100.0)
                     },]
                 }
             );
@@ -503,8 +531,8 @@ mod tests {
                     },
                     book: OrderBook {
                         last_update_time: time,
-                        bids: OrderBookSide::new(Side::Buy, vec![Level::new(50, 1)]),
-                        asks: OrderBookSide::new(Side::Sell, vec![Level::new(100, 1)]),
+                        bids: OrderBookSide::new(Side::Buy, vec![Level::new(num!(50), num!(1))]),
+                        asks: OrderBookSide::new(Side::Sell, vec![Level::new(num!(100), num!(1))]),
                     },
                     input_update: BinanceFuturesOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -526,11 +554,19 @@ mod tests {
                         last_update_time: time,
                         bids: OrderBookSide::new(
                             Side::Buy,
-                            vec![Level::new(80, 1), Level::new(100, 1), Level::new(90, 1)],
+                            vec![
+                                Level::new(num!(80), num!(1)),
+                                Level::new(num!(100), num!(1)),
+                                Level::new(num!(90), num!(1)),
+                            ],
                         ),
                         asks: OrderBookSide::new(
                             Side::Sell,
-                            vec![Level::new(150, 1), Level::new(110, 1), Level::new(120, 1)],
+                            vec![
+                                Level::new(num!(150), num!(1)),
+                                Level::new(num!(110), num!(1)),
+                                Level::new(num!(120), num!(1)),
+                            ],
                         ),
                     },
                     input_update: BinanceFuturesOrderBookL2Delta {
@@ -541,25 +577,25 @@ mod tests {
                         bids: vec![
                             // Level exists & new value is 0 => remove Level
                             BinanceLevel {
-                                price: 80.0,
-                                amount: 0.0,
+                                price: num!(80.0),
+                                amount: num!(0.0),
                             },
                             // Level exists & new value is > 0 => replace Level
                             BinanceLevel {
-                                price: 90.0,
-                                amount: 10.0,
+                                price: num!(90.0),
+                                amount: num!(10.0),
                             },
                         ],
                         asks: vec![
                             // Level does not exist & new value > 0 => insert new Level
                             BinanceLevel {
-                                price: 200.0,
-                                amount: 1.0,
+                                price: num!(200.0),
+                                amount: num!(1.0),
                             },
                             // Level does not exist & new value is 0 => no change
                             BinanceLevel {
-                                price: 500.0,
-                                amount: 0.0,
+                                price: num!(500.0),
+                                amount: num!(0.0),
                             },
                         ],
                     },
@@ -567,15 +603,18 @@ mod tests {
                         last_update_time: time,
                         bids: OrderBookSide::new(
                             Side::Buy,
-                            vec![Level::new(100, 1), Level::new(90, 10)],
+                            vec![
+                                Level::new(num!(100), num!(1)),
+                                Level::new(num!(90), num!(10)),
+                            ],
                         ),
                         asks: OrderBookSide::new(
                             Side::Sell,
                             vec![
-                                Level::new(110, 1),
-                                Level::new(120, 1),
-                                Level::new(150, 1),
-                                Level::new(200, 1),
+                                Level::new(num!(110), num!(1)),
+                                Level::new(num!(120), num!(1)),
+                                Level::new(num!(150), num!(1)),
+                                Level::new(num!(200), num!(1)),
                             ],
                         ),
                     })),
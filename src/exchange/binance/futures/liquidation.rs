@@ -2,12 +2,16 @@ use super::super::BinanceChannel;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
-    subscription::liquidation::Liquidation,
+    number::Number,
+    subscription::liquidation::{Liquidation, LiquidationStatus},
     Identifier,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Serialize,
+};
 
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) Liquidation order message.
 ///
@@ -65,9 +69,15 @@ pub struct BinanceLiquidationOrder {
     #[serde(alias = "S")]
     pub side: Side,
     #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
     #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
-    pub quantity: f64,
+    pub quantity: Number,
+    #[serde(alias = "ap", deserialize_with = "barter_integration::de::de_str")]
+    pub average_price: Number,
+    #[serde(alias = "z", deserialize_with = "barter_integration::de::de_str")]
+    pub filled_quantity: Number,
+    #[serde(alias = "X", deserialize_with = "de_liquidation_status")]
+    pub status: LiquidationStatus,
     #[serde(
         alias = "T",
         deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
@@ -95,6 +105,9 @@ impl From<(ExchangeId, Instrument, BinanceLiquidation)> for MarketIter<Liquidati
                 price: liquidation.order.price,
                 quantity: liquidation.order.quantity,
                 time: liquidation.order.time,
+                average_price: Some(liquidation.order.average_price),
+                filled_quantity: Some(liquidation.order.filled_quantity),
+                status: Some(liquidation.order.status),
             },
         })])
     }
@@ -113,9 +126,31 @@ where
     })
 }
 
+/// Deserialize a [`BinanceLiquidationOrder`] "X" (eg/ "FILLED") as the associated
+/// [`LiquidationStatus`].
+pub fn de_liquidation_status<'de, D>(deserializer: D) -> Result<LiquidationStatus, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as Deserialize>::deserialize(deserializer)?;
+
+    match input {
+        "NEW" => Ok(LiquidationStatus::New),
+        "PARTIALLY_FILLED" => Ok(LiquidationStatus::PartiallyFilled),
+        "FILLED" => Ok(LiquidationStatus::Filled),
+        "CANCELED" => Ok(LiquidationStatus::Cancelled),
+        "EXPIRED" => Ok(LiquidationStatus::Expired),
+        _ => Err(Error::invalid_value(
+            Unexpected::Str(input),
+            &"NEW, PARTIALLY_FILLED, FILLED, CANCELED or EXPIRED",
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::number::num;
 
     mod de {
         use super::*;
@@ -150,8 +185,11 @@ mod tests {
                     order: BinanceLiquidationOrder {
                         subscription_id: SubscriptionId::from("@forceOrder|BTCUSDT"),
                         side: Side::Sell,
-                        price: 18917.15,
-                        quantity: 0.009,
+                        price: num!(18917.15),
+                        quantity: num!(0.009),
+                        average_price: num!(18990.00),
+                        filled_quantity: num!(0.009),
+                        status: LiquidationStatus::Filled,
                         time: datetime_utc_from_epoch_duration(Duration::from_millis(
                             1665523974217,
                         )),
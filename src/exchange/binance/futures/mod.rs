@@ -1,10 +1,25 @@
-use self::{l2::BinanceFuturesBookUpdater, liquidation::BinanceLiquidation};
+use self::{
+    l2::{BinanceFuturesBookUpdater, HTTP_BOOK_L2_SNAPSHOT_DEPTHS_BINANCE_FUTURES_USD},
+    liquidation::BinanceLiquidation,
+    mark_price::BinanceMarkPrice,
+    open_interest::BinanceOpenInterestPoller,
+};
 use super::{Binance, ExchangeServer};
 use crate::{
     exchange::{ExchangeId, StreamSelector},
-    subscription::{book::OrderBooksL2, liquidation::Liquidations},
-    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
-    ExchangeWsStream,
+    poller::PollingStream,
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL2Delta},
+        funding::FundingRates,
+        liquidation::Liquidations,
+        mark_price::MarkPrices,
+        open_interest::OpenInterests,
+    },
+    transformer::{
+        book::{MultiBookDeltaTransformer, MultiBookTransformer},
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, TimedExchangeWsStream,
 };
 
 /// Level 2 OrderBook types (top of book) and perpetual
@@ -14,6 +29,12 @@ pub mod l2;
 /// Liquidation types.
 pub mod liquidation;
 
+/// Mark price & funding rate types.
+pub mod mark_price;
+
+/// Open interest types and HTTP [`HttpPoller`](crate::poller::HttpPoller) implementation.
+pub mod open_interest;
+
 /// [`BinanceFuturesUsd`] WebSocket server base url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
@@ -36,9 +57,33 @@ impl ExchangeServer for BinanceServerFuturesUsd {
 
 impl StreamSelector<OrderBooksL2> for BinanceFuturesUsd {
     type Stream =
-        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceFuturesBookUpdater>>;
+        TimedExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceFuturesBookUpdater>>;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_snapshot_depth(|snapshot_depth| {
+            HTTP_BOOK_L2_SNAPSHOT_DEPTHS_BINANCE_FUTURES_USD.contains(&snapshot_depth)
+        })
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for BinanceFuturesUsd {
+    type Stream = ExchangeWsStream<
+        MultiBookDeltaTransformer<Self, OrderBooksL2Delta, BinanceFuturesBookUpdater>,
+    >;
 }
 
 impl StreamSelector<Liquidations> for BinanceFuturesUsd {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, Liquidations, BinanceLiquidation>>;
 }
+
+impl StreamSelector<FundingRates> for BinanceFuturesUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, FundingRates, BinanceMarkPrice>>;
+}
+
+impl StreamSelector<MarkPrices> for BinanceFuturesUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, MarkPrices, BinanceMarkPrice>>;
+}
+
+impl StreamSelector<OpenInterests> for BinanceFuturesUsd {
+    type Stream = PollingStream<Self, OpenInterests, BinanceOpenInterestPoller>;
+}
@@ -0,0 +1,121 @@
+use super::{super::market::BinanceMarket, BinanceFuturesUsd};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    number::Number,
+    poller::HttpPoller,
+    subscription::open_interest::{OpenInterest, OpenInterests},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// [`BinanceFuturesUsd`] HTTP open interest url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#open-interest>
+pub const HTTP_OPEN_INTEREST_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/openInterest";
+
+/// Default interval between consecutive [`BinanceFuturesUsd`] open interest polls.
+pub const HTTP_OPEN_INTEREST_POLL_INTERVAL_BINANCE_FUTURES_USD: Duration = Duration::from_secs(5);
+
+/// [`BinanceFuturesUsd`] [`HttpPoller`] for [`OpenInterests`], since open interest is only
+/// served over HTTP.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#open-interest>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BinanceOpenInterestPoller;
+
+impl HttpPoller<BinanceFuturesUsd, OpenInterests> for BinanceOpenInterestPoller {
+    type Response = BinanceOpenInterest;
+
+    fn interval() -> Duration {
+        HTTP_OPEN_INTEREST_POLL_INTERVAL_BINANCE_FUTURES_USD
+    }
+
+    fn url(market: &BinanceMarket) -> String {
+        format!(
+            "{HTTP_OPEN_INTEREST_URL_BINANCE_FUTURES_USD}?symbol={}",
+            market.as_ref()
+        )
+    }
+}
+
+/// [`BinanceFuturesUsd`] HTTP open interest response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#open-interest>
+/// ```json
+/// {
+///     "openInterest": "10659.509",
+///     "symbol": "BTCUSDT",
+///     "time": 1589437530011
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceOpenInterest {
+    pub symbol: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub open_interest: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, BinanceOpenInterest)> for MarketIter<OpenInterest> {
+    fn from(
+        (exchange_id, instrument, open_interest): (ExchangeId, Instrument, BinanceOpenInterest),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: open_interest.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OpenInterest {
+                contracts: open_interest.open_interest,
+                notional: None,
+                time: open_interest.time,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+
+        #[test]
+        fn test_binance_open_interest() {
+            let input = r#"
+            {
+                "openInterest": "10659.509",
+                "symbol": "BTCUSDT",
+                "time": 1589437530011
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BinanceOpenInterest>(input).unwrap(),
+                BinanceOpenInterest {
+                    symbol: "BTCUSDT".to_string(),
+                    open_interest: num!(10659.509),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1589437530011)),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_binance_open_interest_poller_url() {
+        assert_eq!(
+            BinanceOpenInterestPoller::url(&BinanceMarket("BTCUSDT".to_string())),
+            "https://fapi.binance.com/fapi/v1/openInterest?symbol=BTCUSDT"
+        );
+    }
+}
@@ -1,12 +1,19 @@
 use self::{
-    book::l1::BinanceOrderBookL1, channel::BinanceChannel, market::BinanceMarket,
-    subscription::BinanceSubResponse, trade::BinanceTrade,
+    book::l1::BinanceOrderBookL1, candle::BinanceKline, channel::BinanceChannel,
+    market::BinanceMarket, subscription::BinanceSubResponse, ticker::BinanceTicker,
+    trade::BinanceTrade,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeServer, ExchangeSub, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Map},
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::OrderBooksL1,
+        candle::{CandleInterval, Candles},
+        ticker::Tickers,
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{candle::CandleTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{
@@ -19,6 +26,10 @@ use url::Url;
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod book;
 
+/// Candle types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -40,6 +51,10 @@ pub mod spot;
 /// and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod subscription;
 
+/// Rolling 24h ticker types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod ticker;
+
 /// Public trade types common to both [`BinanceSpot`](spot::BinanceSpot) and
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod trade;
@@ -113,6 +128,29 @@ where
     type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, BinanceOrderBookL1>>;
 }
 
+impl<Server> StreamSelector<Tickers> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Tickers, BinanceTicker>>;
+}
+
+impl<Server> StreamSelector<Candles> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<CandleTransformer<Self, BinanceKline>>;
+
+    fn validate_kind(kind: &Candles) -> Result<(), String> {
+        match (Self::ID, kind.interval) {
+            (ExchangeId::BinanceFuturesUsd, CandleInterval::Second1) => {
+                Err(format!("{} Candles", kind.interval))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Binance<Server>
 where
     Server: ExchangeServer,
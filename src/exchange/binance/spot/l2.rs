@@ -1,8 +1,10 @@
 use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
 use crate::{
     error::DataError,
-    subscription::book::OrderBook,
-    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    subscription::book::{OrderBook, OrderBookDelta},
+    transformer::book::{
+        InstrumentOrderBook, OrderBookChange, OrderBookDeltaUpdater, OrderBookUpdater,
+    },
     Identifier,
 };
 use async_trait::async_trait;
@@ -13,6 +15,7 @@ use barter_integration::{
 };
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
 use tokio::sync::mpsc;
 
 /// [`BinanceSpot`](super::BinanceSpot) HTTP OrderBook L2 snapshot url.
@@ -20,6 +23,19 @@ use tokio::sync::mpsc;
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#order-book>
 pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/depth";
 
+/// Default number of levels requested in the [`BinanceSpot`](super::BinanceSpot) HTTP
+/// OrderBook L2 snapshot if the [`OrderBooksL2`](crate::subscription::book::OrderBooksL2)
+/// subscription does not configure a `snapshot_depth`.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_DEFAULT_DEPTH_BINANCE_SPOT: usize = 100;
+
+/// Valid number of levels that can be requested in the [`BinanceSpot`](super::BinanceSpot) HTTP
+/// OrderBook L2 snapshot.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_DEPTHS_BINANCE_SPOT: RangeInclusive<usize> = 1..=5000;
+
 /// [`BinanceSpot`](super::BinanceSpot) OrderBook Level2 deltas WebSocket message.
 ///
 /// ### Raw Payload Examples
@@ -159,6 +175,7 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
     async fn init<Exchange, Kind>(
         _: mpsc::UnboundedSender<WsMessage>,
         instrument: Instrument,
+        snapshot_depth: Option<usize>,
     ) -> Result<InstrumentOrderBook<Self>, DataError>
     where
         Exchange: Send,
@@ -166,10 +183,11 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
     {
         // Construct initial OrderBook snapshot GET url
         let snapshot_url = format!(
-            "{}?symbol={}{}&limit=100",
+            "{}?symbol={}{}&limit={}",
             HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
             instrument.base.as_ref().to_uppercase(),
-            instrument.quote.as_ref().to_uppercase()
+            instrument.quote.as_ref().to_uppercase(),
+            snapshot_depth.unwrap_or(HTTP_BOOK_L2_SNAPSHOT_DEFAULT_DEPTH_BINANCE_SPOT)
         );
 
         // Fetch initial OrderBook snapshot via HTTP
@@ -186,12 +204,14 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
             book: OrderBook::from(snapshot),
         })
     }
+}
 
-    fn update(
+impl OrderBookDeltaUpdater for BinanceSpotBookUpdater {
+    fn update_delta(
         &mut self,
-        book: &mut Self::OrderBook,
+        book: &mut OrderBook,
         update: Self::Update,
-    ) -> Result<Option<Self::OrderBook>, DataError> {
+    ) -> Result<Option<OrderBookChange>, DataError> {
         // BinanceSpot: How To Manage A Local OrderBook Correctly
         // See Self's Rust Docs for more information on each numbered step
         // See docs: <https://binance-docs.github.io/apidocs/spot/en/#how-to-manage-a-local-order-book-correctly>
@@ -213,21 +233,28 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
         // 7. The data in each event is the absolute quantity for a price level.
         // 8. If the quantity is 0, remove the price level.
         book.last_update_time = Utc::now();
-        book.bids.upsert(update.bids);
-        book.asks.upsert(update.asks);
+        let bids = book.bids.upsert_delta(update.bids);
+        let asks = book.asks.upsert_delta(update.asks);
 
         // Update OrderBookUpdater metadata
         self.updates_processed += 1;
         self.prev_last_update_id = self.last_update_id;
         self.last_update_id = update.last_update_id;
 
-        Ok(Some(book.snapshot()))
+        Ok(Some(OrderBookChange::Delta(OrderBookDelta {
+            last_update_time: book.last_update_time,
+            prev_sequence: Some(self.prev_last_update_id),
+            sequence: Some(self.last_update_id),
+            bids,
+            asks,
+        })))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::number::num;
 
     mod de {
         use super::*;
@@ -257,12 +284,12 @@ mod tests {
                     last_update_id: 22611425151,
                     bids: vec![
                         BinanceLevel {
-                            price: 1209.67000000,
-                            amount: 85.48210000
+                            price: num!(1209.67000000),
+                            amount: num!(85.48210000)
                         },
                         BinanceLevel {
-                            price: 1209.66000000,
-                            amount: 20.68790000
+                            price: num!(1209.66000000),
+                            amount: num!(20.68790000)
                         },
                     ],
                     asks: vec![]
@@ -495,8 +522,8 @@ mod tests {
                     },
                     book: OrderBook {
                         last_update_time: time,
-                        bids: OrderBookSide::new(Side::Buy, vec![Level::new(50, 1)]),
-                        asks: OrderBookSide::new(Side::Sell, vec![Level::new(100, 1)]),
+                        bids: OrderBookSide::new(Side::Buy, vec![Level::new(num!(50), num!(1))]),
+                        asks: OrderBookSide::new(Side::Sell, vec![Level::new(num!(100), num!(1))]),
                     },
                     input_update: BinanceSpotOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -518,11 +545,19 @@ mod tests {
                         last_update_time: time,
                         bids: OrderBookSide::new(
                             Side::Buy,
-                            vec![Level::new(80, 1), Level::new(100, 1), Level::new(90, 1)],
+                            vec![
+                                Level::new(num!(80), num!(1)),
+                                Level::new(num!(100), num!(1)),
+                                Level::new(num!(90), num!(1)),
+                            ],
                         ),
                         asks: OrderBookSide::new(
                             Side::Sell,
-                            vec![Level::new(150, 1), Level::new(110, 1), Level::new(120, 1)],
+                            vec![
+                                Level::new(num!(150), num!(1)),
+                                Level::new(num!(110), num!(1)),
+                                Level::new(num!(120), num!(1)),
+                            ],
                         ),
                     },
                     input_update: BinanceSpotOrderBookL2Delta {
@@ -532,25 +567,25 @@ mod tests {
                         bids: vec![
                             // Level exists & new value is 0 => remove Level
                             BinanceLevel {
-                                price: 80.0,
-                                amount: 0.0,
+                                price: num!(80.0),
+                                amount: num!(0.0),
                             },
                             // Level exists & new value is > 0 => replace Level
                             BinanceLevel {
-                                price: 90.0,
-                                amount: 10.0,
+                                price: num!(90.0),
+                                amount: num!(10.0),
                             },
                         ],
                         asks: vec![
                             // Level does not exist & new value > 0 => insert new Level
                             BinanceLevel {
-                                price: 200.0,
-                                amount: 1.0,
+                                price: num!(200.0),
+                                amount: num!(1.0),
                             },
                             // Level does not exist & new value is 0 => no change
                             BinanceLevel {
-                                price: 500.0,
-                                amount: 0.0,
+                                price: num!(500.0),
+                                amount: num!(0.0),
                             },
                         ],
                     },
@@ -558,15 +593,18 @@ mod tests {
                         last_update_time: time,
                         bids: OrderBookSide::new(
                             Side::Buy,
-                            vec![Level::new(100, 1), Level::new(90, 10)],
+                            vec![
+                                Level::new(num!(100), num!(1)),
+                                Level::new(num!(90), num!(10)),
+                            ],
                         ),
                         asks: OrderBookSide::new(
                             Side::Sell,
                             vec![
-                                Level::new(110, 1),
-                                Level::new(120, 1),
-                                Level::new(150, 1),
-                                Level::new(200, 1),
+                                Level::new(num!(110), num!(1)),
+                                Level::new(num!(120), num!(1)),
+                                Level::new(num!(150), num!(1)),
+                                Level::new(num!(200), num!(1)),
                             ],
                         ),
                     })),
@@ -1,10 +1,10 @@
-use self::l2::BinanceSpotBookUpdater;
+use self::l2::{BinanceSpotBookUpdater, HTTP_BOOK_L2_SNAPSHOT_DEPTHS_BINANCE_SPOT};
 use super::{Binance, ExchangeServer};
 use crate::{
     exchange::{ExchangeId, StreamSelector},
-    subscription::book::OrderBooksL2,
-    transformer::book::MultiBookTransformer,
-    ExchangeWsStream,
+    subscription::book::{OrderBooksL2, OrderBooksL2Delta},
+    transformer::book::{MultiBookDeltaTransformer, MultiBookTransformer},
+    ExchangeWsStream, TimedExchangeWsStream,
 };
 
 /// Level 2 OrderBook types (top of book) and spot
@@ -33,5 +33,17 @@ impl ExchangeServer for BinanceServerSpot {
 
 impl StreamSelector<OrderBooksL2> for BinanceSpot {
     type Stream =
-        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceSpotBookUpdater>>;
+        TimedExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceSpotBookUpdater>>;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_snapshot_depth(|snapshot_depth| {
+            HTTP_BOOK_L2_SNAPSHOT_DEPTHS_BINANCE_SPOT.contains(&snapshot_depth)
+        })
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for BinanceSpot {
+    type Stream = ExchangeWsStream<
+        MultiBookDeltaTransformer<Self, OrderBooksL2Delta, BinanceSpotBookUpdater>,
+    >;
 }
@@ -0,0 +1,184 @@
+use super::BinanceChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    number::Number,
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Binance rolling 24h ticker message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-ticker-streams>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-ticker-streams>
+/// #### Spot Ticker
+/// ```json
+/// {
+///     "e": "24hrTicker",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "p": "0.0015",
+///     "P": "250.00",
+///     "w": "0.0018",
+///     "x": "0.0009",
+///     "c": "0.0025",
+///     "Q": "10",
+///     "b": "0.0024",
+///     "B": "10",
+///     "a": "0.0026",
+///     "A": "100",
+///     "o": "0.0010",
+///     "h": "0.0025",
+///     "l": "0.0010",
+///     "v": "10000",
+///     "q": "18",
+///     "O": 0,
+///     "C": 86400000,
+///     "F": 0,
+///     "L": 18150,
+///     "n": 18151
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceTicker {
+    #[serde(alias = "s", deserialize_with = "de_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub last: Number,
+    #[serde(alias = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: Number,
+    #[serde(alias = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: Number,
+    #[serde(alias = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: Number,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume_base: Number,
+    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub volume_quote: Number,
+    #[serde(alias = "P", deserialize_with = "barter_integration::de::de_str")]
+    pub change_percent: Number,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceTicker)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, BinanceTicker)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Ticker {
+                last: ticker.last,
+                open: Some(ticker.open),
+                high: ticker.high,
+                low: ticker.low,
+                volume_base: ticker.volume_base,
+                volume_quote: Some(ticker.volume_quote),
+                change_percent: Some(ticker.change_percent),
+                time: ticker.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceTicker`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@ticker|BTCUSDT").
+pub fn de_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::TICKERS, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_ticker() {
+            struct TestCase {
+                input: &'static str,
+                expected: BinanceTicker,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid Spot BinanceTicker
+                    input: r#"
+                    {
+                        "e": "24hrTicker", "E": 1672515782136, "s": "BNBBTC", "p": "0.0015",
+                        "P": "150.00", "w": "0.0018", "x": "0.0009", "c": "0.0025", "Q": "10",
+                        "b": "0.0024", "B": "10", "a": "0.0026", "A": "100", "o": "0.0010",
+                        "h": "0.0025", "l": "0.0010", "v": "10000", "q": "18", "O": 0,
+                        "C": 86400000, "F": 0, "L": 18150, "n": 18151
+                    }
+                    "#,
+                    expected: BinanceTicker {
+                        subscription_id: SubscriptionId::from("@ticker|BNBBTC"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        last: num!(0.0025),
+                        open: num!(0.0010),
+                        high: num!(0.0025),
+                        low: num!(0.0010),
+                        volume_base: num!(10000.0),
+                        volume_quote: num!(18.0),
+                        change_percent: num!(150.0),
+                    },
+                },
+                TestCase {
+                    // TC1: valid FuturePerpetual BinanceTicker
+                    input: r#"
+                    {
+                        "e": "24hrTicker", "E": 1672515782136, "s": "BTCUSDT", "p": "-200.50",
+                        "P": "-1.002", "w": "20100.10", "c": "19800.00", "Q": "0.010",
+                        "o": "20000.50", "h": "20300.00", "l": "19700.00", "v": "250000.123",
+                        "q": "5025000000.50", "O": 1672429380000, "C": 1672515782136,
+                        "F": 3000000, "L": 3100000, "n": 100001
+                    }
+                    "#,
+                    expected: BinanceTicker {
+                        subscription_id: SubscriptionId::from("@ticker|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        last: num!(19800.0),
+                        open: num!(20000.5),
+                        high: num!(20300.0),
+                        low: num!(19700.0),
+                        volume_base: num!(250000.123),
+                        volume_quote: num!(5025000000.5),
+                        change_percent: num!(-1.002),
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceTicker>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
@@ -0,0 +1,525 @@
+use crate::{
+    error::DataError,
+    number::{self, Number, ZERO},
+    subscription::book::{Level, OrderBook, OrderBookDelta, OrderBookSide},
+    transformer::book::{
+        InstrumentOrderBook, OrderBookChange, OrderBookDeltaUpdater, OrderBookUpdater,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// Number of [`Level`]s on each side of the [`Bitfinex`](super::super::Bitfinex) OrderBook
+/// subscribed to via the "book" channel "len" parameter.
+///
+/// Valid lengths are 1, 25 & 100.
+pub const BITFINEX_ORDER_BOOK_L2_LENGTH: u16 = 25;
+
+/// Number of [`Level`]s on each side of the [`OrderBook`] used to generate the
+/// [`Bitfinex`](super::super::Bitfinex) OrderBook checksum.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-websocket-checksum>
+const BITFINEX_CHECKSUM_DEPTH: usize = 25;
+
+/// [`Bitfinex`](super::super::Bitfinex) OrderBook Level2 message received over the "book"
+/// channel, identified by the `channel_id` (see the
+/// [`BitfinexMessage`](super::super::message::BitfinexMessage) for more details).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+/// #### Snapshot
+/// ```json
+/// [17082,[[7254.7,3,3.3],[7254.6,2,1.2],[7254.8,1,-0.5]]]
+/// ```
+///
+/// #### Update
+/// ```json
+/// [17082,[7254.5,0,1]]
+/// ```
+///
+/// #### Checksum
+/// See docs: <https://docs.bitfinex.com/docs/ws-websocket-checksum>
+/// ```json
+/// [17082,"cs",-1512791856]
+/// ```
+///
+/// #### Heartbeat
+/// ```json
+/// [17082,"hb"]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexOrderBookL2 {
+    pub channel_id: u32,
+    pub payload: BitfinexBookPayload,
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) OrderBook Level2 message variants.
+///
+/// See [`BitfinexOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum BitfinexBookPayload {
+    Heartbeat,
+    Snapshot(Vec<BitfinexLevel>),
+    Update(BitfinexLevel),
+    Checksum(i32),
+}
+
+impl Identifier<Option<SubscriptionId>> for BitfinexOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self.payload {
+            BitfinexBookPayload::Heartbeat => None,
+            _ => Some(SubscriptionId::from(self.channel_id.to_string())),
+        }
+    }
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) OrderBook Level2 price aggregated level.
+///
+/// ### Notes
+/// - Positive amounts are bids, negative amounts are asks.
+/// - A count of 0 indicates the level should be removed, with an amount of 1 for bids and -1
+///   for asks.
+///
+/// #### Raw Payload Examples
+/// Format: \[PRICE, COUNT, AMOUNT\]
+/// ```json
+/// [7254.7,3,3.3]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitfinexLevel {
+    pub price: Number,
+    pub count: u64,
+    pub amount: Number,
+}
+
+impl BitfinexLevel {
+    /// Determine the [`Side`] of the [`OrderBook`] this [`BitfinexLevel`] is associated with.
+    pub fn side(&self) -> Side {
+        if self.amount > ZERO {
+            Side::Buy
+        } else {
+            Side::Sell
+        }
+    }
+}
+
+impl From<BitfinexLevel> for Level {
+    fn from(level: BitfinexLevel) -> Self {
+        match level.count {
+            // Removal is communicated via an upsert with a zero amount
+            0 => Self::new(level.price, ZERO),
+            _ => Self::new(level.price, level.amount.abs()),
+        }
+    }
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) [`OrderBookUpdater`] that maintains an OrderBook Level2
+/// from the "book" channel, validating the "cs" checksum messages enabled via the
+/// [`BITFINEX_CONF_FLAG_CHECKSUM`](super::super::subscription::BITFINEX_CONF_FLAG_CHECKSUM)
+/// configuration flag.
+///
+/// ### Notes
+/// - The [`OrderBook`] is initialised by the WebSocket snapshot that Bitfinex sends as the first
+///   message of the "book" channel (returned by the
+///   [`BitfinexWebSocketSubValidator`](super::super::validator::BitfinexWebSocketSubValidator)
+///   if it is received during validation).
+/// - Any checksum mismatch is a terminal [`DataError::InvalidChecksum`].
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+/// See docs: <https://docs.bitfinex.com/docs/ws-websocket-checksum>
+#[derive(
+    Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct BitfinexBookUpdater;
+
+impl BitfinexBookUpdater {
+    /// Apply a [`BitfinexLevel`] to the associated [`OrderBookSide`], removing it if the count
+    /// is 0, and returning the [`Level`] if it changed the [`OrderBook`].
+    fn apply(book: &mut OrderBook, level: BitfinexLevel) -> Option<(Side, Level)> {
+        let side = level.side();
+        match side {
+            Side::Buy => book.bids.upsert_single_delta(level),
+            Side::Sell => book.asks.upsert_single_delta(level),
+        }
+        .map(|level| (side, level))
+    }
+
+    /// Generate the [`Bitfinex`](super::super::Bitfinex) checksum input by interleaving the
+    /// top 25 bids and asks as "bidPrice:bidAmount:askPrice:-askAmount:...".
+    pub fn checksum_input(book: &OrderBook) -> String {
+        let (mut bids, mut asks) = (book.bids.levels(), book.asks.levels());
+
+        let mut fields = Vec::with_capacity(BITFINEX_CHECKSUM_DEPTH * 4);
+        for _ in 0..BITFINEX_CHECKSUM_DEPTH {
+            if let Some(bid) = bids.next() {
+                fields.push(format_js_number(number::to_f64(bid.price)));
+                fields.push(format_js_number(number::to_f64(bid.amount)));
+            }
+            if let Some(ask) = asks.next() {
+                fields.push(format_js_number(number::to_f64(ask.price)));
+                fields.push(format_js_number(number::to_f64(-ask.amount)));
+            }
+        }
+
+        fields.join(":")
+    }
+
+    /// Generate the [`Bitfinex`](super::super::Bitfinex) signed CRC32 checksum of the
+    /// [`OrderBook`].
+    pub fn checksum(book: &OrderBook) -> i32 {
+        crc32fast::hash(Self::checksum_input(book).as_bytes()) as i32
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BitfinexBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BitfinexOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        _: Option<usize>,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // OrderBook is initialised by the first WebSocket snapshot
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self,
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+}
+
+impl OrderBookDeltaUpdater for BitfinexBookUpdater {
+    fn update_delta(
+        &mut self,
+        book: &mut OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookChange>, DataError> {
+        // Bitfinex raw OrderBook messages do not contain a timestamp
+        let last_update_time = Utc::now();
+
+        let change = match update.payload {
+            BitfinexBookPayload::Heartbeat => return Ok(None),
+            BitfinexBookPayload::Snapshot(levels) => {
+                book.bids = OrderBookSide::new(Side::Buy, Vec::<Level>::new());
+                book.asks = OrderBookSide::new(Side::Sell, Vec::<Level>::new());
+                for level in levels {
+                    Self::apply(book, level);
+                }
+                OrderBookChange::Snapshot
+            }
+            BitfinexBookPayload::Update(level) => {
+                let (bids, asks) = match Self::apply(book, level) {
+                    Some((Side::Buy, level)) => (vec![level], vec![]),
+                    Some((Side::Sell, level)) => (vec![], vec![level]),
+                    None => (vec![], vec![]),
+                };
+
+                OrderBookChange::Delta(OrderBookDelta {
+                    last_update_time,
+                    prev_sequence: None,
+                    sequence: None,
+                    bids,
+                    asks,
+                })
+            }
+            BitfinexBookPayload::Checksum(expected) => {
+                let actual = Self::checksum(book);
+
+                return if actual == expected {
+                    Ok(None)
+                } else {
+                    Err(DataError::InvalidChecksum {
+                        expected: expected as u32,
+                        actual: actual as u32,
+                    })
+                };
+            }
+        };
+
+        book.last_update_time = last_update_time;
+        Ok(Some(change))
+    }
+}
+
+/// Format an `f64` the same way as the JavaScript `Number.prototype.toString()` used by
+/// [`Bitfinex`](super::super::Bitfinex) to generate it's checksum.
+///
+/// eg/ 0.3 -> "0.3", -2.0 -> "-2", 0.0000005 -> "5e-7"
+fn format_js_number(value: f64) -> String {
+    if value != 0.0 && value.abs() < 1e-6 {
+        format!("{value:e}")
+    } else {
+        value.to_string()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexOrderBookL2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        /// Second element of a [`BitfinexOrderBookL2`] sequence.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Element {
+            Tag(String),
+            Snapshot(Vec<BitfinexLevel>),
+            Update(BitfinexLevel),
+        }
+
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexOrderBookL2;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexOrderBookL2 struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Snapshot: [CHANNEL_ID, [[PRICE, COUNT, AMOUNT], ...]]
+                // Update: [CHANNEL_ID, [PRICE, COUNT, AMOUNT]]
+                // Checksum: [CHANNEL_ID, "cs", CHECKSUM]
+                // Heartbeat: [CHANNEL_ID, "hb"]
+
+                // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+
+                // Extract payload or message tag: 2nd element of the sequence
+                let payload = match extract_next::<SeqAccessor, Element>(&mut seq, "payload")? {
+                    Element::Snapshot(levels) => BitfinexBookPayload::Snapshot(levels),
+                    Element::Update(level) => BitfinexBookPayload::Update(level),
+                    Element::Tag(tag) => match tag.as_str() {
+                        "hb" => BitfinexBookPayload::Heartbeat,
+                        "cs" => BitfinexBookPayload::Checksum(extract_next(&mut seq, "checksum")?),
+                        other => {
+                            return Err(serde::de::Error::unknown_variant(
+                                other,
+                                &["heartbeat (hb)", "checksum (cs)"],
+                            ))
+                        }
+                    },
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(BitfinexOrderBookL2 {
+                    channel_id,
+                    payload,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the WebSocket BitfinexOrderBookL2
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    fn level(price: Number, count: u64, amount: Number) -> BitfinexLevel {
+        BitfinexLevel {
+            price,
+            count,
+            amount,
+        }
+    }
+
+    fn book(bids: Vec<Level>, asks: Vec<Level>) -> OrderBook {
+        OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, bids),
+            asks: OrderBookSide::new(Side::Sell, asks),
+        }
+    }
+
+    #[test]
+    fn test_de_bitfinex_order_book_l2() {
+        struct TestCase {
+            input: &'static str,
+            expected: BitfinexBookPayload,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot
+                input: r#"[17082,[[7254.7,3,3.3],[7254.8,1,-0.5]]]"#,
+                expected: BitfinexBookPayload::Snapshot(vec![
+                    level(num!(7254.7), 3, num!(3.3)),
+                    level(num!(7254.8), 1, num!(-0.5)),
+                ]),
+            },
+            TestCase {
+                // TC1: update removing a bid
+                input: r#"[17082,[7254.5,0,1]]"#,
+                expected: BitfinexBookPayload::Update(level(num!(7254.5), 0, num!(1.0))),
+            },
+            TestCase {
+                // TC2: checksum
+                input: r#"[17082,"cs",-1512791856]"#,
+                expected: BitfinexBookPayload::Checksum(-1512791856),
+            },
+            TestCase {
+                // TC3: heartbeat
+                input: r#"[17082,"hb"]"#,
+                expected: BitfinexBookPayload::Heartbeat,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitfinexOrderBookL2>(test.input).unwrap();
+            assert_eq!(
+                actual,
+                BitfinexOrderBookL2 {
+                    channel_id: 17082,
+                    payload: test.expected
+                },
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_bitfinex_book_updater_checksum_input() {
+        let book = book(
+            vec![
+                Level::new(num!(7254.7), num!(0.3)),
+                Level::new(num!(7254.6), num!(1.5)),
+            ],
+            vec![
+                Level::new(num!(7254.8), num!(2.0)),
+                Level::new(num!(7255.0), num!(0.0000005)),
+            ],
+        );
+
+        assert_eq!(
+            BitfinexBookUpdater::checksum_input(&book),
+            "7254.7:0.3:7254.8:-2:7254.6:1.5:7255:-5e-7"
+        );
+    }
+
+    #[test]
+    fn test_bitfinex_book_updater_update() {
+        struct TestCase {
+            input: BitfinexBookPayload,
+            expected: Result<Option<(Vec<Level>, Vec<Level>)>, ()>,
+        }
+
+        let bids = vec![
+            Level::new(num!(100.0), num!(1.0)),
+            Level::new(num!(99.0), num!(2.0)),
+        ];
+        let asks = vec![Level::new(num!(101.0), num!(1.0))];
+        let valid_checksum = BitfinexBookUpdater::checksum(&book(bids.clone(), asks.clone()));
+
+        let tests = vec![
+            TestCase {
+                // TC0: update w/ count > 0 upserts level on the side determined by the amount
+                input: BitfinexBookPayload::Update(level(num!(101.5), 2, num!(-3.0))),
+                expected: Ok(Some((
+                    bids.clone(),
+                    vec![
+                        Level::new(num!(101.0), num!(1.0)),
+                        Level::new(num!(101.5), num!(3.0)),
+                    ],
+                ))),
+            },
+            TestCase {
+                // TC1: update w/ count = 0 & amount = 1 removes bid level
+                input: BitfinexBookPayload::Update(level(num!(100.0), 0, num!(1.0))),
+                expected: Ok(Some((
+                    vec![Level::new(num!(99.0), num!(2.0))],
+                    asks.clone(),
+                ))),
+            },
+            TestCase {
+                // TC2: update w/ count = 0 & amount = -1 removes ask level
+                input: BitfinexBookPayload::Update(level(num!(101.0), 0, num!(-1.0))),
+                expected: Ok(Some((bids.clone(), vec![]))),
+            },
+            TestCase {
+                // TC3: snapshot replaces the OrderBook
+                input: BitfinexBookPayload::Snapshot(vec![
+                    level(num!(90.0), 1, num!(1.0)),
+                    level(num!(91.0), 1, num!(-1.0)),
+                ]),
+                expected: Ok(Some((
+                    vec![Level::new(num!(90.0), num!(1.0))],
+                    vec![Level::new(num!(91.0), num!(1.0))],
+                ))),
+            },
+            TestCase {
+                // TC4: matching checksum is ignored
+                input: BitfinexBookPayload::Checksum(valid_checksum),
+                expected: Ok(None),
+            },
+            TestCase {
+                // TC5: checksum mismatch is a terminal error
+                input: BitfinexBookPayload::Checksum(valid_checksum.wrapping_add(1)),
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut book = book(bids.clone(), asks.clone());
+            let update = BitfinexOrderBookL2 {
+                channel_id: 1,
+                payload: test.input,
+            };
+
+            match (BitfinexBookUpdater.update(&mut book, update), test.expected) {
+                (Ok(Some(actual)), Ok(Some((bids, asks)))) => {
+                    assert_eq!(
+                        actual.bids,
+                        OrderBookSide::new(Side::Buy, bids),
+                        "TC{} failed",
+                        index
+                    );
+                    assert_eq!(
+                        actual.asks,
+                        OrderBookSide::new(Side::Sell, asks),
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (Ok(None), Ok(None)) => {
+                    // Test passed
+                }
+                (Err(error), Err(_)) => assert!(error.is_terminal(), "TC{} failed", index),
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
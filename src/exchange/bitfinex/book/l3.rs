@@ -0,0 +1,400 @@
+use crate::{
+    error::DataError,
+    number::{Number, ZERO},
+    subscription::book::{Order, OrderBookL3, OrderBookL3Delta},
+    transformer::book::{
+        InstrumentOrderBook, OrderBookL3Change, OrderBookL3DeltaUpdater, OrderBookUpdater,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// Number of orders on each side of the [`Bitfinex`](super::super::Bitfinex) raw OrderBook
+/// subscribed to via the "book" channel "len" parameter.
+///
+/// Valid lengths are 1, 25 & 100.
+pub const BITFINEX_ORDER_BOOK_L3_LENGTH: u16 = 100;
+
+/// [`Bitfinex`](super::super::Bitfinex) raw OrderBook message received over the "book" channel
+/// subscribed to with "R0" precision, identified by the `channel_id` (see the
+/// [`BitfinexMessage`](super::super::message::BitfinexMessage) for more details).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+/// #### Snapshot
+/// ```json
+/// [17082,[[34668169,7254.7,3.3],[34668170,7254.8,-0.5]]]
+/// ```
+///
+/// #### Update
+/// ```json
+/// [17082,[34668169,0,1]]
+/// ```
+///
+/// #### Heartbeat
+/// ```json
+/// [17082,"hb"]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexOrderBookL3 {
+    pub channel_id: u32,
+    pub payload: BitfinexBookL3Payload,
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) raw OrderBook message variants.
+///
+/// See [`BitfinexOrderBookL3`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum BitfinexBookL3Payload {
+    Heartbeat,
+    Snapshot(Vec<BitfinexOrder>),
+    Update(BitfinexOrder),
+}
+
+impl Identifier<Option<SubscriptionId>> for BitfinexOrderBookL3 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self.payload {
+            BitfinexBookL3Payload::Heartbeat => None,
+            _ => Some(SubscriptionId::from(self.channel_id.to_string())),
+        }
+    }
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) raw OrderBook order.
+///
+/// ### Notes
+/// - Positive amounts are bids, negative amounts are asks.
+/// - A price of 0 indicates the order should be removed.
+///
+/// #### Raw Payload Examples
+/// Format: \[ORDER_ID, PRICE, AMOUNT\]
+/// ```json
+/// [34668169,7254.7,3.3]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitfinexOrder {
+    pub id: u64,
+    pub price: Number,
+    pub amount: Number,
+}
+
+impl BitfinexOrder {
+    /// Determine the [`Side`] of the [`OrderBookL3`] this [`BitfinexOrder`] is associated with.
+    pub fn side(&self) -> Side {
+        if self.amount > ZERO {
+            Side::Buy
+        } else {
+            Side::Sell
+        }
+    }
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) [`OrderBookL3DeltaUpdater`] that maintains an
+/// [`OrderBookL3`] from the "book" channel subscribed to with "R0" precision.
+///
+/// ### Notes
+/// - The [`OrderBookL3`] is initialised by the WebSocket snapshot that Bitfinex sends as the
+///   first message of the raw "book" channel (returned by the
+///   [`BitfinexWebSocketSubValidator`](super::super::validator::BitfinexWebSocketSubValidator)
+///   if it is received during validation).
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+#[derive(
+    Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct BitfinexL3BookUpdater;
+
+impl BitfinexL3BookUpdater {
+    /// Apply a [`BitfinexOrder`] to the [`OrderBookL3`], removing it if the price is 0.
+    ///
+    /// Returns the changed [`Order`] if the [`OrderBookL3`] changed, with a zero amount if it
+    /// was removed.
+    fn apply(book: &mut OrderBookL3, order: BitfinexOrder) -> Option<Order> {
+        if order.price == ZERO {
+            return book.remove(&order.id.to_string()).map(|removed| Order {
+                amount: ZERO,
+                ..removed
+            });
+        }
+
+        let order = Order {
+            id: order.id.to_string(),
+            side: order.side(),
+            price: order.price,
+            amount: order.amount.abs(),
+            time: book.last_update_time,
+        };
+        book.upsert(order.clone());
+        Some(order)
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BitfinexL3BookUpdater {
+    type OrderBook = OrderBookL3;
+    type Update = BitfinexOrderBookL3;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        _: Option<usize>,
+    ) -> Result<InstrumentOrderBook<Self, OrderBookL3>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // OrderBookL3 is initialised by the first WebSocket snapshot
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self,
+            book: OrderBookL3 {
+                last_update_time: Utc::now(),
+                orders: Default::default(),
+            },
+        })
+    }
+}
+
+impl OrderBookL3DeltaUpdater for BitfinexL3BookUpdater {
+    fn update_delta(
+        &mut self,
+        book: &mut OrderBookL3,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookL3Change>, DataError> {
+        // Bitfinex raw OrderBook messages do not contain a timestamp
+        let last_update_time = Utc::now();
+
+        match update.payload {
+            BitfinexBookL3Payload::Heartbeat => Ok(None),
+            BitfinexBookL3Payload::Snapshot(orders) => {
+                book.last_update_time = last_update_time;
+                book.orders.clear();
+                for order in orders {
+                    Self::apply(book, order);
+                }
+                Ok(Some(OrderBookL3Change::Snapshot))
+            }
+            BitfinexBookL3Payload::Update(order) => {
+                book.last_update_time = last_update_time;
+                Ok(Self::apply(book, order).map(|order| {
+                    OrderBookL3Change::Delta(OrderBookL3Delta {
+                        last_update_time,
+                        sequence: None,
+                        orders: vec![order],
+                    })
+                }))
+            }
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexOrderBookL3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        /// Second element of a [`BitfinexOrderBookL3`] sequence.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Element {
+            Tag(String),
+            Snapshot(Vec<BitfinexOrder>),
+            Update(BitfinexOrder),
+        }
+
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexOrderBookL3;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexOrderBookL3 struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Snapshot: [CHANNEL_ID, [[ORDER_ID, PRICE, AMOUNT], ...]]
+                // Update: [CHANNEL_ID, [ORDER_ID, PRICE, AMOUNT]]
+                // Heartbeat: [CHANNEL_ID, "hb"]
+
+                // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+
+                // Extract payload or message tag: 2nd element of the sequence
+                let payload = match extract_next::<SeqAccessor, Element>(&mut seq, "payload")? {
+                    Element::Snapshot(orders) => BitfinexBookL3Payload::Snapshot(orders),
+                    Element::Update(order) => BitfinexBookL3Payload::Update(order),
+                    Element::Tag(tag) => match tag.as_str() {
+                        "hb" => BitfinexBookL3Payload::Heartbeat,
+                        other => {
+                            return Err(serde::de::Error::unknown_variant(
+                                other,
+                                &["heartbeat (hb)"],
+                            ))
+                        }
+                    },
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(BitfinexOrderBookL3 {
+                    channel_id,
+                    payload,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the WebSocket BitfinexOrderBookL3
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    fn order(id: u64, price: Number, amount: Number) -> BitfinexOrder {
+        BitfinexOrder { id, price, amount }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bitfinex_order_book_l3() {
+            struct TestCase {
+                input: &'static str,
+                expected: BitfinexOrderBookL3,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input BitfinexBookL3Payload::Snapshot is deserialised
+                    input: r#"[17082,[[34668169,7254.7,3.3],[34668170,7254.8,-0.5]]]"#,
+                    expected: BitfinexOrderBookL3 {
+                        channel_id: 17082,
+                        payload: BitfinexBookL3Payload::Snapshot(vec![
+                            order(34668169, num!(7254.7), num!(3.3)),
+                            order(34668170, num!(7254.8), num!(-0.5)),
+                        ]),
+                    },
+                },
+                TestCase {
+                    // TC1: input BitfinexBookL3Payload::Update is deserialised
+                    input: r#"[17082,[34668169,0,1]]"#,
+                    expected: BitfinexOrderBookL3 {
+                        channel_id: 17082,
+                        payload: BitfinexBookL3Payload::Update(order(
+                            34668169,
+                            num!(0.0),
+                            num!(1.0),
+                        )),
+                    },
+                },
+                TestCase {
+                    // TC2: input BitfinexBookL3Payload::Heartbeat is deserialised
+                    input: r#"[17082,"hb"]"#,
+                    expected: BitfinexOrderBookL3 {
+                        channel_id: 17082,
+                        payload: BitfinexBookL3Payload::Heartbeat,
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitfinexOrderBookL3>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitfinex_l3_book_updater_update() {
+        struct TestCase {
+            input: BitfinexBookL3Payload,
+            expected: Option<Vec<(&'static str, Side, Number, Number)>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: heartbeat does not change the OrderBookL3
+                input: BitfinexBookL3Payload::Heartbeat,
+                expected: None,
+            },
+            TestCase {
+                // TC1: update w/ new order id inserts an ask Order
+                input: BitfinexBookL3Payload::Update(order(3, num!(102.0), num!(-2.0))),
+                expected: Some(vec![
+                    ("1", Side::Buy, num!(100.0), num!(1.0)),
+                    ("2", Side::Sell, num!(101.0), num!(1.0)),
+                    ("3", Side::Sell, num!(102.0), num!(2.0)),
+                ]),
+            },
+            TestCase {
+                // TC2: update w/ existing order id replaces the Order
+                input: BitfinexBookL3Payload::Update(order(1, num!(99.0), num!(5.0))),
+                expected: Some(vec![
+                    ("1", Side::Buy, num!(99.0), num!(5.0)),
+                    ("2", Side::Sell, num!(101.0), num!(1.0)),
+                ]),
+            },
+            TestCase {
+                // TC3: update w/ price 0 removes the Order
+                input: BitfinexBookL3Payload::Update(order(2, num!(0.0), num!(-1.0))),
+                expected: Some(vec![("1", Side::Buy, num!(100.0), num!(1.0))]),
+            },
+            TestCase {
+                // TC4: snapshot replaces the OrderBookL3
+                input: BitfinexBookL3Payload::Snapshot(vec![order(4, num!(98.0), num!(1.5))]),
+                expected: Some(vec![("4", Side::Buy, num!(98.0), num!(1.5))]),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut book = OrderBookL3::default();
+            BitfinexL3BookUpdater::apply(&mut book, order(1, num!(100.0), num!(1.0)));
+            BitfinexL3BookUpdater::apply(&mut book, order(2, num!(101.0), num!(-1.0)));
+
+            let update = BitfinexOrderBookL3 {
+                channel_id: 17082,
+                payload: test.input,
+            };
+
+            let actual = BitfinexL3BookUpdater
+                .update(&mut book, update)
+                .unwrap()
+                .map(|snapshot| {
+                    let mut orders = snapshot
+                        .orders
+                        .into_values()
+                        .map(|order| (order.id, order.side, order.price, order.amount))
+                        .collect::<Vec<_>>();
+                    orders.sort_by(|a, b| a.0.cmp(&b.0));
+                    orders
+                });
+            let expected = test.expected.map(|orders| {
+                orders
+                    .into_iter()
+                    .map(|(id, side, price, amount)| (id.to_string(), side, price, amount))
+                    .collect::<Vec<_>>()
+            });
+
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// Level 3 (raw) OrderBook types.
+pub mod l3;
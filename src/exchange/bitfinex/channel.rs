@@ -1,6 +1,12 @@
 use super::Bitfinex;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta, OrderBooksL3},
+        candle::Candles,
+        ticker::Tickers,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +23,23 @@ impl BitfinexChannel {
     ///
     /// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Bitfinex`] real-time OrderBook Level2 channel (subscribed to with "P0" precision).
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+    pub const ORDER_BOOK_L2: Self = Self("book");
+
+    /// [`Bitfinex`] real-time raw OrderBook Level3 channel. This is the "book" channel subscribed
+    /// to with "R0" precision, so it is given a distinct identifier to differentiate its
+    /// [`SubscriptionId`](barter_integration::model::SubscriptionId).
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+    pub const ORDER_BOOK_L3: Self = Self("book_raw");
+
+    /// [`Bitfinex`] real-time rolling 24h ticker channel.
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-ticker>
+    pub const TICKERS: Self = Self("ticker");
 }
 
 impl Identifier<BitfinexChannel> for Subscription<Bitfinex, PublicTrades> {
@@ -25,6 +48,47 @@ impl Identifier<BitfinexChannel> for Subscription<Bitfinex, PublicTrades> {
     }
 }
 
+/// [`Candles`] are aggregated from the trades channel since Bitfinex does not offer native
+/// candle streams (see [`TradeCandleTransformer`](crate::transformer::candle::TradeCandleTransformer)).
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, Candles> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::TRADES
+    }
+}
+
+/// [`OrderBooksL1`] are synthesised from the OrderBook Level2 channel since [`Bitfinex`] does not
+/// offer a native top of book channel (see
+/// [`OrderBookL1Transformer`](crate::transformer::book::OrderBookL1Transformer)).
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, OrderBooksL1> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, OrderBooksL2> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, OrderBooksL2Delta> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, OrderBooksL3> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L3
+    }
+}
+
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, Tickers> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::TICKERS
+    }
+}
+
 impl AsRef<str> for BitfinexChannel {
     fn as_ref(&self) -> &str {
         self.0
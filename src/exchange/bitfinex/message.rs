@@ -132,6 +132,7 @@ impl<'de> serde::Deserialize<'de> for BitfinexMessage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::number::num;
     use barter_integration::{
         de::datetime_utc_from_epoch_duration, error::SocketError, model::Side,
     };
@@ -160,8 +161,8 @@ mod tests {
                             1665452200022,
                         )),
                         side: Side::Sell,
-                        price: 19027.02807752,
-                        amount: 0.08980641,
+                        price: num!(19027.02807752),
+                        amount: num!(0.08980641),
                     }),
                 }),
             },
@@ -176,8 +177,8 @@ mod tests {
                             1665452200022,
                         )),
                         side: Side::Buy,
-                        price: 19027.02807752,
-                        amount: 0.08980641,
+                        price: num!(19027.02807752),
+                        amount: num!(0.08980641),
                     }),
                 }),
             },
@@ -18,23 +18,51 @@
 //! - Bitfinex trades subscriptions results in receiving tag="te" & tag="tu" trades.
 //! - Both appear to be identical payloads, but "te" arriving marginally faster.
 //! - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
+//!
+//! #### OrderBook Checksums
+//! - If any "book" channel is subscribed to, a "conf" event enabling checksum messages is sent
+//!   before the subscriptions, causing Bitfinex to send a "cs" message after every book update.
+//! - Raw "book" channels (precision "R0") used for OrderBooksL3 are not checksum validated.
 
 use self::{
-    channel::BitfinexChannel, market::BitfinexMarket, message::BitfinexMessage,
-    subscription::BitfinexPlatformEvent, validator::BitfinexWebSocketSubValidator,
+    book::{
+        l2::{BitfinexBookUpdater, BITFINEX_ORDER_BOOK_L2_LENGTH},
+        l3::{BitfinexL3BookUpdater, BITFINEX_ORDER_BOOK_L3_LENGTH},
+    },
+    channel::BitfinexChannel,
+    market::BitfinexMarket,
+    message::BitfinexMessage,
+    subscription::{BitfinexPlatformEvent, BITFINEX_CONF_FLAG_CHECKSUM},
+    ticker::BitfinexTicker,
+    validator::BitfinexWebSocketSubValidator,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::WebSocketSubscriber,
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta, OrderBooksL3},
+        candle::Candles,
+        ticker::Tickers,
+        trade::PublicTrades,
+    },
+    transformer::{
+        book::{
+            MultiBookDeltaTransformer, MultiBookL3Transformer, MultiBookTransformer,
+            OrderBookL1Transformer,
+        },
+        candle::TradeCandleTransformer,
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, TimedExchangeWsStream,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
 
+/// Order book types for [`Bitfinex`].
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -50,6 +78,9 @@ pub mod message;
 /// [`Validator`](barter_integration::Validator) for [`Bitfinex`].
 pub mod subscription;
 
+/// Rolling 24h ticker types for [`Bitfinex`].
+pub mod ticker;
+
 /// Public trade types for [`Bitfinex`].
 pub mod trade;
 
@@ -83,22 +114,83 @@ impl Connector for Bitfinex {
     }
 
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        exchange_subs
-            .into_iter()
-            .map(|ExchangeSub { channel, market }| {
+        // Enable OrderBook checksum messages before subscribing to any "book" channels
+        let conf = exchange_subs
+            .iter()
+            .any(|sub| sub.channel == BitfinexChannel::ORDER_BOOK_L2)
+            .then(|| {
                 WsMessage::Text(
                     json!({
-                        "event": "subscribe",
-                        "channel": channel.as_ref(),
-                        "symbol": market.as_ref(),
+                        "event": "conf",
+                        "flags": BITFINEX_CONF_FLAG_CHECKSUM,
                     })
                     .to_string(),
                 )
-            })
-            .collect()
+            });
+
+        let subscriptions = exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { channel, market }| {
+                let request = match channel {
+                    BitfinexChannel::ORDER_BOOK_L2 => json!({
+                        "event": "subscribe",
+                        "channel": channel.as_ref(),
+                        "symbol": market.as_ref(),
+                        "prec": "P0",
+                        "freq": "F0",
+                        "len": BITFINEX_ORDER_BOOK_L2_LENGTH.to_string(),
+                    }),
+                    BitfinexChannel::ORDER_BOOK_L3 => json!({
+                        "event": "subscribe",
+                        "channel": BitfinexChannel::ORDER_BOOK_L2.as_ref(),
+                        "symbol": market.as_ref(),
+                        "prec": "R0",
+                        "len": BITFINEX_ORDER_BOOK_L3_LENGTH.to_string(),
+                    }),
+                    _ => json!({
+                        "event": "subscribe",
+                        "channel": channel.as_ref(),
+                        "symbol": market.as_ref(),
+                    }),
+                };
+
+                WsMessage::Text(request.to_string())
+            });
+
+        conf.into_iter().chain(subscriptions).collect()
     }
 }
 
 impl StreamSelector<PublicTrades> for Bitfinex {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BitfinexMessage>>;
 }
+
+impl StreamSelector<Candles> for Bitfinex {
+    type Stream = TimedExchangeWsStream<TradeCandleTransformer<Self, BitfinexMessage>>;
+}
+
+impl StreamSelector<Tickers> for Bitfinex {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Tickers, BitfinexTicker>>;
+}
+
+impl StreamSelector<OrderBooksL1> for Bitfinex {
+    type Stream = ExchangeWsStream<OrderBookL1Transformer<Self, BitfinexBookUpdater>>;
+}
+
+impl StreamSelector<OrderBooksL2> for Bitfinex {
+    type Stream =
+        TimedExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BitfinexBookUpdater>>;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for Bitfinex {
+    type Stream =
+        ExchangeWsStream<MultiBookDeltaTransformer<Self, OrderBooksL2Delta, BitfinexBookUpdater>>;
+}
+
+impl StreamSelector<OrderBooksL3> for Bitfinex {
+    type Stream = ExchangeWsStream<MultiBookL3Transformer<Self, BitfinexL3BookUpdater>>;
+}
@@ -1,3 +1,4 @@
+use super::channel::BitfinexChannel;
 use barter_integration::{error::SocketError, Validator};
 use serde::{Deserialize, Serialize};
 
@@ -28,6 +29,15 @@ use serde::{Deserialize, Serialize};
 /// }
 /// ```
 ///
+/// #### Configuration Success
+/// ``` json
+/// {
+///   "event": "conf",
+///   "status": "OK",
+///   "flags": 131072
+/// }
+/// ```
+///
 /// #### Subscription Failure
 /// ``` json
 /// {
@@ -40,6 +50,7 @@ use serde::{Deserialize, Serialize};
 pub enum BitfinexPlatformEvent {
     #[serde(rename = "info")]
     PlatformStatus(BitfinexPlatformStatus),
+    Conf(BitfinexConf),
     Subscribed(BitfinexSubResponse),
     Error(BitfinexError),
 }
@@ -57,7 +68,13 @@ impl Validator for BitfinexPlatformEvent {
                     status.api_version, status.server_id,
                 ))),
             },
-            BitfinexPlatformEvent::Subscribed(_) => Ok(self),
+            BitfinexPlatformEvent::Conf(conf) if conf.status != BITFINEX_CONF_STATUS_OK => {
+                Err(SocketError::Subscribe(format!(
+                    "received failure configuration response status: {} for flags: {}",
+                    conf.status, conf.flags,
+                )))
+            }
+            BitfinexPlatformEvent::Conf(_) | BitfinexPlatformEvent::Subscribed(_) => Ok(self),
             BitfinexPlatformEvent::Error(error) => Err(SocketError::Subscribe(format!(
                 "received failure subscription response code: {} with message: {}",
                 error.code, error.msg,
@@ -115,6 +132,34 @@ pub enum Status {
     Operative,
 }
 
+/// [`Bitfinex`](super::Bitfinex) configuration event flag that enables "cs" checksum messages
+/// for all OrderBook channels on the connection.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#configuration>
+pub const BITFINEX_CONF_FLAG_CHECKSUM: u64 = 131072;
+
+/// [`BitfinexConf`] status indicating the configuration flags were applied.
+const BITFINEX_CONF_STATUS_OK: &str = "OK";
+
+/// [`Bitfinex`](super::Bitfinex) configuration response, received after sending a "conf" event
+/// with the desired connection flags (eg/ [`BITFINEX_CONF_FLAG_CHECKSUM`]).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#configuration>
+/// ``` json
+/// {
+///   "event": "conf",
+///   "status": "OK",
+///   "flags": 131072
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BitfinexConf {
+    pub status: String,
+    #[serde(default)]
+    pub flags: u64,
+}
+
 /// [`Bitfinex`](super::Bitfinex) subscription success response variants for each channel.
 ///
 /// ### Raw Payload Examples
@@ -144,6 +189,19 @@ pub struct BitfinexSubResponse {
     pub market: String,
     #[serde(rename = "chanId")]
     pub channel_id: BitfinexChannelId,
+    #[serde(rename = "prec", default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<String>,
+}
+
+impl BitfinexSubResponse {
+    /// Determine the [`BitfinexChannel`] that was subscribed to, distinguishing raw "book"
+    /// channel subscriptions (precision "R0") from price aggregated ones.
+    pub fn subscribed_channel(&self) -> &str {
+        match (self.channel.as_str(), self.precision.as_deref()) {
+            ("book", Some("R0")) => BitfinexChannel::ORDER_BOOK_L3.as_ref(),
+            (channel, _) => channel,
+        }
+    }
 }
 
 /// [`Bitfinex`](super::Bitfinex) channel identifier that is used to identify the subscription
@@ -249,9 +307,28 @@ mod tests {
                     channel: "trades".to_string(),
                     channel_id: BitfinexChannelId(2203),
                     market: "tBTCUSD".to_owned(),
+                    precision: None,
                 })),
             },
-            // TC2: Input response is error
+            // TC2: successful checksum configuration
+            TestCase {
+                input: r#"{"event": "conf", "status": "OK", "flags": 131072}"#,
+                expected: Ok(BitfinexPlatformEvent::Conf(BitfinexConf {
+                    status: "OK".to_string(),
+                    flags: BITFINEX_CONF_FLAG_CHECKSUM,
+                })),
+            },
+            // TC3: successful raw book channel subscription
+            TestCase {
+                input: r#"{"event": "subscribed", "channel": "book", "chanId": 2204, "symbol": "tBTCUSD", "prec": "R0", "freq": "F0", "len": "100", "pair": "BTCUSD"}"#,
+                expected: Ok(BitfinexPlatformEvent::Subscribed(BitfinexSubResponse {
+                    channel: "book".to_string(),
+                    channel_id: BitfinexChannelId(2204),
+                    market: "tBTCUSD".to_owned(),
+                    precision: Some("R0".to_string()),
+                })),
+            },
+            // TC4: Input response is error
             TestCase {
                 input: r#"{"event": "error", "msg": "Already subscribed", "code": 10202}"#,
                 expected: Ok(BitfinexPlatformEvent::Error(BitfinexError {
@@ -319,11 +396,13 @@ mod tests {
                     channel: "channel".to_string(),
                     market: "market".to_string(),
                     channel_id: BitfinexChannelId(1),
+                    precision: None,
                 }),
                 expected: Ok(BitfinexPlatformEvent::Subscribed(BitfinexSubResponse {
                     channel: "channel".to_string(),
                     market: "market".to_string(),
                     channel_id: BitfinexChannelId(1),
+                    precision: None,
                 })),
             },
             TestCase {
@@ -337,6 +416,17 @@ mod tests {
                     0, "error message",
                 ))),
             },
+            TestCase {
+                // TC4: configuration failure
+                input: BitfinexPlatformEvent::Conf(BitfinexConf {
+                    status: "ERROR".to_string(),
+                    flags: BITFINEX_CONF_FLAG_CHECKSUM,
+                }),
+                expected: Err(SocketError::Subscribe(format!(
+                    "received failure configuration response status: {} for flags: {}",
+                    "ERROR", BITFINEX_CONF_FLAG_CHECKSUM,
+                ))),
+            },
         ];
 
         for (index, test) in tests.into_iter().enumerate() {
@@ -0,0 +1,271 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    number::{Number, ONE_HUNDRED},
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_integration::{
+    de::extract_next,
+    model::{instrument::Instrument, Exchange, SubscriptionId},
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`Bitfinex`](super::Bitfinex) rolling 24h ticker message received over the "ticker" channel,
+/// identified by the `channel_id` (see the [`BitfinexMessage`](super::message::BitfinexMessage)
+/// for more details).
+///
+/// ### Notes
+/// The first ticker received after subscribing is consumed during subscription validation
+/// (see [`BitfinexWebSocketSubValidator`](super::validator::BitfinexWebSocketSubValidator)).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-ticker>
+/// #### Ticker
+/// ```json
+/// [17470,[7616.5,31.89055171,7617.5,43.358118629999986,-550.8,-0.0674,7617.1,8314.71200815,8257.8,7500]]
+/// ```
+///
+/// #### Heartbeat
+/// ```json
+/// [17470,"hb"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexTicker {
+    pub channel_id: u32,
+    pub payload: BitfinexTickerPayload,
+}
+
+/// [`Bitfinex`](super::Bitfinex) rolling 24h ticker message variants.
+///
+/// See [`BitfinexTicker`] for full raw payload examples.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum BitfinexTickerPayload {
+    Heartbeat,
+    Ticker(BitfinexTickerData),
+}
+
+/// [`Bitfinex`](super::Bitfinex) rolling 24h ticker statistics.
+///
+/// #### Raw Payload Examples
+/// Format: \[BID, BID_SIZE, ASK, ASK_SIZE, DAILY_CHANGE, DAILY_CHANGE_RELATIVE, LAST_PRICE,
+/// VOLUME, HIGH, LOW\]
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-ticker>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexTickerData {
+    pub daily_change: Number,
+    pub daily_change_relative: Number,
+    pub last: Number,
+    pub volume: Number,
+    pub high: Number,
+    pub low: Number,
+}
+
+impl Identifier<Option<SubscriptionId>> for BitfinexTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self.payload {
+            BitfinexTickerPayload::Heartbeat => None,
+            BitfinexTickerPayload::Ticker(_) => {
+                Some(SubscriptionId::from(self.channel_id.to_string()))
+            }
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitfinexTicker)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, BitfinexTicker)) -> Self {
+        let ticker = match ticker.payload {
+            BitfinexTickerPayload::Heartbeat => return Self(vec![]),
+            BitfinexTickerPayload::Ticker(ticker) => ticker,
+        };
+
+        // Bitfinex tickers do not contain a timestamp, so use the received time
+        let time = Utc::now();
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Ticker {
+                last: ticker.last,
+                open: Some(ticker.last - ticker.daily_change),
+                high: ticker.high,
+                low: ticker.low,
+                volume_base: ticker.volume,
+                volume_quote: None,
+                change_percent: Some(ticker.daily_change_relative * ONE_HUNDRED),
+                time,
+            },
+        })])
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexTicker {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        /// Second element of a [`BitfinexTicker`] sequence.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Element {
+            Tag(String),
+            Ticker(BitfinexTickerData),
+        }
+
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexTicker;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexTicker struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Ticker: [CHANNEL_ID, [BID, BID_SIZE, ASK, ASK_SIZE, DAILY_CHANGE, ...]]
+                // Heartbeat: [CHANNEL_ID, "hb"]
+
+                // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+
+                // Extract payload or message tag: 2nd element of the sequence
+                let payload = match extract_next::<SeqAccessor, Element>(&mut seq, "payload")? {
+                    Element::Ticker(ticker) => BitfinexTickerPayload::Ticker(ticker),
+                    Element::Tag(tag) => match tag.as_str() {
+                        "hb" => BitfinexTickerPayload::Heartbeat,
+                        other => {
+                            return Err(serde::de::Error::unknown_variant(
+                                other,
+                                &["heartbeat (hb)"],
+                            ))
+                        }
+                    },
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(BitfinexTicker {
+                    channel_id,
+                    payload,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the WebSocket BitfinexTicker
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexTickerData {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexTickerData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexTickerData struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Ticker: [BID, BID_SIZE, ASK, ASK_SIZE, DAILY_CHANGE, DAILY_CHANGE_RELATIVE,
+                //          LAST_PRICE, VOLUME, HIGH, LOW]
+
+                // Extract best bid & ask, and ignore since they are not part of a Ticker
+                for field in ["bid", "bid_size", "ask", "ask_size"] {
+                    let _: serde::de::IgnoredAny = extract_next(&mut seq, field)?;
+                }
+
+                let daily_change = extract_next(&mut seq, "daily_change")?;
+                let daily_change_relative = extract_next(&mut seq, "daily_change_relative")?;
+                let last = extract_next(&mut seq, "last_price")?;
+                let volume = extract_next(&mut seq, "volume")?;
+                let high = extract_next(&mut seq, "high")?;
+                let low = extract_next(&mut seq, "low")?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(BitfinexTickerData {
+                    daily_change,
+                    daily_change_relative,
+                    last,
+                    volume,
+                    high,
+                    low,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BitfinexTickerData
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    #[test]
+    fn test_de_bitfinex_ticker() {
+        struct TestCase {
+            input: &'static str,
+            expected: BitfinexTickerPayload,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: ticker
+                input: r#"[17470,[7616.5,31.89055171,7617.5,43.358118629999986,-550.8,-0.0674,7617.1,8314.71200815,8257.8,7500]]"#,
+                expected: BitfinexTickerPayload::Ticker(BitfinexTickerData {
+                    daily_change: num!(-550.8),
+                    daily_change_relative: num!(-0.0674),
+                    last: num!(7617.1),
+                    volume: num!(8314.71200815),
+                    high: num!(8257.8),
+                    low: num!(7500.0),
+                }),
+            },
+            TestCase {
+                // TC1: heartbeat
+                input: r#"[17470,"hb"]"#,
+                expected: BitfinexTickerPayload::Heartbeat,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitfinexTicker>(test.input).unwrap();
+            assert_eq!(
+                actual,
+                BitfinexTicker {
+                    channel_id: 17470,
+                    payload: test.expected,
+                },
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
+    number::Number,
     subscription::trade::PublicTrade,
 };
 use barter_integration::{
@@ -41,8 +42,8 @@ pub struct BitfinexTrade {
     pub id: u64,
     pub time: DateTime<Utc>,
     pub side: Side,
-    pub price: f64,
-    pub amount: f64,
+    pub price: Number,
+    pub amount: Number,
 }
 
 impl From<(ExchangeId, Instrument, BitfinexTrade)> for MarketIter<PublicTrade> {
@@ -86,7 +87,7 @@ impl<'de> serde::Deserialize<'de> for BitfinexTrade {
                 // Trade: [ID, TIME, AMOUNT,PRICE]
                 let id = extract_next(&mut seq, "id")?;
                 let time_millis = extract_next(&mut seq, "time")?;
-                let amount: f64 = extract_next(&mut seq, "amount")?;
+                let amount: Number = extract_next(&mut seq, "amount")?;
                 let price = extract_next(&mut seq, "price")?;
                 let side = match amount.is_sign_positive() {
                     true => Side::Buy,
@@ -1,4 +1,7 @@
-use super::subscription::{BitfinexPlatformEvent, BitfinexSubResponse};
+use super::{
+    channel::BitfinexChannel,
+    subscription::{BitfinexPlatformEvent, BitfinexSubResponse},
+};
 use crate::{
     exchange::{Connector, ExchangeSub},
     subscriber::validator::SubscriptionValidator,
@@ -10,13 +13,14 @@ use barter_integration::{
     error::SocketError,
     model::{instrument::Instrument, SubscriptionId},
     protocol::{
-        websocket::{WebSocket, WebSocketParser},
+        websocket::{WebSocket, WebSocketParser, WsMessage},
         StreamParser,
     },
     Validator,
 };
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use tracing::debug;
 
 /// [`Bitfinex`](super::Bitfinex) specific [`SubscriptionValidator`].
@@ -30,6 +34,9 @@ use tracing::debug;
 /// - Therefore the [`SubscriptionId`] format must change during [`BitfinexWebSocketSubValidator::validate`]
 ///   to use the [`BitfinexChannelId`](super::subscription::BitfinexChannelId)
 ///   (see module level "SubscriptionId" documentation notes for more details).
+/// - Bitfinex sends a snapshot as the first message of every "trades" & "book" channel. Historical
+///   "trades" snapshots are discarded, but every other market data message received during
+///   validation (eg/ "book" snapshots) is returned so it can be transformed.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BitfinexWebSocketSubValidator;
 
@@ -40,7 +47,7 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
     async fn validate<Exchange, Kind>(
         mut map: Map<Instrument>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+    ) -> Result<(Map<Instrument>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send,
@@ -50,17 +57,19 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
         let expected_responses = Exchange::expected_responses(&map);
 
         // Parameter to keep track of successful Subscription outcomes
-        // '--> Bitfinex sends snapshots as the first message, so count them also
         let mut success_responses = 0usize;
-        let mut init_snapshots_received = 0usize;
+
+        // Channel ids of "trades" subscriptions awaiting their historical trades snapshot
+        let mut pending_trade_snapshots = HashSet::new();
+
+        // Market data messages received during validation that must still be transformed
+        let mut buffered = Vec::new();
 
         loop {
-            // Break if all Subscriptions were a success
-            if success_responses == expected_responses
-                && init_snapshots_received == expected_responses
-            {
+            // Break if all Subscriptions were a success & historical trades snapshots discarded
+            if success_responses == expected_responses && pending_trade_snapshots.is_empty() {
                 debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
-                break Ok(map);
+                break Ok((map, buffered));
             }
 
             tokio::select! {
@@ -90,17 +99,30 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                                 );
                             }
 
+                            // Connection configuration (eg/ OrderBook checksums) applied
+                            Ok(BitfinexPlatformEvent::Conf(conf)) => {
+                                debug!(
+                                    exchange = %Exchange::ID,
+                                    payload = ?conf,
+                                    "received Bitfinex configuration response",
+                                );
+                            }
+
                             // Subscription success
                             Ok(BitfinexPlatformEvent::Subscribed(response)) => {
                                 // Determine SubscriptionId associated with the success response
-                                let BitfinexSubResponse { channel, market, channel_id } = &response;
-                                let subscription_id = ExchangeSub::from((channel, market)).id();
+                                let BitfinexSubResponse { market, channel_id, .. } = &response;
+                                let subscription_id = ExchangeSub::from((response.subscribed_channel(), market)).id();
 
                                 // Replace SubscriptionId with SubscriptionId(channel_id)
                                 if let Some(subscription) = map.0.remove(&subscription_id) {
                                     success_responses += 1;
                                     map.0.insert(SubscriptionId(channel_id.0.to_string()), subscription);
 
+                                    if response.subscribed_channel() == BitfinexChannel::TRADES.as_ref() {
+                                        pending_trade_snapshots.insert(channel_id.0);
+                                    }
+
                                     debug!(
                                         exchange = %Exchange::ID,
                                         %success_responses,
@@ -117,17 +139,36 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                             // Not reachable after BitfinexPlatformEvent validate()
                             Ok(BitfinexPlatformEvent::Error(error)) => panic!("{error:?}"),
                         }
-                        Some(Err(SocketError::Deserialise { error, payload })) if success_responses >= 1 => {
-                            // Already active Bitfinex subscriptions will send initial snapshots
-                            init_snapshots_received += 1;
-                            debug!(
-                                exchange = %Exchange::ID,
-                                ?error,
-                                %success_responses,
-                                %expected_responses,
-                                %payload,
-                                "failed to deserialise non SubResponse payload"
-                            );
+                        Some(Err(SocketError::Deserialise { error, payload })) => {
+                            // Already active Bitfinex subscriptions send market data messages
+                            let frame = match BitfinexDataFrame::parse(&payload) {
+                                Some(frame) => frame,
+                                None => {
+                                    debug!(
+                                        exchange = %Exchange::ID,
+                                        ?error,
+                                        %payload,
+                                        "failed to deserialise non SubResponse payload"
+                                    );
+                                    continue
+                                }
+                            };
+
+                            if frame.snapshot && pending_trade_snapshots.remove(&frame.channel_id) {
+                                debug!(
+                                    exchange = %Exchange::ID,
+                                    channel_id = %frame.channel_id,
+                                    "discarded Bitfinex historical trades snapshot"
+                                );
+                            } else if map.0.contains_key(&SubscriptionId(frame.channel_id.to_string())) {
+                                buffered.push(WsMessage::Text(payload));
+                            } else {
+                                debug!(
+                                    exchange = %Exchange::ID,
+                                    %payload,
+                                    "received Bitfinex message for an unknown channel"
+                                );
+                            }
                             continue
                         }
                         Some(Err(SocketError::Terminated(close_frame))) => {
@@ -145,3 +186,118 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
         }
     }
 }
+
+/// Channel id & kind of a [`Bitfinex`](super::Bitfinex) market data message received during
+/// [`BitfinexWebSocketSubValidator::validate`].
+///
+/// ### Raw Payload Examples
+/// #### Snapshot
+/// ```json
+/// [17082,[[7254.7,3,3.3],[7254.6,2,1.2]]]
+/// ```
+///
+/// #### Update, Checksum & Heartbeat
+/// ```json
+/// [17082,[7254.5,0,1]]
+/// [17082,"cs",-1512791856]
+/// [17082,"hb"]
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct BitfinexDataFrame {
+    channel_id: u32,
+    snapshot: bool,
+}
+
+impl BitfinexDataFrame {
+    /// Parse the [`BitfinexDataFrame`] of a raw payload, returning `None` if the payload is not a
+    /// Bitfinex channel message.
+    fn parse(payload: &str) -> Option<Self> {
+        let frame = serde_json::from_str::<Vec<serde_json::Value>>(payload).ok()?;
+        let channel_id = u32::try_from(frame.first()?.as_u64()?).ok()?;
+
+        // Snapshots are the only messages with a sequence of sequences as the 2nd element
+        let snapshot = frame.len() == 2
+            && frame[1]
+                .as_array()
+                .is_some_and(|items| items.iter().all(serde_json::Value::is_array));
+
+        Some(Self {
+            channel_id,
+            snapshot,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitfinex_data_frame_parse() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<BitfinexDataFrame>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: OrderBook snapshot
+                input: r#"[17082,[[7254.7,3,3.3],[7254.6,2,1.2]]]"#,
+                expected: Some(BitfinexDataFrame {
+                    channel_id: 17082,
+                    snapshot: true,
+                }),
+            },
+            TestCase {
+                // TC1: trades snapshot
+                input: r#"[17470,[[401597395,1574694478808,0.005,7245.3]]]"#,
+                expected: Some(BitfinexDataFrame {
+                    channel_id: 17470,
+                    snapshot: true,
+                }),
+            },
+            TestCase {
+                // TC2: OrderBook update
+                input: r#"[17082,[7254.5,0,1]]"#,
+                expected: Some(BitfinexDataFrame {
+                    channel_id: 17082,
+                    snapshot: false,
+                }),
+            },
+            TestCase {
+                // TC3: trades update
+                input: r#"[17470,"te",[401597395,1574694478808,0.005,7245.3]]"#,
+                expected: Some(BitfinexDataFrame {
+                    channel_id: 17470,
+                    snapshot: false,
+                }),
+            },
+            TestCase {
+                // TC4: checksum
+                input: r#"[17082,"cs",-1512791856]"#,
+                expected: Some(BitfinexDataFrame {
+                    channel_id: 17082,
+                    snapshot: false,
+                }),
+            },
+            TestCase {
+                // TC5: heartbeat
+                input: r#"[17082,"hb"]"#,
+                expected: Some(BitfinexDataFrame {
+                    channel_id: 17082,
+                    snapshot: false,
+                }),
+            },
+            TestCase {
+                // TC6: non channel message
+                input: r#"{"event":"info","version":2}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = BitfinexDataFrame::parse(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{bitmex::message::BitmexMessage, ExchangeId},
+    number::Number,
+    subscription::book::{Level, OrderBookL1},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Bitmex`](super::super::Bitmex) real-time quote WebSocket message,
+/// used to generate OrderBook Level1 (top of book) snapshots.
+pub type BitmexOrderBookL1 = BitmexMessage<BitmexQuote>;
+
+/// [`Bitmex`](super::super::Bitmex) best bid and ask quote.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+/// ```json
+/// {
+///     "table": "quote",
+///     "action": "insert",
+///     "data": [
+///         {
+///             "timestamp": "2023-02-18T09:27:59.701Z",
+///             "symbol": "XBTUSD",
+///             "bidSize": 48900,
+///             "bidPrice": 24564.5,
+///             "askPrice": 24565,
+///             "askSize": 162800
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitmexQuote {
+    pub timestamp: DateTime<Utc>,
+    pub symbol: String,
+    pub bid_price: Number,
+    pub bid_size: Number,
+    pub ask_price: Number,
+    pub ask_size: Number,
+}
+
+impl Identifier<Option<SubscriptionId>> for BitmexOrderBookL1 {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|quote| SubscriptionId(format!("{}|{}", self.table, quote.symbol)))
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitmexOrderBookL1)> for MarketIter<OrderBookL1> {
+    fn from(
+        (exchange_id, instrument, quotes): (ExchangeId, Instrument, BitmexOrderBookL1),
+    ) -> Self {
+        Self(
+            quotes
+                .data
+                .into_iter()
+                .map(|quote| MarketEvent {
+                    exchange_time: quote.timestamp,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: OrderBookL1 {
+                        last_update_time: quote.timestamp,
+                        best_bid: Level::new(quote.bid_price, quote.bid_size),
+                        best_ask: Level::new(quote.ask_price, quote.ask_size),
+                    },
+                })
+                .map(Ok)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use chrono::{Duration, TimeZone};
+
+        #[test]
+        fn test_bitmex_order_book_l1() {
+            let input = r#"
+            {
+                "table": "quote",
+                "action": "insert",
+                "data": [
+                    {
+                        "timestamp": "2023-02-18T09:27:59.701Z",
+                        "symbol": "XBTUSD",
+                        "bidSize": 48900,
+                        "bidPrice": 24564.5,
+                        "askPrice": 24565,
+                        "askSize": 162800
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<BitmexOrderBookL1>(input).unwrap();
+
+            assert_eq!(
+                actual,
+                BitmexOrderBookL1 {
+                    table: "quote".to_string(),
+                    data: vec![BitmexQuote {
+                        timestamp: Utc.with_ymd_and_hms(2023, 2, 18, 9, 27, 59).unwrap()
+                            + Duration::milliseconds(701),
+                        symbol: "XBTUSD".to_string(),
+                        bid_price: num!(24564.5),
+                        bid_size: num!(48900.0),
+                        ask_price: num!(24565.0),
+                        ask_size: num!(162800.0),
+                    }],
+                }
+            );
+            assert_eq!(actual.id(), Some(SubscriptionId::from("quote|XBTUSD")));
+        }
+    }
+}
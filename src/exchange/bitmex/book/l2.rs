@@ -0,0 +1,508 @@
+use crate::{
+    error::DataError,
+    number::{Number, ZERO},
+    subscription::book::{Level, OrderBook, OrderBookDelta, OrderBookSide},
+    transformer::book::{
+        InstrumentOrderBook, OrderBookChange, OrderBookDeltaUpdater, OrderBookUpdater,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// [`Bitmex`](super::super::Bitmex) real-time OrderBook Level2 WebSocket message from the
+/// "orderBookL2" table.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#Response-Format>
+/// #### Partial (snapshot)
+/// ```json
+/// {
+///     "table": "orderBookL2",
+///     "action": "partial",
+///     "keys": ["symbol", "id", "side"],
+///     "filter": {"symbol": "XBTUSD"},
+///     "data": [
+///         {
+///             "symbol": "XBTUSD",
+///             "id": 17999992000,
+///             "side": "Sell",
+///             "size": 100,
+///             "price": 80,
+///             "timestamp": "2023-02-18T09:27:59.701Z"
+///         }
+///     ]
+/// }
+/// ```
+///
+/// #### Delete
+/// ```json
+/// {
+///     "table": "orderBookL2",
+///     "action": "delete",
+///     "data": [
+///         {
+///             "symbol": "XBTUSD",
+///             "id": 17999992000,
+///             "side": "Sell",
+///             "timestamp": "2023-02-18T09:28:00.112Z"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexOrderBookL2 {
+    pub table: String,
+    pub action: BitmexBookAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<BitmexBookFilter>,
+    pub data: Vec<BitmexLevel>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BitmexOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        // "partial" messages for an empty OrderBook contain no data, but are always filtered
+        self.data
+            .first()
+            .map(|level| level.symbol.as_str())
+            .or(self.filter.as_ref().map(|filter| filter.symbol.as_str()))
+            .map(|symbol| SubscriptionId(format!("{}|{}", self.table, symbol)))
+    }
+}
+
+/// [`Bitmex`](super::super::Bitmex) subscription filter sent with the "partial" of a
+/// [`BitmexOrderBookL2`] table.
+///
+/// See [`BitmexOrderBookL2`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BitmexBookFilter {
+    pub symbol: String,
+}
+
+/// [`Bitmex`](super::super::Bitmex) table action applied to each [`BitmexLevel`] of a
+/// [`BitmexOrderBookL2`] message.
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#Response-Format>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BitmexBookAction {
+    Partial,
+    Insert,
+    Update,
+    Delete,
+}
+
+/// [`Bitmex`](super::super::Bitmex) OrderBook Level2 level keyed by it's unique `id`.
+///
+/// ### Notes
+/// - "delete" actions may omit the `size` and `price`, and "update" actions may omit the `price`,
+///   so the price is resolved from the `id` of the previously inserted level.
+/// - Any other level without a `size` is skipped, rather than being applied as a removal.
+///
+/// See [`BitmexOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexLevel {
+    pub symbol: String,
+    pub id: u64,
+    pub side: Side,
+    #[serde(default)]
+    pub size: Option<Number>,
+    #[serde(default)]
+    pub price: Option<Number>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// [`Bitmex`](super::super::Bitmex) [`OrderBookUpdater`] that maintains an OrderBook Level2 from
+/// the id-keyed "orderBookL2" table.
+///
+/// ### Notes
+/// - No HTTP snapshot is required since [`Bitmex`](super::super::Bitmex) sends a "partial" as
+///   the first WebSocket message after subscribing, and any actions received before it are
+///   ignored.
+/// - An `id` to price map is maintained so id-keyed actions can be applied to the price-keyed
+///   normalised [`OrderBook`].
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#OrderBookL2>
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct BitmexBookUpdater {
+    pub partial_received: bool,
+    pub prices: HashMap<u64, Number>,
+}
+
+impl BitmexBookUpdater {
+    /// Apply a [`BitmexLevel`] with the provided amount to the associated [`OrderBookSide`],
+    /// recording the [`Level`] in the [`OrderBookDelta`] if it changed the [`OrderBook`].
+    fn apply(book: &mut OrderBook, delta: &mut OrderBookDelta, side: Side, level: Level) {
+        let (book_side, delta_levels) = match side {
+            Side::Buy => (&mut book.bids, &mut delta.bids),
+            Side::Sell => (&mut book.asks, &mut delta.asks),
+        };
+        delta_levels.extend(book_side.upsert_single_delta(level));
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BitmexBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BitmexOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        _: Option<usize>,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Initial OrderBook "partial" is sent as the first WebSocket message
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+}
+
+impl OrderBookDeltaUpdater for BitmexBookUpdater {
+    fn update_delta(
+        &mut self,
+        book: &mut OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookChange>, DataError> {
+        let is_snapshot = match update.action {
+            BitmexBookAction::Partial => {
+                self.partial_received = true;
+                self.prices.clear();
+                book.bids = OrderBookSide::new(Side::Buy, Vec::<Level>::new());
+                book.asks = OrderBookSide::new(Side::Sell, Vec::<Level>::new());
+                true
+            }
+            _ if !self.partial_received => return Ok(None),
+            _ => false,
+        };
+
+        let mut delta = OrderBookDelta {
+            last_update_time: book.last_update_time,
+            prev_sequence: None,
+            sequence: None,
+            bids: vec![],
+            asks: vec![],
+        };
+
+        for level in update.data {
+            book.last_update_time = level.timestamp;
+            delta.last_update_time = level.timestamp;
+
+            let amount = match (update.action, level.size) {
+                (BitmexBookAction::Delete, _) => ZERO,
+                (_, Some(size)) => size,
+                (_, None) => {
+                    debug!(?level, action = ?update.action, "Level size not found");
+                    continue;
+                }
+            };
+
+            let price = match update.action {
+                BitmexBookAction::Partial | BitmexBookAction::Insert => {
+                    level.price.inspect(|price| {
+                        self.prices.insert(level.id, *price);
+                    })
+                }
+                BitmexBookAction::Update => level.price.or(self.prices.get(&level.id).copied()),
+                BitmexBookAction::Delete => self.prices.remove(&level.id).or(level.price),
+            };
+
+            let Some(price) = price else {
+                debug!(?level, action = ?update.action, "Level price not found for id");
+                continue;
+            };
+
+            Self::apply(book, &mut delta, level.side, Level::new(price, amount));
+        }
+
+        if is_snapshot {
+            Ok(Some(OrderBookChange::Snapshot))
+        } else {
+            Ok(Some(OrderBookChange::Delta(delta)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use chrono::{Duration, TimeZone};
+
+        #[test]
+        fn test_bitmex_order_book_l2() {
+            let input = r#"
+            {
+                "table": "orderBookL2",
+                "action": "delete",
+                "data": [
+                    {
+                        "symbol": "XBTUSD",
+                        "id": 17999992000,
+                        "side": "Sell",
+                        "timestamp": "2023-02-18T09:27:59.701Z"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<BitmexOrderBookL2>(input).unwrap();
+
+            assert_eq!(
+                actual,
+                BitmexOrderBookL2 {
+                    table: "orderBookL2".to_string(),
+                    action: BitmexBookAction::Delete,
+                    filter: None,
+                    data: vec![BitmexLevel {
+                        symbol: "XBTUSD".to_string(),
+                        id: 17999992000,
+                        side: Side::Sell,
+                        size: None,
+                        price: None,
+                        timestamp: Utc.with_ymd_and_hms(2023, 2, 18, 9, 27, 59).unwrap()
+                            + Duration::milliseconds(701),
+                    }],
+                }
+            );
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("orderBookL2|XBTUSD"))
+            );
+        }
+
+        #[test]
+        fn test_bitmex_order_book_l2_empty_partial() {
+            let input = r#"
+            {
+                "table": "orderBookL2",
+                "action": "partial",
+                "keys": ["symbol", "id", "side"],
+                "filter": {"symbol": "XBTUSD"},
+                "data": []
+            }
+            "#;
+
+            let actual = serde_json::from_str::<BitmexOrderBookL2>(input).unwrap();
+
+            assert_eq!(
+                actual,
+                BitmexOrderBookL2 {
+                    table: "orderBookL2".to_string(),
+                    action: BitmexBookAction::Partial,
+                    filter: Some(BitmexBookFilter {
+                        symbol: "XBTUSD".to_string()
+                    }),
+                    data: vec![],
+                }
+            );
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("orderBookL2|XBTUSD"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_bitmex_book_updater_update() {
+        struct TestCase {
+            action: BitmexBookAction,
+            data: Vec<(u64, Side, Option<Number>, Option<Number>)>,
+            expected: Option<(Vec<Level>, Vec<Level>)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: partial replaces the OrderBook
+                action: BitmexBookAction::Partial,
+                data: vec![
+                    (1, Side::Buy, Some(num!(5.0)), Some(num!(90.0))),
+                    (2, Side::Sell, Some(num!(6.0)), Some(num!(91.0))),
+                ],
+                expected: Some((
+                    vec![Level::new(num!(90.0), num!(5.0))],
+                    vec![Level::new(num!(91.0), num!(6.0))],
+                )),
+            },
+            TestCase {
+                // TC1: insert adds a new level
+                action: BitmexBookAction::Insert,
+                data: vec![(3, Side::Buy, Some(num!(7.0)), Some(num!(99.5)))],
+                expected: Some((
+                    vec![
+                        Level::new(num!(100.0), num!(1.0)),
+                        Level::new(num!(99.5), num!(7.0)),
+                        Level::new(num!(99.0), num!(2.0)),
+                    ],
+                    vec![Level::new(num!(101.0), num!(3.0))],
+                )),
+            },
+            TestCase {
+                // TC2: update w/o price resolves the price from the level id
+                action: BitmexBookAction::Update,
+                data: vec![(10, Side::Buy, Some(num!(4.0)), None)],
+                expected: Some((
+                    vec![
+                        Level::new(num!(100.0), num!(4.0)),
+                        Level::new(num!(99.0), num!(2.0)),
+                    ],
+                    vec![Level::new(num!(101.0), num!(3.0))],
+                )),
+            },
+            TestCase {
+                // TC3: delete w/o price & size removes the level associated with the id
+                action: BitmexBookAction::Delete,
+                data: vec![(12, Side::Sell, None, None)],
+                expected: Some((
+                    vec![
+                        Level::new(num!(100.0), num!(1.0)),
+                        Level::new(num!(99.0), num!(2.0)),
+                    ],
+                    vec![],
+                )),
+            },
+            TestCase {
+                // TC4: update for an unknown id w/o price is skipped
+                action: BitmexBookAction::Update,
+                data: vec![(99, Side::Buy, Some(num!(4.0)), None)],
+                expected: Some((
+                    vec![
+                        Level::new(num!(100.0), num!(1.0)),
+                        Level::new(num!(99.0), num!(2.0)),
+                    ],
+                    vec![Level::new(num!(101.0), num!(3.0))],
+                )),
+            },
+            TestCase {
+                // TC5: update w/o size is skipped rather than removing the level
+                action: BitmexBookAction::Update,
+                data: vec![(10, Side::Buy, None, None)],
+                expected: Some((
+                    vec![
+                        Level::new(num!(100.0), num!(1.0)),
+                        Level::new(num!(99.0), num!(2.0)),
+                    ],
+                    vec![Level::new(num!(101.0), num!(3.0))],
+                )),
+            },
+            TestCase {
+                // TC6: insert w/o size is skipped
+                action: BitmexBookAction::Insert,
+                data: vec![(3, Side::Buy, None, Some(num!(99.5)))],
+                expected: Some((
+                    vec![
+                        Level::new(num!(100.0), num!(1.0)),
+                        Level::new(num!(99.0), num!(2.0)),
+                    ],
+                    vec![Level::new(num!(101.0), num!(3.0))],
+                )),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            // Initialise OrderBook via a partial w/ levels: 10 -> 100.0, 11 -> 99.0, 12 -> 101.0
+            let mut updater = BitmexBookUpdater::default();
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            };
+            updater
+                .update(
+                    &mut book,
+                    message(
+                        BitmexBookAction::Partial,
+                        vec![
+                            (10, Side::Buy, Some(num!(1.0)), Some(num!(100.0))),
+                            (11, Side::Buy, Some(num!(2.0)), Some(num!(99.0))),
+                            (12, Side::Sell, Some(num!(3.0)), Some(num!(101.0))),
+                        ],
+                    ),
+                )
+                .unwrap();
+
+            let actual = updater
+                .update(&mut book, message(test.action, test.data))
+                .unwrap()
+                .map(|book| (book.bids, book.asks));
+            let expected = test.expected.map(|(bids, asks)| {
+                (
+                    OrderBookSide::new(Side::Buy, bids),
+                    OrderBookSide::new(Side::Sell, asks),
+                )
+            });
+
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+
+        fn message(
+            action: BitmexBookAction,
+            data: Vec<(u64, Side, Option<Number>, Option<Number>)>,
+        ) -> BitmexOrderBookL2 {
+            BitmexOrderBookL2 {
+                table: "orderBookL2".to_string(),
+                action,
+                filter: None,
+                data: data
+                    .into_iter()
+                    .map(|(id, side, size, price)| BitmexLevel {
+                        symbol: "XBTUSD".to_string(),
+                        id,
+                        side,
+                        size,
+                        price,
+                        timestamp: Utc::now(),
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitmex_book_updater_ignores_actions_before_partial() {
+        let mut updater = BitmexBookUpdater::default();
+        let mut book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        };
+
+        let update = BitmexOrderBookL2 {
+            table: "orderBookL2".to_string(),
+            action: BitmexBookAction::Insert,
+            filter: None,
+            data: vec![BitmexLevel {
+                symbol: "XBTUSD".to_string(),
+                id: 1,
+                side: Side::Buy,
+                size: Some(num!(1.0)),
+                price: Some(num!(100.0)),
+                timestamp: Utc::now(),
+            }],
+        };
+
+        assert_eq!(updater.update(&mut book, update).unwrap(), None);
+        assert!(updater.prices.is_empty());
+    }
+}
@@ -1,6 +1,14 @@
 use crate::{
     exchange::bitmex::Bitmex,
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        candle::Candles,
+        funding::FundingRates,
+        liquidation::Liquidations,
+        open_interest::OpenInterests,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +25,27 @@ impl BitmexChannel {
     ///
     /// See docs: <https://www.bitmex.com/app/wsAPI>
     pub const TRADES: Self = Self("trade");
+
+    /// [`Bitmex`] real-time quote channel name, used for OrderBook Level1 (top of book).
+    ///
+    /// See docs: <https://www.bitmex.com/app/wsAPI>
+    pub const ORDER_BOOK_L1: Self = Self("quote");
+
+    /// [`Bitmex`] real-time full depth OrderBook Level2 channel name.
+    ///
+    /// See docs: <https://www.bitmex.com/app/wsAPI>
+    pub const ORDER_BOOK_L2: Self = Self("orderBookL2");
+
+    /// [`Bitmex`] real-time instrument channel name, used for current & indicative funding
+    /// rates, and open interest.
+    ///
+    /// See docs: <https://www.bitmex.com/app/wsAPI>
+    pub const INSTRUMENT: Self = Self("instrument");
+
+    /// [`Bitmex`] real-time liquidation orders channel name.
+    ///
+    /// See docs: <https://www.bitmex.com/app/wsAPI>
+    pub const LIQUIDATIONS: Self = Self("liquidation");
 }
 
 impl Identifier<BitmexChannel> for Subscription<Bitmex, PublicTrades> {
@@ -25,6 +54,52 @@ impl Identifier<BitmexChannel> for Subscription<Bitmex, PublicTrades> {
     }
 }
 
+/// [`Candles`] are aggregated from the trades channel since Bitmex does not offer native
+/// candle streams (see [`TradeCandleTransformer`](crate::transformer::candle::TradeCandleTransformer)).
+impl Identifier<BitmexChannel> for Subscription<Bitmex, Candles> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::TRADES
+    }
+}
+
+impl Identifier<BitmexChannel> for Subscription<Bitmex, OrderBooksL1> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::ORDER_BOOK_L1
+    }
+}
+
+impl Identifier<BitmexChannel> for Subscription<Bitmex, OrderBooksL2> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<BitmexChannel> for Subscription<Bitmex, OrderBooksL2Delta> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::ORDER_BOOK_L2
+    }
+}
+
+/// [`FundingRates`] are sourced from the instrument channel since the Bitmex funding channel
+/// only publishes settled funding rates.
+impl Identifier<BitmexChannel> for Subscription<Bitmex, FundingRates> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::INSTRUMENT
+    }
+}
+
+impl Identifier<BitmexChannel> for Subscription<Bitmex, OpenInterests> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::INSTRUMENT
+    }
+}
+
+impl Identifier<BitmexChannel> for Subscription<Bitmex, Liquidations> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::LIQUIDATIONS
+    }
+}
+
 impl AsRef<str> for BitmexChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -0,0 +1,282 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{bitmex::message::BitmexMessage, ExchangeId},
+    number::Number,
+    subscription::{funding::FundingRate, open_interest::OpenInterest},
+    transformer::merge::Merge,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Bitmex`](super::Bitmex) real-time instrument WebSocket message.
+pub type BitmexInstrumentMessage = BitmexMessage<BitmexInstrument>;
+
+/// [`Bitmex`](super::Bitmex) instrument state.
+///
+/// ### Notes
+/// - The "instrument" table sends an initial "partial" containing every field, followed by
+///   "update" messages that only contain the fields that changed. Every field is therefore
+///   optional, and updates are [`Merge`]d into the latest state.
+/// - The "funding" table only publishes funding rates once they have been settled, so the
+///   "instrument" table is used to stream the current & indicative funding rates.
+/// - "openValue" is denominated in the settlement currency's smallest unit (eg/ XBt satoshis),
+///   so the normalised [`OpenInterest`] notional is left empty.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+/// ```json
+/// {
+///     "table": "instrument",
+///     "action": "partial",
+///     "data": [
+///         {
+///             "symbol": "XBTUSD",
+///             "state": "Open",
+///             "typ": "FFWCSX",
+///             "fundingTimestamp": "2023-02-18T12:00:00.000Z",
+///             "fundingInterval": "2000-01-01T08:00:00.000Z",
+///             "fundingRate": 0.0001,
+///             "indicativeFundingRate": 0.000075,
+///             "openInterest": 325456100,
+///             "openValue": 1325193918624,
+///             "markPrice": 24561.47,
+///             "indicativeSettlePrice": 24560.09,
+///             "timestamp": "2023-02-18T09:27:55.000Z"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitmexInstrument {
+    pub symbol: String,
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub funding_rate: Option<Number>,
+    #[serde(default)]
+    pub indicative_funding_rate: Option<Number>,
+    #[serde(default)]
+    pub funding_timestamp: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub mark_price: Option<Number>,
+    #[serde(default)]
+    pub open_interest: Option<Number>,
+}
+
+impl Merge for BitmexInstrument {
+    fn merge(&mut self, update: Self) {
+        self.timestamp = update.timestamp.or(self.timestamp);
+        self.funding_rate = update.funding_rate.or(self.funding_rate);
+        self.indicative_funding_rate = update
+            .indicative_funding_rate
+            .or(self.indicative_funding_rate);
+        self.funding_timestamp = update.funding_timestamp.or(self.funding_timestamp);
+        self.mark_price = update.mark_price.or(self.mark_price);
+        self.open_interest = update.open_interest.or(self.open_interest);
+    }
+}
+
+impl Merge for BitmexInstrumentMessage {
+    fn merge(&mut self, update: Self) {
+        self.table = update.table;
+        for instrument in update.data {
+            match self
+                .data
+                .iter_mut()
+                .find(|state| state.symbol == instrument.symbol)
+            {
+                Some(state) => state.merge(instrument),
+                None => self.data.push(instrument),
+            }
+        }
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for BitmexInstrumentMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|instrument| SubscriptionId(format!("{}|{}", self.table, instrument.symbol)))
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitmexInstrumentMessage)> for MarketIter<FundingRate> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BitmexInstrumentMessage),
+    ) -> Self {
+        Self(
+            message
+                .data
+                .into_iter()
+                .filter_map(|state| {
+                    // Non-perpetual instruments do not have a funding rate
+                    let rate = state.funding_rate?;
+
+                    Some(Ok(MarketEvent {
+                        exchange_time: state.timestamp.unwrap_or_else(Utc::now),
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(exchange_id),
+                        instrument: instrument.clone(),
+                        kind: FundingRate {
+                            rate,
+                            predicted_rate: state.indicative_funding_rate,
+                            next_funding_time: state.funding_timestamp,
+                            mark_price: state.mark_price,
+                        },
+                    }))
+                })
+                .collect(),
+        )
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitmexInstrumentMessage)> for MarketIter<OpenInterest> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BitmexInstrumentMessage),
+    ) -> Self {
+        Self(
+            message
+                .data
+                .into_iter()
+                .filter_map(|state| {
+                    let contracts = state.open_interest?;
+                    let time = state.timestamp.unwrap_or_else(Utc::now);
+
+                    Some(Ok(MarketEvent {
+                        exchange_time: time,
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(exchange_id),
+                        instrument: instrument.clone(),
+                        kind: OpenInterest {
+                            contracts,
+                            notional: None,
+                            time,
+                        },
+                    }))
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bitmex_instrument() {
+            struct TestCase {
+                input: &'static str,
+                expected: BitmexInstrumentMessage,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input partial is deserialised
+                    input: r#"
+                    {
+                        "table": "instrument",
+                        "action": "partial",
+                        "data": [
+                            {
+                                "symbol": "XBTUSD",
+                                "state": "Open",
+                                "fundingTimestamp": "2023-02-18T12:00:00.000Z",
+                                "fundingRate": 0.0001,
+                                "indicativeFundingRate": 0.000075,
+                                "openInterest": 325456100,
+                                "markPrice": 24561.47,
+                                "timestamp": "2023-02-18T09:27:55.000Z"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: BitmexInstrumentMessage {
+                        table: "instrument".to_string(),
+                        data: vec![BitmexInstrument {
+                            symbol: "XBTUSD".to_string(),
+                            timestamp: Some("2023-02-18T09:27:55.000Z".parse().unwrap()),
+                            funding_rate: Some(num!(0.0001)),
+                            indicative_funding_rate: Some(num!(0.000075)),
+                            funding_timestamp: Some("2023-02-18T12:00:00.000Z".parse().unwrap()),
+                            mark_price: Some(num!(24561.47)),
+                            open_interest: Some(num!(325456100.0)),
+                        }],
+                    },
+                },
+                TestCase {
+                    // TC1: input update with missing fields is deserialised
+                    input: r#"
+                    {
+                        "table": "instrument",
+                        "action": "update",
+                        "data": [
+                            {
+                                "symbol": "XBTUSD",
+                                "markPrice": 24562.1,
+                                "timestamp": "2023-02-18T09:28:00.000Z"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: BitmexInstrumentMessage {
+                        table: "instrument".to_string(),
+                        data: vec![BitmexInstrument {
+                            symbol: "XBTUSD".to_string(),
+                            timestamp: Some("2023-02-18T09:28:00.000Z".parse().unwrap()),
+                            mark_price: Some(num!(24562.1)),
+                            ..Default::default()
+                        }],
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitmexInstrumentMessage>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitmex_instrument_message_merge() {
+        let instrument = |symbol: &str, funding_rate, mark_price| BitmexInstrument {
+            symbol: symbol.to_string(),
+            funding_rate,
+            mark_price,
+            ..Default::default()
+        };
+
+        let mut state = BitmexInstrumentMessage {
+            table: "instrument".to_string(),
+            data: vec![instrument(
+                "XBTUSD",
+                Some(num!(0.0001)),
+                Some(num!(24561.47)),
+            )],
+        };
+
+        state.merge(BitmexInstrumentMessage {
+            table: "instrument".to_string(),
+            data: vec![
+                instrument("XBTUSD", None, Some(num!(24562.1))),
+                instrument("ETHUSD", Some(num!(0.0002)), None),
+            ],
+        });
+
+        assert_eq!(
+            state.data,
+            vec![
+                instrument("XBTUSD", Some(num!(0.0001)), Some(num!(24562.1))),
+                instrument("ETHUSD", Some(num!(0.0002)), None),
+            ]
+        );
+    }
+}
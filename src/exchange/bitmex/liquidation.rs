@@ -0,0 +1,252 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    number::Number,
+    subscription::liquidation::Liquidation,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`Bitmex`](super::Bitmex) real-time liquidation WebSocket message.
+///
+/// ### Notes
+/// - The "liquidation" table contains the liquidation orders currently resting in the book.
+///   Only "partial" & "insert" actions represent new liquidations, whereas "update" & "delete"
+///   actions only contain the changed fields of existing liquidation orders.
+/// - The `action` is therefore required, so a dedicated message type is used rather than the
+///   [`BitmexMessage`](super::message::BitmexMessage).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+/// ```json
+/// {
+///     "table": "liquidation",
+///     "action": "insert",
+///     "data": [
+///         {
+///             "orderID": "f8e4c2b5-3a57-4b1f-8e0a-4d1f7d5f7f1a",
+///             "symbol": "XBTUSD",
+///             "side": "Sell",
+///             "price": 24500.5,
+///             "leavesQty": 1500
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexLiquidations {
+    pub table: String,
+    pub action: String,
+    pub data: Vec<BitmexLiquidation>,
+}
+
+/// [`Bitmex`](super::Bitmex) liquidation order.
+///
+/// See [`BitmexLiquidations`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitmexLiquidation {
+    #[serde(rename = "orderID")]
+    pub order_id: String,
+    pub symbol: String,
+    #[serde(default)]
+    pub side: Option<Side>,
+    #[serde(default)]
+    pub price: Option<Number>,
+    #[serde(default)]
+    pub leaves_qty: Option<Number>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BitmexLiquidations {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|liquidation| SubscriptionId(format!("{}|{}", self.table, liquidation.symbol)))
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitmexLiquidations)> for MarketIter<Liquidation> {
+    fn from(
+        (exchange_id, instrument, liquidations): (ExchangeId, Instrument, BitmexLiquidations),
+    ) -> Self {
+        // Updates & deletes relate to liquidations that have already been yielded
+        if !matches!(liquidations.action.as_str(), "partial" | "insert") {
+            return Self(vec![]);
+        }
+
+        // Bitmex liquidations do not contain a timestamp
+        let time = Utc::now();
+
+        liquidations
+            .data
+            .into_iter()
+            .filter_map(|liquidation| {
+                let (Some(side), Some(price), Some(quantity)) =
+                    (liquidation.side, liquidation.price, liquidation.leaves_qty)
+                else {
+                    return None;
+                };
+
+                Some(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Liquidation {
+                        side,
+                        price,
+                        quantity,
+                        time,
+                        average_price: None,
+                        filled_quantity: None,
+                        status: None,
+                    },
+                })
+            })
+            .map(Ok)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bitmex_liquidations() {
+            struct TestCase {
+                input: &'static str,
+                expected: BitmexLiquidations,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input insert is deserialised
+                    input: r#"
+                    {
+                        "table": "liquidation",
+                        "action": "insert",
+                        "data": [
+                            {
+                                "orderID": "f8e4c2b5-3a57-4b1f-8e0a-4d1f7d5f7f1a",
+                                "symbol": "XBTUSD",
+                                "side": "Sell",
+                                "price": 24500.5,
+                                "leavesQty": 1500
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: BitmexLiquidations {
+                        table: "liquidation".to_string(),
+                        action: "insert".to_string(),
+                        data: vec![BitmexLiquidation {
+                            order_id: "f8e4c2b5-3a57-4b1f-8e0a-4d1f7d5f7f1a".to_string(),
+                            symbol: "XBTUSD".to_string(),
+                            side: Some(Side::Sell),
+                            price: Some(num!(24500.5)),
+                            leaves_qty: Some(num!(1500.0)),
+                        }],
+                    },
+                },
+                TestCase {
+                    // TC1: input delete with missing fields is deserialised
+                    input: r#"
+                    {
+                        "table": "liquidation",
+                        "action": "delete",
+                        "data": [
+                            {
+                                "orderID": "f8e4c2b5-3a57-4b1f-8e0a-4d1f7d5f7f1a",
+                                "symbol": "XBTUSD"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: BitmexLiquidations {
+                        table: "liquidation".to_string(),
+                        action: "delete".to_string(),
+                        data: vec![BitmexLiquidation {
+                            order_id: "f8e4c2b5-3a57-4b1f-8e0a-4d1f7d5f7f1a".to_string(),
+                            symbol: "XBTUSD".to_string(),
+                            side: None,
+                            price: None,
+                            leaves_qty: None,
+                        }],
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitmexLiquidations>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+                assert_eq!(
+                    actual.id(),
+                    Some(SubscriptionId::from("liquidation|XBTUSD")),
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitmex_liquidations_only_yields_new_liquidations() {
+        struct TestCase {
+            action: &'static str,
+            expected_len: usize,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: partial snapshot of resting liquidations is yielded
+                action: "partial",
+                expected_len: 1,
+            },
+            TestCase {
+                // TC1: inserted liquidation is yielded
+                action: "insert",
+                expected_len: 1,
+            },
+            TestCase {
+                // TC2: update of existing liquidation is ignored
+                action: "update",
+                expected_len: 0,
+            },
+            TestCase {
+                // TC3: delete of existing liquidation is ignored
+                action: "delete",
+                expected_len: 0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let liquidations = BitmexLiquidations {
+                table: "liquidation".to_string(),
+                action: test.action.to_string(),
+                data: vec![BitmexLiquidation {
+                    order_id: "f8e4c2b5-3a57-4b1f-8e0a-4d1f7d5f7f1a".to_string(),
+                    symbol: "XBTUSD".to_string(),
+                    side: Some(Side::Buy),
+                    price: Some(num!(24500.5)),
+                    leaves_qty: Some(num!(1500.0)),
+                }],
+            };
+
+            let actual = MarketIter::<Liquidation>::from((
+                ExchangeId::Bitmex,
+                Instrument::from(("xbt", "usd", InstrumentKind::Perpetual)),
+                liquidations,
+            ));
+
+            assert_eq!(actual.0.len(), test.expected_len, "TC{} failed", index);
+        }
+    }
+}
@@ -1,16 +1,34 @@
 use crate::{
     exchange::{
         bitmex::{
-            channel::BitmexChannel, market::BitmexMarket, subscription::BitmexSubResponse,
+            book::{l1::BitmexOrderBookL1, l2::BitmexBookUpdater},
+            channel::BitmexChannel,
+            instrument::BitmexInstrumentMessage,
+            liquidation::BitmexLiquidations,
+            market::BitmexMarket,
+            subscription::BitmexSubResponse,
             trade::BitmexTrade,
         },
         subscription::ExchangeSub,
         Connector, ExchangeId, StreamSelector,
     },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        candle::Candles,
+        funding::FundingRates,
+        liquidation::Liquidations,
+        open_interest::OpenInterests,
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{
+        book::{MultiBookDeltaTransformer, MultiBookTransformer},
+        candle::TradeCandleTransformer,
+        merge::MergeTransformer,
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, TimedExchangeWsStream,
 };
 use barter_integration::{
     error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
@@ -19,10 +37,19 @@ use serde::de::{Error, Unexpected};
 use std::fmt::Debug;
 use url::Url;
 
+/// Order book types for [`Bitmex`]
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Instrument types for [`Bitmex`], used for funding rates & open interest.
+pub mod instrument;
+
+/// Liquidation types for [`Bitmex`].
+pub mod liquidation;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -81,6 +108,40 @@ impl StreamSelector<PublicTrades> for Bitmex {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BitmexTrade>>;
 }
 
+impl StreamSelector<Candles> for Bitmex {
+    type Stream = TimedExchangeWsStream<TradeCandleTransformer<Self, BitmexTrade>>;
+}
+
+impl StreamSelector<OrderBooksL1> for Bitmex {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, BitmexOrderBookL1>>;
+}
+
+impl StreamSelector<OrderBooksL2> for Bitmex {
+    type Stream =
+        TimedExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BitmexBookUpdater>>;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for Bitmex {
+    type Stream =
+        ExchangeWsStream<MultiBookDeltaTransformer<Self, OrderBooksL2Delta, BitmexBookUpdater>>;
+}
+
+impl StreamSelector<FundingRates> for Bitmex {
+    type Stream = ExchangeWsStream<MergeTransformer<Self, FundingRates, BitmexInstrumentMessage>>;
+}
+
+impl StreamSelector<OpenInterests> for Bitmex {
+    type Stream = ExchangeWsStream<MergeTransformer<Self, OpenInterests, BitmexInstrumentMessage>>;
+}
+
+impl StreamSelector<Liquidations> for Bitmex {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Liquidations, BitmexLiquidations>>;
+}
+
 impl<'de> serde::Deserialize<'de> for Bitmex {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
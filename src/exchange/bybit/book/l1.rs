@@ -0,0 +1,110 @@
+use super::BybitOrderBookMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::book::{Level, OrderBookL1},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::Utc;
+
+impl From<(ExchangeId, Instrument, BybitOrderBookMessage)> for MarketIter<OrderBookL1> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BybitOrderBookMessage),
+    ) -> Self {
+        // Depth 1 messages contain at most the best bid and ask, so each message with both
+        // sides populated fully describes the top of book
+        let book = match message {
+            BybitOrderBookMessage::OrderBook(book) => book,
+            BybitOrderBookMessage::Response(_) => return Self(vec![]),
+        };
+
+        match (book.data.bids.first(), book.data.asks.first()) {
+            (Some(best_bid), Some(best_ask)) => Self(vec![Ok(MarketEvent {
+                exchange_time: book.time,
+                received_time: Utc::now(),
+                exchange: Exchange::from(exchange_id),
+                instrument,
+                kind: OrderBookL1 {
+                    last_update_time: book.time,
+                    best_bid: Level::from(*best_bid),
+                    best_ask: Level::from(*best_ask),
+                },
+            })]),
+            _ => Self(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::bybit::book::{BybitLevel, BybitOrderBook, BybitOrderBookInner};
+    use crate::number::num;
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+
+    #[test]
+    fn test_bybit_order_book_l1_from_message() {
+        struct TestCase {
+            bids: Vec<BybitLevel>,
+            asks: Vec<BybitLevel>,
+            expected: Option<(Level, Level)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: message with best bid & ask generates an OrderBookL1
+                bids: vec![BybitLevel {
+                    price: num!(100.0),
+                    amount: num!(1.0),
+                }],
+                asks: vec![BybitLevel {
+                    price: num!(101.0),
+                    amount: num!(2.0),
+                }],
+                expected: Some((
+                    Level::new(num!(100.0), num!(1.0)),
+                    Level::new(num!(101.0), num!(2.0)),
+                )),
+            },
+            TestCase {
+                // TC1: message with an empty side generates no OrderBookL1
+                bids: vec![],
+                asks: vec![BybitLevel {
+                    price: num!(101.0),
+                    amount: num!(2.0),
+                }],
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let message = BybitOrderBookMessage::OrderBook(BybitOrderBook {
+                subscription_id: SubscriptionId::from("orderbook.1|BTCUSDT"),
+                r#type: "snapshot".to_string(),
+                time: Utc::now(),
+                data: BybitOrderBookInner {
+                    market: "BTCUSDT".to_string(),
+                    bids: test.bids,
+                    asks: test.asks,
+                    update_id: 1,
+                    sequence: 1,
+                },
+            });
+
+            let actual = MarketIter::<OrderBookL1>::from((
+                ExchangeId::BybitSpot,
+                Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                message,
+            ))
+            .0
+            .into_iter()
+            .map(|event| {
+                let book = event.unwrap().kind;
+                (book.best_bid, book.best_ask)
+            })
+            .next();
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
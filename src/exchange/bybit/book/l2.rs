@@ -0,0 +1,306 @@
+use super::BybitOrderBookMessage;
+use crate::{
+    error::DataError,
+    subscription::book::{Level, OrderBook, OrderBookDelta, OrderBookSide},
+    transformer::book::{
+        InstrumentOrderBook, OrderBookChange, OrderBookDeltaUpdater, OrderBookUpdater,
+    },
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side},
+    protocol::websocket::WsMessage,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Bybit`](super::super::Bybit) OrderBook Level2 message type of a full snapshot.
+const BYBIT_BOOK_SNAPSHOT: &str = "snapshot";
+
+/// [`Bybit`](super::super::Bybit) OrderBook Level2 message type of an incremental delta.
+const BYBIT_BOOK_DELTA: &str = "delta";
+
+/// [`Bybit`](super::super::Bybit) [`OrderBookUpdater`] that maintains an OrderBook Level2 from
+/// the "orderbook.{depth}.{symbol}" channel.
+///
+/// ### Notes
+/// - No HTTP snapshot is required since [`Bybit`](super::super::Bybit) pushes a snapshot as the
+///   first WebSocket message after subscribing.
+/// - Any subsequent snapshot (eg/ "u" of 1 after a Bybit service restart) replaces the local
+///   OrderBook.
+/// - Each delta "u" must equal the previous message "u" + 1, otherwise a terminal
+///   [`DataError::InvalidSequence`] is returned.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BybitBookUpdater {
+    pub last_update_id: Option<u64>,
+}
+
+impl BybitBookUpdater {
+    /// Construct a new [`Bybit`](super::super::Bybit) [`OrderBookUpdater`] awaiting it's initial
+    /// snapshot.
+    pub fn new() -> Self {
+        Self {
+            last_update_id: None,
+        }
+    }
+}
+
+impl Default for BybitBookUpdater {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BybitBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BybitOrderBookMessage;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        _: Option<usize>,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Initial OrderBook snapshot is sent as the first WebSocket message
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+}
+
+impl OrderBookDeltaUpdater for BybitBookUpdater {
+    fn update_delta(
+        &mut self,
+        book: &mut OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookChange>, DataError> {
+        let update = match update {
+            BybitOrderBookMessage::OrderBook(update) => update,
+            BybitOrderBookMessage::Response(_) => return Ok(None),
+        };
+
+        let change = match (update.r#type.as_str(), self.last_update_id) {
+            (BYBIT_BOOK_SNAPSHOT, _) => {
+                book.bids = OrderBookSide::new(Side::Buy, update.data.bids);
+                book.asks = OrderBookSide::new(Side::Sell, update.data.asks);
+                OrderBookChange::Snapshot
+            }
+            // Ignore any deltas received before the initial snapshot
+            (BYBIT_BOOK_DELTA, None) => return Ok(None),
+            (BYBIT_BOOK_DELTA, Some(last_update_id)) => {
+                if update.data.update_id != last_update_id + 1 {
+                    return Err(DataError::InvalidSequence {
+                        prev_last_update_id: last_update_id,
+                        first_update_id: update.data.update_id,
+                    });
+                }
+
+                OrderBookChange::Delta(OrderBookDelta {
+                    last_update_time: update.time,
+                    prev_sequence: Some(last_update_id),
+                    sequence: Some(update.data.update_id),
+                    bids: book.bids.upsert_delta(update.data.bids),
+                    asks: book.asks.upsert_delta(update.data.asks),
+                })
+            }
+            _ => return Ok(None),
+        };
+
+        book.last_update_time = update.time;
+        self.last_update_id = Some(update.data.update_id);
+
+        Ok(Some(change))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::bybit::book::{BybitLevel, BybitOrderBook, BybitOrderBookInner};
+    use crate::number::{num, Number};
+    use barter_integration::model::SubscriptionId;
+
+    fn message(
+        r#type: &str,
+        update_id: u64,
+        bids: Vec<(Number, Number)>,
+        asks: Vec<(Number, Number)>,
+    ) -> BybitOrderBookMessage {
+        let levels = |levels: Vec<(Number, Number)>| {
+            levels
+                .into_iter()
+                .map(|(price, amount)| BybitLevel { price, amount })
+                .collect()
+        };
+
+        BybitOrderBookMessage::OrderBook(BybitOrderBook {
+            subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
+            r#type: r#type.to_string(),
+            time: Utc::now(),
+            data: BybitOrderBookInner {
+                market: "BTCUSDT".to_string(),
+                bids: levels(bids),
+                asks: levels(asks),
+                update_id,
+                sequence: update_id,
+            },
+        })
+    }
+
+    #[test]
+    fn test_bybit_book_updater_update() {
+        struct TestCase {
+            updater: BybitBookUpdater,
+            book: OrderBook,
+            input: BybitOrderBookMessage,
+            expected: Result<Option<(Vec<Level>, Vec<Level>)>, DataError>,
+        }
+
+        let book = |bids: Vec<Level>, asks: Vec<Level>| OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, bids),
+            asks: OrderBookSide::new(Side::Sell, asks),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: initial snapshot replaces empty OrderBook
+                updater: BybitBookUpdater::new(),
+                book: book(vec![], vec![]),
+                input: message(
+                    "snapshot",
+                    10,
+                    vec![(num!(100.0), num!(1.0)), (num!(99.0), num!(1.0))],
+                    vec![(num!(101.0), num!(1.0))],
+                ),
+                expected: Ok(Some((
+                    vec![
+                        Level::new(num!(100.0), num!(1.0)),
+                        Level::new(num!(99.0), num!(1.0)),
+                    ],
+                    vec![Level::new(num!(101.0), num!(1.0))],
+                ))),
+            },
+            TestCase {
+                // TC1: delta received before initial snapshot is ignored
+                updater: BybitBookUpdater::new(),
+                book: book(vec![], vec![]),
+                input: message("delta", 11, vec![(num!(100.0), num!(2.0))], vec![]),
+                expected: Ok(None),
+            },
+            TestCase {
+                // TC2: sequential delta upserts & removes levels
+                updater: BybitBookUpdater {
+                    last_update_id: Some(10),
+                },
+                book: book(
+                    vec![
+                        Level::new(num!(100.0), num!(1.0)),
+                        Level::new(num!(99.0), num!(1.0)),
+                    ],
+                    vec![Level::new(num!(101.0), num!(1.0))],
+                ),
+                input: message(
+                    "delta",
+                    11,
+                    vec![(num!(100.0), num!(0.0)), (num!(99.5), num!(3.0))],
+                    vec![(num!(100.5), num!(2.0))],
+                ),
+                expected: Ok(Some((
+                    vec![
+                        Level::new(num!(99.5), num!(3.0)),
+                        Level::new(num!(99.0), num!(1.0)),
+                    ],
+                    vec![
+                        Level::new(num!(100.5), num!(2.0)),
+                        Level::new(num!(101.0), num!(1.0)),
+                    ],
+                ))),
+            },
+            TestCase {
+                // TC3: delta w/ update_id gap returns terminal DataError::InvalidSequence
+                updater: BybitBookUpdater {
+                    last_update_id: Some(10),
+                },
+                book: book(
+                    vec![Level::new(num!(100.0), num!(1.0))],
+                    vec![Level::new(num!(101.0), num!(1.0))],
+                ),
+                input: message("delta", 12, vec![(num!(100.0), num!(2.0))], vec![]),
+                expected: Err(DataError::InvalidSequence {
+                    prev_last_update_id: 10,
+                    first_update_id: 12,
+                }),
+            },
+            TestCase {
+                // TC4: snapshot after service restart (u=1) replaces existing OrderBook
+                updater: BybitBookUpdater {
+                    last_update_id: Some(10),
+                },
+                book: book(
+                    vec![Level::new(num!(100.0), num!(1.0))],
+                    vec![Level::new(num!(101.0), num!(1.0))],
+                ),
+                input: message(
+                    "snapshot",
+                    1,
+                    vec![(num!(90.0), num!(1.0))],
+                    vec![(num!(91.0), num!(1.0))],
+                ),
+                expected: Ok(Some((
+                    vec![Level::new(num!(90.0), num!(1.0))],
+                    vec![Level::new(num!(91.0), num!(1.0))],
+                ))),
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            let actual = test.updater.update(&mut test.book, test.input);
+
+            match (actual, test.expected) {
+                (Ok(Some(actual)), Ok(Some((bids, asks)))) => {
+                    assert_eq!(
+                        actual.bids,
+                        OrderBookSide::new(Side::Buy, bids),
+                        "TC{} failed",
+                        index
+                    );
+                    assert_eq!(
+                        actual.asks,
+                        OrderBookSide::new(Side::Sell, asks),
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (Ok(None), Ok(None)) => {
+                    // Test passed
+                }
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(
+                        actual.to_string(),
+                        expected.to_string(),
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    exchange::bybit::{message::BybitPayload, subscription::BybitResponse},
+    number::Number,
+    subscription::book::Level,
+    Identifier,
+};
+use barter_integration::model::SubscriptionId;
+use serde::{Deserialize, Serialize};
+
+/// Level 1 OrderBook types (top of book).
+pub mod l1;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// Terse type alias for a [`Bybit`](super::Bybit) real-time OrderBook WebSocket message.
+pub type BybitOrderBook = BybitPayload<BybitOrderBookInner>;
+
+/// [`Bybit`](super::Bybit) OrderBook WebSocket message, which may also be a
+/// [`BybitResponse`] (eg/ pong) received on the same connection.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BybitOrderBookMessage {
+    Response(BybitResponse),
+    OrderBook(BybitOrderBook),
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitOrderBookMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitOrderBookMessage::OrderBook(book) => Some(book.subscription_id.clone()),
+            BybitOrderBookMessage::Response(_) => None,
+        }
+    }
+}
+
+/// [`Bybit`](super::Bybit) OrderBook snapshot or delta data.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// {
+///     "topic": "orderbook.50.BTCUSDT",
+///     "type": "snapshot",
+///     "ts": 1672304484978,
+///     "data": {
+///         "s": "BTCUSDT",
+///         "b": [["16493.50", "0.006"], ["16493.00", "0.100"]],
+///         "a": [["16611.00", "0.029"], ["16612.00", "0.213"]],
+///         "u": 18521288,
+///         "seq": 7961638724
+///     },
+///     "cts": 1672304484976
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookInner {
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(rename = "b")]
+    pub bids: Vec<BybitLevel>,
+    #[serde(rename = "a")]
+    pub asks: Vec<BybitLevel>,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "seq")]
+    pub sequence: u64,
+}
+
+/// [`Bybit`](super::Bybit) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// ["16493.50", "0.006"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitLevel {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+}
+
+impl From<BybitLevel> for Level {
+    fn from(level: BybitLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use crate::exchange::bybit::subscription::BybitReturnMessage;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_order_book_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: BybitOrderBookMessage,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input BybitOrderBookMessage::OrderBook snapshot is deserialised
+                    input: r#"
+                    {
+                        "topic": "orderbook.50.BTCUSDT",
+                        "type": "snapshot",
+                        "ts": 1672304484978,
+                        "data": {
+                            "s": "BTCUSDT",
+                            "b": [["16493.50", "0.006"], ["16493.00", "0.100"]],
+                            "a": [["16611.00", "0.029"]],
+                            "u": 18521288,
+                            "seq": 7961638724
+                        },
+                        "cts": 1672304484976
+                    }
+                    "#,
+                    expected: BybitOrderBookMessage::OrderBook(BybitOrderBook {
+                        subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
+                        r#type: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672304484978,
+                        )),
+                        data: BybitOrderBookInner {
+                            market: "BTCUSDT".to_string(),
+                            bids: vec![
+                                BybitLevel {
+                                    price: num!(16493.50),
+                                    amount: num!(0.006),
+                                },
+                                BybitLevel {
+                                    price: num!(16493.00),
+                                    amount: num!(0.100),
+                                },
+                            ],
+                            asks: vec![BybitLevel {
+                                price: num!(16611.00),
+                                amount: num!(0.029),
+                            }],
+                            update_id: 18521288,
+                            sequence: 7961638724,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: input BybitOrderBookMessage::Response pong is deserialised
+                    input: r#"
+                    {
+                        "success": true,
+                        "ret_msg": "pong",
+                        "conn_id": "0970e817-426e-429a-a679-ff7f55e0b16a",
+                        "op": "ping"
+                    }
+                    "#,
+                    expected: BybitOrderBookMessage::Response(BybitResponse {
+                        success: true,
+                        ret_msg: BybitReturnMessage::Pong,
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitOrderBookMessage>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
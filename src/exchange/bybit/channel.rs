@@ -1,6 +1,15 @@
 use crate::{
-    exchange::bybit::Bybit,
-    subscription::{trade::PublicTrades, Subscription},
+    exchange::bybit::{futures::BybitPerpetualsUsd, Bybit},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        funding::FundingRates,
+        liquidation::Liquidations,
+        mark_price::MarkPrices,
+        open_interest::OpenInterests,
+        ticker::Tickers,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +26,27 @@ impl BybitChannel {
     ///
     /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
     pub const TRADES: Self = Self("publicTrade");
+
+    /// [`Bybit`](super::Bybit) real-time OrderBook Level1 (top of book) channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub const ORDER_BOOK_L1: Self = Self("orderbook.1");
+
+    /// [`Bybit`](super::Bybit) real-time OrderBook Level2 channel name (50 depth, supported by
+    /// both spot & linear servers).
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub const ORDER_BOOK_L2: Self = Self("orderbook.50");
+
+    /// [`Bybit`](super::Bybit) real-time tickers channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+    pub const TICKERS: Self = Self("tickers");
+
+    /// [`BybitPerpetualsUsd`] real-time liquidations channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/liquidation>
+    pub const LIQUIDATIONS: Self = Self("liquidation");
 }
 
 impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, PublicTrades> {
@@ -25,6 +55,54 @@ impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, PublicTrad
     }
 }
 
+impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, OrderBooksL1> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDER_BOOK_L1
+    }
+}
+
+impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, OrderBooksL2> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, OrderBooksL2Delta> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, Tickers> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitPerpetualsUsd, FundingRates> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitPerpetualsUsd, OpenInterests> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitPerpetualsUsd, MarkPrices> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitPerpetualsUsd, Liquidations> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::LIQUIDATIONS
+    }
+}
+
 impl AsRef<str> for BybitChannel {
     fn as_ref(&self) -> &str {
         self.0
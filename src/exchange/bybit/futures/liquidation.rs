@@ -0,0 +1,208 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{
+        bybit::{message::BybitPayload, subscription::BybitResponse},
+        ExchangeId,
+    },
+    number::Number,
+    subscription::liquidation::Liquidation,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`BybitPerpetualsUsd`](super::BybitPerpetualsUsd) real-time
+/// liquidation WebSocket message.
+pub type BybitLiquidation = BybitPayload<BybitLiquidationInner>;
+
+/// [`BybitPerpetualsUsd`](super::BybitPerpetualsUsd) liquidation WebSocket message, which may
+/// also be a [`BybitResponse`] (eg/ pong) received on the same connection.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BybitLiquidationMessage {
+    Response(BybitResponse),
+    Liquidation(BybitLiquidation),
+}
+
+/// [`BybitPerpetualsUsd`](super::BybitPerpetualsUsd) liquidation data.
+///
+/// ### Notes
+/// The `side` is the side of the liquidated position, ie/ "Buy" means a long position has been
+/// liquidated.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/liquidation>
+/// ```json
+/// {
+///     "topic": "liquidation.BTCUSDT",
+///     "type": "snapshot",
+///     "ts": 1703485237953,
+///     "data": {
+///         "updatedTime": 1703485237953,
+///         "symbol": "BTCUSDT",
+///         "side": "Sell",
+///         "size": "0.003",
+///         "price": "43511.70"
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitLiquidationInner {
+    pub symbol: String,
+    pub side: Side,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub size: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub updated_time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitLiquidationMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitLiquidationMessage::Liquidation(liquidation) => {
+                Some(liquidation.subscription_id.clone())
+            }
+            BybitLiquidationMessage::Response(_) => None,
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BybitLiquidationMessage)> for MarketIter<Liquidation> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BybitLiquidationMessage),
+    ) -> Self {
+        let liquidation = match message {
+            BybitLiquidationMessage::Liquidation(liquidation) => liquidation,
+            BybitLiquidationMessage::Response(_) => return Self(vec![]),
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: liquidation.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Liquidation {
+                // Normalise the liquidated position side to the liquidation order side
+                side: match liquidation.data.side {
+                    Side::Buy => Side::Sell,
+                    Side::Sell => Side::Buy,
+                },
+                price: liquidation.data.price,
+                quantity: liquidation.data.size,
+                time: liquidation.data.updated_time,
+                average_price: None,
+                filled_quantity: None,
+                status: None,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, model::instrument::kind::InstrumentKind,
+    };
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bybit_liquidation() {
+            struct TestCase {
+                input: &'static str,
+                expected: BybitLiquidation,
+            }
+
+            let tests = vec![TestCase {
+                // TC0: input BybitLiquidation is deserialised
+                input: r#"
+                {
+                    "topic": "liquidation.BTCUSDT",
+                    "type": "snapshot",
+                    "ts": 1703485237953,
+                    "data": {
+                        "updatedTime": 1703485237953,
+                        "symbol": "BTCUSDT",
+                        "side": "Sell",
+                        "size": "0.003",
+                        "price": "43511.70"
+                    }
+                }
+                "#,
+                expected: BybitLiquidation {
+                    subscription_id: SubscriptionId::from("liquidation|BTCUSDT"),
+                    r#type: "snapshot".to_string(),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1703485237953)),
+                    data: BybitLiquidationInner {
+                        symbol: "BTCUSDT".to_string(),
+                        side: Side::Sell,
+                        size: num!(0.003),
+                        price: num!(43511.70),
+                        updated_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1703485237953,
+                        )),
+                    },
+                },
+            }];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitLiquidation>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bybit_liquidation_side_is_normalised() {
+        struct TestCase {
+            position_side: Side,
+            expected: Side,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: liquidated long position yields a Side::Sell liquidation
+                position_side: Side::Buy,
+                expected: Side::Sell,
+            },
+            TestCase {
+                // TC1: liquidated short position yields a Side::Buy liquidation
+                position_side: Side::Sell,
+                expected: Side::Buy,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let time = datetime_utc_from_epoch_duration(Duration::from_millis(1703485237953));
+            let message = BybitLiquidationMessage::Liquidation(BybitLiquidation {
+                subscription_id: SubscriptionId::from("liquidation|BTCUSDT"),
+                r#type: "snapshot".to_string(),
+                time,
+                data: BybitLiquidationInner {
+                    symbol: "BTCUSDT".to_string(),
+                    side: test.position_side,
+                    size: num!(0.003),
+                    price: num!(43511.70),
+                    updated_time: time,
+                },
+            });
+
+            let actual = MarketIter::<Liquidation>::from((
+                ExchangeId::BybitPerpetualsUsd,
+                Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                message,
+            ));
+
+            let kind = actual.0[0].as_ref().unwrap().kind;
+            assert_eq!(kind.side, test.expected, "TC{} failed", index);
+        }
+    }
+}
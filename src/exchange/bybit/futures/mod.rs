@@ -1,5 +1,17 @@
-use super::{Bybit, ExchangeServer};
-use crate::exchange::ExchangeId;
+use self::liquidation::BybitLiquidationMessage;
+use super::{ticker::BybitTickerMessage, Bybit, ExchangeServer};
+use crate::{
+    exchange::{ExchangeId, StreamSelector},
+    subscription::{
+        funding::FundingRates, liquidation::Liquidations, mark_price::MarkPrices,
+        open_interest::OpenInterests,
+    },
+    transformer::{merge::MergeTransformer, stateless::StatelessTransformer},
+    ExchangeWsStream,
+};
+
+/// Liquidation types for [`BybitPerpetualsUsd`].
+pub mod liquidation;
 
 /// [`BybitPerpetualsUsd`] WebSocket server base url.
 ///
@@ -20,3 +32,20 @@ impl ExchangeServer for BybitServerPerpetualsUsd {
         WEBSOCKET_BASE_URL_BYBIT_PERPETUALS_USD
     }
 }
+
+impl StreamSelector<FundingRates> for BybitPerpetualsUsd {
+    type Stream = ExchangeWsStream<MergeTransformer<Self, FundingRates, BybitTickerMessage>>;
+}
+
+impl StreamSelector<OpenInterests> for BybitPerpetualsUsd {
+    type Stream = ExchangeWsStream<MergeTransformer<Self, OpenInterests, BybitTickerMessage>>;
+}
+
+impl StreamSelector<MarkPrices> for BybitPerpetualsUsd {
+    type Stream = ExchangeWsStream<MergeTransformer<Self, MarkPrices, BybitTickerMessage>>;
+}
+
+impl StreamSelector<Liquidations> for BybitPerpetualsUsd {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Liquidations, BybitLiquidationMessage>>;
+}
@@ -60,10 +60,10 @@ pub struct BybitPayload<T> {
     pub data: T,
 }
 
-/// Deserialize a [`BybitPayload`] "s" (eg/ "publicTrade.BTCUSDT") as the associated
-/// [`SubscriptionId`].
+/// Deserialize a [`BybitPayload`] "topic" (eg/ "publicTrade.BTCUSDT" or
+/// "orderbook.50.BTCUSDT") as the associated [`SubscriptionId`].
 ///
-/// eg/ "publicTrade|BTCUSDT"
+/// eg/ "publicTrade|BTCUSDT" or "orderbook.50|BTCUSDT"
 pub fn de_message_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -71,14 +71,25 @@ where
     let input = <&str as serde::Deserialize>::deserialize(deserializer)?;
     let mut tokens = input.split('.');
 
-    match (tokens.next(), tokens.next(), tokens.next()) {
-        (Some("publicTrade"), Some(market), None) => Ok(SubscriptionId::from(format!(
+    match (tokens.next(), tokens.next(), tokens.next(), tokens.next()) {
+        (Some("publicTrade"), Some(market), None, None) => Ok(SubscriptionId::from(format!(
             "{}|{market}",
             BybitChannel::TRADES.0
         ))),
+        (Some("tickers"), Some(market), None, None) => Ok(SubscriptionId::from(format!(
+            "{}|{market}",
+            BybitChannel::TICKERS.0
+        ))),
+        (Some("liquidation"), Some(market), None, None) => Ok(SubscriptionId::from(format!(
+            "{}|{market}",
+            BybitChannel::LIQUIDATIONS.0
+        ))),
+        (Some("orderbook"), Some(depth), Some(market), None) => {
+            Ok(SubscriptionId::from(format!("orderbook.{depth}|{market}")))
+        }
         _ => Err(Error::invalid_value(
             Unexpected::Str(input),
-            &"invalid message type expected pattern: <type>.<symbol>",
+            &"invalid message type expected pattern: <type>.<symbol> or <type>.<depth>.<symbol>",
         )),
     }
 }
@@ -1,16 +1,29 @@
 use crate::{
     exchange::{
         bybit::{
-            channel::BybitChannel, market::BybitMarket, message::BybitMessage,
+            book::{l2::BybitBookUpdater, BybitOrderBookMessage},
+            channel::BybitChannel,
+            market::BybitMarket,
+            message::BybitMessage,
             subscription::BybitResponse,
+            ticker::BybitTickerMessage,
         },
         subscription::ExchangeSub,
         Connector, ExchangeId, ExchangeServer, PingInterval, StreamSelector,
     },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        ticker::Tickers,
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{
+        book::{MultiBookDeltaTransformer, MultiBookTransformer},
+        merge::MergeTransformer,
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, TimedExchangeWsStream,
 };
 use barter_integration::{
     error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
@@ -20,6 +33,10 @@ use std::{fmt::Debug, marker::PhantomData, time::Duration};
 use tokio::time;
 use url::Url;
 
+/// OrderBook types common to both [`BybitSpot`](spot::BybitSpot) and
+/// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -45,6 +62,12 @@ pub mod spot;
 /// and [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod subscription;
 
+/// Ticker types used by [`BybitFuturesUsd`](futures::BybitPerpetualsUsd) derived streams
+/// (eg/ [`FundingRates`](crate::subscription::funding::FundingRates),
+/// [`OpenInterests`](crate::subscription::open_interest::OpenInterests) &
+/// [`MarkPrices`](crate::subscription::mark_price::MarkPrices)).
+pub mod ticker;
+
 /// Public trade types common to both [`BybitSpot`](spot::BybitSpot) and
 /// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod trade;
@@ -115,6 +138,39 @@ where
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BybitMessage>>;
 }
 
+impl<Server> StreamSelector<OrderBooksL1> for Bybit<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, BybitOrderBookMessage>>;
+}
+
+impl<Server> StreamSelector<OrderBooksL2> for Bybit<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = TimedExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BybitBookUpdater>>;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl<Server> StreamSelector<OrderBooksL2Delta> for Bybit<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream =
+        ExchangeWsStream<MultiBookDeltaTransformer<Self, OrderBooksL2Delta, BybitBookUpdater>>;
+}
+
+impl<Server> StreamSelector<Tickers> for Bybit<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<MergeTransformer<Self, Tickers, BybitTickerMessage>>;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Bybit<Server>
 where
     Server: ExchangeServer,
@@ -0,0 +1,494 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{
+        bybit::{message::BybitPayload, subscription::BybitResponse},
+        ExchangeId,
+    },
+    number::{Number, ONE_HUNDRED},
+    subscription::{
+        funding::FundingRate, mark_price::MarkPrice, open_interest::OpenInterest, ticker::Ticker,
+    },
+    transformer::merge::Merge,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Bybit`](super::Bybit) real-time tickers WebSocket message.
+pub type BybitTicker = BybitPayload<BybitTickerInner>;
+
+/// [`Bybit`](super::Bybit) tickers WebSocket message, which may also be a [`BybitResponse`]
+/// (eg/ pong) received on the same connection.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BybitTickerMessage {
+    Response(BybitResponse),
+    Ticker(Box<BybitTicker>),
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitTickerMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitTickerMessage::Ticker(ticker) => Some(ticker.subscription_id.clone()),
+            BybitTickerMessage::Response(_) => None,
+        }
+    }
+}
+
+impl Merge for BybitTickerMessage {
+    fn merge(&mut self, update: Self) {
+        match (self, update) {
+            (BybitTickerMessage::Ticker(ticker), BybitTickerMessage::Ticker(update)) => {
+                ticker.merge(*update)
+            }
+            (state, update) => *state = update,
+        }
+    }
+}
+
+impl Merge for BybitTicker {
+    fn merge(&mut self, update: Self) {
+        self.r#type = update.r#type;
+        self.time = update.time;
+        self.data.merge(update.data);
+    }
+}
+
+/// [`Bybit`](super::Bybit) ticker data.
+///
+/// ### Notes
+/// [`BybitPerpetualsUsd`](super::futures::BybitPerpetualsUsd) sends an initial "snapshot",
+/// followed by "delta" messages that only contain the fields that changed. Every field is
+/// therefore optional, and deltas are [`Merge`]d into the latest snapshot.
+/// [`BybitSpot`](super::spot::BybitSpot) only sends "snapshot" messages, which do not contain
+/// any derivative fields (eg/ `markPrice`).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+/// ```json
+/// {
+///     "topic": "tickers.BTCUSDT",
+///     "type": "snapshot",
+///     "data": {
+///         "symbol": "BTCUSDT",
+///         "tickDirection": "PlusTick",
+///         "price24hPcnt": "0.017103",
+///         "lastPrice": "17216.00",
+///         "prevPrice24h": "16926.50",
+///         "highPrice24h": "17281.50",
+///         "lowPrice24h": "16915.00",
+///         "prevPrice1h": "17238.00",
+///         "markPrice": "17217.33",
+///         "indexPrice": "17227.36",
+///         "openInterest": "68744.761",
+///         "openInterestValue": "1183601235.91",
+///         "turnover24h": "1570383121.943499",
+///         "volume24h": "91705.276",
+///         "nextFundingTime": "1673280000000",
+///         "fundingRate": "-0.000212",
+///         "bid1Price": "17215.50",
+///         "bid1Size": "84.489",
+///         "ask1Price": "17216.00",
+///         "ask1Size": "83.020"
+///     },
+///     "cs": 24987956059,
+///     "ts": 1673272861686
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitTickerInner {
+    pub symbol: String,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub last_price: Option<Number>,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub prev_price_24h: Option<Number>,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub high_price_24h: Option<Number>,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub low_price_24h: Option<Number>,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub volume_24h: Option<Number>,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub turnover_24h: Option<Number>,
+    #[serde(rename = "price24hPcnt", default, deserialize_with = "de_option_str")]
+    pub price_24h_pcnt: Option<Number>,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub mark_price: Option<Number>,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub index_price: Option<Number>,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub funding_rate: Option<Number>,
+    #[serde(default, deserialize_with = "de_option_str_epoch_ms_as_datetime_utc")]
+    pub next_funding_time: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub open_interest: Option<Number>,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub open_interest_value: Option<Number>,
+}
+
+impl Merge for BybitTickerInner {
+    fn merge(&mut self, update: Self) {
+        self.last_price = update.last_price.or(self.last_price);
+        self.prev_price_24h = update.prev_price_24h.or(self.prev_price_24h);
+        self.high_price_24h = update.high_price_24h.or(self.high_price_24h);
+        self.low_price_24h = update.low_price_24h.or(self.low_price_24h);
+        self.volume_24h = update.volume_24h.or(self.volume_24h);
+        self.turnover_24h = update.turnover_24h.or(self.turnover_24h);
+        self.price_24h_pcnt = update.price_24h_pcnt.or(self.price_24h_pcnt);
+        self.mark_price = update.mark_price.or(self.mark_price);
+        self.index_price = update.index_price.or(self.index_price);
+        self.funding_rate = update.funding_rate.or(self.funding_rate);
+        self.next_funding_time = update.next_funding_time.or(self.next_funding_time);
+        self.open_interest = update.open_interest.or(self.open_interest);
+        self.open_interest_value = update.open_interest_value.or(self.open_interest_value);
+    }
+}
+
+impl From<(ExchangeId, Instrument, BybitTickerMessage)> for MarketIter<FundingRate> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BybitTickerMessage),
+    ) -> Self {
+        let ticker = match message {
+            BybitTickerMessage::Ticker(ticker) => ticker,
+            BybitTickerMessage::Response(_) => return Self(vec![]),
+        };
+
+        // Spot tickers & incomplete snapshots do not contain a funding rate
+        let Some(rate) = ticker.data.funding_rate else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: FundingRate {
+                rate,
+                predicted_rate: None,
+                next_funding_time: ticker.data.next_funding_time,
+                mark_price: ticker.data.mark_price,
+            },
+        })])
+    }
+}
+
+impl From<(ExchangeId, Instrument, BybitTickerMessage)> for MarketIter<OpenInterest> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BybitTickerMessage),
+    ) -> Self {
+        let ticker = match message {
+            BybitTickerMessage::Ticker(ticker) => ticker,
+            BybitTickerMessage::Response(_) => return Self(vec![]),
+        };
+
+        // Spot tickers & incomplete snapshots do not contain open interest
+        let Some(contracts) = ticker.data.open_interest else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OpenInterest {
+                contracts,
+                notional: ticker.data.open_interest_value,
+                time: ticker.time,
+            },
+        })])
+    }
+}
+
+impl From<(ExchangeId, Instrument, BybitTickerMessage)> for MarketIter<MarkPrice> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BybitTickerMessage),
+    ) -> Self {
+        let ticker = match message {
+            BybitTickerMessage::Ticker(ticker) => ticker,
+            BybitTickerMessage::Response(_) => return Self(vec![]),
+        };
+
+        // Spot tickers & incomplete snapshots do not contain a mark price
+        let Some(mark) = ticker.data.mark_price else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: MarkPrice {
+                mark,
+                index: ticker.data.index_price,
+                estimated_settle: None,
+                time: ticker.time,
+            },
+        })])
+    }
+}
+
+impl From<(ExchangeId, Instrument, BybitTickerMessage)> for MarketIter<Ticker> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BybitTickerMessage),
+    ) -> Self {
+        let ticker = match message {
+            BybitTickerMessage::Ticker(ticker) => ticker,
+            BybitTickerMessage::Response(_) => return Self(vec![]),
+        };
+
+        // Incomplete snapshots may not contain the rolling 24h statistics
+        let (Some(last), Some(high), Some(low), Some(volume_base)) = (
+            ticker.data.last_price,
+            ticker.data.high_price_24h,
+            ticker.data.low_price_24h,
+            ticker.data.volume_24h,
+        ) else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Ticker {
+                last,
+                open: ticker.data.prev_price_24h,
+                high,
+                low,
+                volume_base,
+                volume_quote: ticker.data.turnover_24h,
+                change_percent: ticker
+                    .data
+                    .price_24h_pcnt
+                    .map(|change| change * ONE_HUNDRED),
+                time: ticker.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize an optional, possibly empty, `String` as an optional [`Number`].
+fn de_option_str<'de, D>(deserializer: D) -> Result<Option<Number>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Deserialize an optional `String` u64 milliseconds value as an optional `DateTime<Utc>`.
+fn de_option_str_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            value
+                .parse::<i64>()
+                .map_err(serde::de::Error::custom)
+                .and_then(|epoch_ms| {
+                    Utc.timestamp_millis_opt(epoch_ms)
+                        .single()
+                        .ok_or_else(|| serde::de::Error::custom("invalid epoch milliseconds"))
+                })
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_ticker() {
+            struct TestCase {
+                input: &'static str,
+                expected: BybitTicker,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input linear snapshot is deserialised
+                    input: r#"
+                    {
+                        "topic": "tickers.BTCUSDT",
+                        "type": "snapshot",
+                        "data": {
+                            "symbol": "BTCUSDT",
+                            "tickDirection": "PlusTick",
+                            "price24hPcnt": "0.017103",
+                            "lastPrice": "17216.00",
+                            "prevPrice24h": "16926.50",
+                            "highPrice24h": "17281.50",
+                            "lowPrice24h": "16915.00",
+                            "turnover24h": "1570383121.943499",
+                            "volume24h": "91705.276",
+                            "markPrice": "17217.33",
+                            "indexPrice": "17227.36",
+                            "openInterest": "68744.761",
+                            "openInterestValue": "1183601235.91",
+                            "nextFundingTime": "1673280000000",
+                            "fundingRate": "-0.000212",
+                            "bid1Price": "17215.50"
+                        },
+                        "cs": 24987956059,
+                        "ts": 1673272861686
+                    }
+                    "#,
+                    expected: BybitTicker {
+                        subscription_id: SubscriptionId::from("tickers|BTCUSDT"),
+                        r#type: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1673272861686,
+                        )),
+                        data: BybitTickerInner {
+                            symbol: "BTCUSDT".to_string(),
+                            last_price: Some(num!(17216.0)),
+                            prev_price_24h: Some(num!(16926.5)),
+                            high_price_24h: Some(num!(17281.5)),
+                            low_price_24h: Some(num!(16915.0)),
+                            volume_24h: Some(num!(91705.276)),
+                            turnover_24h: Some(num!(1570383121.943499)),
+                            price_24h_pcnt: Some(num!(0.017103)),
+                            mark_price: Some(num!(17217.33)),
+                            index_price: Some(num!(17227.36)),
+                            funding_rate: Some(num!(-0.000212)),
+                            next_funding_time: Some(datetime_utc_from_epoch_duration(
+                                Duration::from_millis(1673280000000),
+                            )),
+                            open_interest: Some(num!(68744.761)),
+                            open_interest_value: Some(num!(1183601235.91)),
+                        },
+                    },
+                },
+                TestCase {
+                    // TC1: input spot snapshot is deserialised
+                    input: r#"
+                    {
+                        "topic": "tickers.BTCUSDT",
+                        "ts": 1673853746003,
+                        "type": "snapshot",
+                        "cs": 2588407389,
+                        "data": {
+                            "symbol": "BTCUSDT",
+                            "lastPrice": "21109.77",
+                            "highPrice24h": "21426.99",
+                            "lowPrice24h": "20575",
+                            "prevPrice24h": "20704.93",
+                            "volume24h": "6780.866843",
+                            "turnover24h": "141946527.22907118",
+                            "price24hPcnt": "0.0196",
+                            "usdIndexPrice": "21120.2400136"
+                        }
+                    }
+                    "#,
+                    expected: BybitTicker {
+                        subscription_id: SubscriptionId::from("tickers|BTCUSDT"),
+                        r#type: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1673853746003,
+                        )),
+                        data: BybitTickerInner {
+                            symbol: "BTCUSDT".to_string(),
+                            last_price: Some(num!(21109.77)),
+                            prev_price_24h: Some(num!(20704.93)),
+                            high_price_24h: Some(num!(21426.99)),
+                            low_price_24h: Some(num!(20575.0)),
+                            volume_24h: Some(num!(6780.866843)),
+                            turnover_24h: Some(num!(141946527.22907118)),
+                            price_24h_pcnt: Some(num!(0.0196)),
+                            ..Default::default()
+                        },
+                    },
+                },
+                TestCase {
+                    // TC2: input linear delta with missing fields is deserialised
+                    input: r#"
+                    {
+                        "topic": "tickers.BTCUSDT",
+                        "type": "delta",
+                        "data": {
+                            "symbol": "BTCUSDT",
+                            "markPrice": "17218.00",
+                            "bid1Price": "17215.50"
+                        },
+                        "cs": 24987956060,
+                        "ts": 1673272861786
+                    }
+                    "#,
+                    expected: BybitTicker {
+                        subscription_id: SubscriptionId::from("tickers|BTCUSDT"),
+                        r#type: "delta".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1673272861786,
+                        )),
+                        data: BybitTickerInner {
+                            symbol: "BTCUSDT".to_string(),
+                            mark_price: Some(num!(17218.0)),
+                            ..Default::default()
+                        },
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitTicker>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bybit_ticker_merge() {
+        let mut state = BybitTickerInner {
+            symbol: "BTCUSDT".to_string(),
+            last_price: Some(num!(17216.0)),
+            high_price_24h: Some(num!(17281.5)),
+            mark_price: Some(num!(17217.33)),
+            index_price: Some(num!(17227.36)),
+            funding_rate: Some(num!(-0.000212)),
+            open_interest: Some(num!(68744.761)),
+            ..Default::default()
+        };
+
+        state.merge(BybitTickerInner {
+            symbol: "BTCUSDT".to_string(),
+            last_price: Some(num!(17290.0)),
+            high_price_24h: Some(num!(17290.0)),
+            mark_price: Some(num!(17218.0)),
+            funding_rate: Some(num!(-0.0002)),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            state,
+            BybitTickerInner {
+                symbol: "BTCUSDT".to_string(),
+                last_price: Some(num!(17290.0)),
+                high_price_24h: Some(num!(17290.0)),
+                mark_price: Some(num!(17218.0)),
+                index_price: Some(num!(17227.36)),
+                funding_rate: Some(num!(-0.0002)),
+                open_interest: Some(num!(68744.761)),
+                ..Default::default()
+            }
+        );
+    }
+}
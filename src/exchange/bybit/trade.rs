@@ -1,6 +1,7 @@
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{bybit::message::BybitPayload, ExchangeId},
+    number::Number,
     subscription::trade::PublicTrade,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
@@ -40,10 +41,10 @@ pub struct BybitTradeInner {
     pub side: Side,
 
     #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Number,
 
     #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
 
     #[serde(rename = "i")]
     pub id: String,
@@ -77,6 +78,7 @@ impl From<(ExchangeId, Instrument, BybitTrade)> for MarketIter<PublicTrade> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::number::num;
 
     mod de {
         use super::*;
@@ -113,8 +115,8 @@ mod tests {
                         )),
                         market: "BTCUSDT".to_string(),
                         side: Side::Buy,
-                        amount: 0.001,
-                        price: 16578.50,
+                        amount: num!(0.001),
+                        price: num!(16578.50),
                         id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
                     }),
                 },
@@ -138,8 +140,8 @@ mod tests {
                         )),
                         market: "BTCUSDT".to_string(),
                         side: Side::Sell,
-                        amount: 0.001,
-                        price: 16578.50,
+                        amount: num!(0.001),
+                        price: num!(16578.50),
                         id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
                     }),
                 },
@@ -233,8 +235,8 @@ mod tests {
                                 )),
                                 market: "BTCUSDT".to_string(),
                                 side: Side::Buy,
-                                amount: 0.001,
-                                price: 16578.50,
+                                amount: num!(0.001),
+                                price: num!(16578.50),
                                 id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
                             },
                             BybitTradeInner {
@@ -243,8 +245,8 @@ mod tests {
                                 )),
                                 market: "BTCUSDT".to_string(),
                                 side: Side::Sell,
-                                amount: 0.001,
-                                price: 16578.50,
+                                amount: num!(0.001),
+                                price: num!(16578.50),
                                 id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
                             },
                         ],
@@ -0,0 +1,132 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{coinbase::channel::CoinbaseChannel, ExchangeId, ExchangeSub},
+    number::Number,
+    subscription::book::{Level, OrderBookL1},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Coinbase`](super::super::Coinbase) real-time ticker WebSocket message, used to generate
+/// OrderBook Level1 (top of book) snapshots.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#ticker-channel>
+/// ```json
+/// {
+///     "type": "ticker",
+///     "sequence": 37475248783,
+///     "product_id": "ETH-USD",
+///     "price": "1285.22",
+///     "open_24h": "1310.79",
+///     "volume_24h": "245532.79269678",
+///     "low_24h": "1280.52",
+///     "high_24h": "1313.8",
+///     "volume_30d": "9788783.60117027",
+///     "best_bid": "1285.04",
+///     "best_bid_size": "0.46688654",
+///     "best_ask": "1285.27",
+///     "best_ask_size": "1.56637040",
+///     "side": "buy",
+///     "time": "2022-10-19T23:28:22.061769Z",
+///     "trade_id": 370843401,
+///     "last_size": "11.4396987"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderBookL1 {
+    #[serde(alias = "product_id", deserialize_with = "de_ob_l1_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub time: DateTime<Utc>,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub best_bid: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub best_bid_size: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub best_ask: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub best_ask_size: Number,
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL1 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, CoinbaseOrderBookL1)> for MarketIter<OrderBookL1> {
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, Instrument, CoinbaseOrderBookL1),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: book.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: book.time,
+                best_bid: Level::new(book.best_bid, book.best_bid_size),
+                best_ask: Level::new(book.best_ask, book.best_ask_size),
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`CoinbaseOrderBookL1`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("ticker|BTC-USD").
+pub fn de_ob_l1_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L1, product_id)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_coinbase_order_book_l1() {
+            let input = r#"
+            {
+                "type": "ticker",
+                "sequence": 37475248783,
+                "product_id": "ETH-USD",
+                "price": "1285.22",
+                "open_24h": "1310.79",
+                "volume_24h": "245532.79269678",
+                "low_24h": "1280.52",
+                "high_24h": "1313.8",
+                "volume_30d": "9788783.60117027",
+                "best_bid": "1285.04",
+                "best_bid_size": "0.46688654",
+                "best_ask": "1285.27",
+                "best_ask_size": "1.56637040",
+                "side": "buy",
+                "time": "2022-10-19T23:28:22.061769Z",
+                "trade_id": 370843401,
+                "last_size": "11.4396987"
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<CoinbaseOrderBookL1>(input).unwrap(),
+                CoinbaseOrderBookL1 {
+                    subscription_id: SubscriptionId::from("ticker|ETH-USD"),
+                    time: "2022-10-19T23:28:22.061769Z".parse().unwrap(),
+                    best_bid: num!(1285.04),
+                    best_bid_size: num!(0.46688654),
+                    best_ask: num!(1285.27),
+                    best_ask_size: num!(1.56637040),
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use crate::{
+    error::DataError,
+    exchange::{coinbase::channel::CoinbaseChannel, ExchangeSub},
+    number::Number,
+    subscription::book::{Level, OrderBook, OrderBookDelta, OrderBookSide},
+    transformer::book::{
+        InstrumentOrderBook, OrderBookChange, OrderBookDeltaUpdater, OrderBookUpdater,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Coinbase`](super::super::Coinbase) real-time OrderBook Level2 WebSocket message from the
+/// "level2_batch" channel.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+/// #### Snapshot
+/// ```json
+/// {
+///     "type": "snapshot",
+///     "product_id": "BTC-USD",
+///     "bids": [["10101.10", "0.45054140"]],
+///     "asks": [["10102.55", "0.57753524"]]
+/// }
+/// ```
+///
+/// #### Update
+/// ```json
+/// {
+///     "type": "l2update",
+///     "product_id": "BTC-USD",
+///     "changes": [["buy", "22356.270000", "0.00000000"], ["sell", "22356.300000", "1.00000000"]],
+///     "time": "2022-08-04T15:25:05.010758Z"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoinbaseOrderBookL2 {
+    Snapshot {
+        #[serde(rename = "product_id", deserialize_with = "de_ob_l2_subscription_id")]
+        subscription_id: SubscriptionId,
+        bids: Vec<CoinbaseLevel>,
+        asks: Vec<CoinbaseLevel>,
+    },
+    #[serde(rename = "l2update")]
+    Update {
+        #[serde(rename = "product_id", deserialize_with = "de_ob_l2_subscription_id")]
+        subscription_id: SubscriptionId,
+        time: DateTime<Utc>,
+        changes: Vec<CoinbaseLevelChange>,
+    },
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            CoinbaseOrderBookL2::Snapshot {
+                subscription_id, ..
+            }
+            | CoinbaseOrderBookL2::Update {
+                subscription_id, ..
+            } => Some(subscription_id.clone()),
+        }
+    }
+}
+
+/// [`Coinbase`](super::super::Coinbase) OrderBook Level2 snapshot level.
+///
+/// #### Raw Payload Examples
+/// ```json
+/// ["10101.10", "0.45054140"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseLevel {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+}
+
+impl From<CoinbaseLevel> for Level {
+    fn from(level: CoinbaseLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`Coinbase`](super::super::Coinbase) OrderBook Level2 update change, where an amount of zero
+/// indicates the level should be removed.
+///
+/// #### Raw Payload Examples
+/// ```json
+/// ["buy", "22356.270000", "0.00000000"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseLevelChange {
+    pub side: Side,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+}
+
+impl From<CoinbaseLevelChange> for Level {
+    fn from(change: CoinbaseLevelChange) -> Self {
+        Self {
+            price: change.price,
+            amount: change.amount,
+        }
+    }
+}
+
+/// Deserialize a [`CoinbaseOrderBookL2`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("level2_batch|BTC-USD").
+pub fn de_ob_l2_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L2, product_id)).id())
+}
+
+/// [`Coinbase`](super::super::Coinbase) [`OrderBookUpdater`] that maintains an OrderBook Level2
+/// from the "level2_batch" channel.
+///
+/// ### Notes
+/// - No HTTP snapshot is required since [`Coinbase`](super::super::Coinbase) sends a snapshot
+///   as the first WebSocket message after subscribing.
+/// - Any "l2update" received before the initial snapshot is ignored.
+/// - "level2_batch" messages carry no sequence numbers, so update continuity cannot be
+///   validated.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+#[derive(
+    Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct CoinbaseBookUpdater {
+    pub snapshot_received: bool,
+}
+
+#[async_trait]
+impl OrderBookUpdater for CoinbaseBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = CoinbaseOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        _: Option<usize>,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Initial OrderBook snapshot is sent as the first WebSocket message
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+}
+
+impl OrderBookDeltaUpdater for CoinbaseBookUpdater {
+    fn update_delta(
+        &mut self,
+        book: &mut OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookChange>, DataError> {
+        match update {
+            CoinbaseOrderBookL2::Snapshot { bids, asks, .. } => {
+                // Coinbase snapshots do not contain a timestamp
+                book.last_update_time = Utc::now();
+                book.bids = OrderBookSide::new(Side::Buy, bids);
+                book.asks = OrderBookSide::new(Side::Sell, asks);
+                self.snapshot_received = true;
+                Ok(Some(OrderBookChange::Snapshot))
+            }
+            CoinbaseOrderBookL2::Update { .. } if !self.snapshot_received => Ok(None),
+            CoinbaseOrderBookL2::Update { time, changes, .. } => {
+                book.last_update_time = time;
+                let mut delta = OrderBookDelta {
+                    last_update_time: time,
+                    prev_sequence: None,
+                    sequence: None,
+                    bids: vec![],
+                    asks: vec![],
+                };
+
+                for change in changes {
+                    let (side, levels) = match change.side {
+                        Side::Buy => (&mut book.bids, &mut delta.bids),
+                        Side::Sell => (&mut book.asks, &mut delta.asks),
+                    };
+                    levels.extend(side.upsert_single_delta(change));
+                }
+
+                Ok(Some(OrderBookChange::Delta(delta)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_coinbase_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: CoinbaseOrderBookL2,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input CoinbaseOrderBookL2::Snapshot is deserialised
+                    input: r#"
+                    {
+                        "type": "snapshot",
+                        "product_id": "BTC-USD",
+                        "bids": [["10101.10", "0.45054140"]],
+                        "asks": [["10102.55", "0.57753524"]]
+                    }
+                    "#,
+                    expected: CoinbaseOrderBookL2::Snapshot {
+                        subscription_id: SubscriptionId::from("level2_batch|BTC-USD"),
+                        bids: vec![CoinbaseLevel {
+                            price: num!(10101.10),
+                            amount: num!(0.45054140),
+                        }],
+                        asks: vec![CoinbaseLevel {
+                            price: num!(10102.55),
+                            amount: num!(0.57753524),
+                        }],
+                    },
+                },
+                TestCase {
+                    // TC1: input CoinbaseOrderBookL2::Update is deserialised
+                    input: r#"
+                    {
+                        "type": "l2update",
+                        "product_id": "BTC-USD",
+                        "changes": [["buy", "22356.270000", "0.00000000"], ["sell", "22356.300000", "1.00000000"]],
+                        "time": "2022-08-04T15:25:05.010758Z"
+                    }
+                    "#,
+                    expected: CoinbaseOrderBookL2::Update {
+                        subscription_id: SubscriptionId::from("level2_batch|BTC-USD"),
+                        time: "2022-08-04T15:25:05.010758Z".parse().unwrap(),
+                        changes: vec![
+                            CoinbaseLevelChange {
+                                side: Side::Buy,
+                                price: num!(22356.27),
+                                amount: num!(0.0),
+                            },
+                            CoinbaseLevelChange {
+                                side: Side::Sell,
+                                price: num!(22356.3),
+                                amount: num!(1.0),
+                            },
+                        ],
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<CoinbaseOrderBookL2>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_coinbase_book_updater_update() {
+        struct TestCase {
+            updater: CoinbaseBookUpdater,
+            input: CoinbaseOrderBookL2,
+            expected: Option<(Vec<Level>, Vec<Level>)>,
+        }
+
+        let subscription_id = SubscriptionId::from("level2_batch|BTC-USD");
+        let change = |side, price, amount| CoinbaseLevelChange {
+            side,
+            price,
+            amount,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot replaces the existing OrderBook
+                updater: CoinbaseBookUpdater::default(),
+                input: CoinbaseOrderBookL2::Snapshot {
+                    subscription_id: subscription_id.clone(),
+                    bids: vec![CoinbaseLevel {
+                        price: num!(90.0),
+                        amount: num!(1.0),
+                    }],
+                    asks: vec![CoinbaseLevel {
+                        price: num!(91.0),
+                        amount: num!(1.0),
+                    }],
+                },
+                expected: Some((
+                    vec![Level::new(num!(90.0), num!(1.0))],
+                    vec![Level::new(num!(91.0), num!(1.0))],
+                )),
+            },
+            TestCase {
+                // TC1: update received before the initial snapshot is ignored
+                updater: CoinbaseBookUpdater::default(),
+                input: CoinbaseOrderBookL2::Update {
+                    subscription_id: subscription_id.clone(),
+                    time: Utc::now(),
+                    changes: vec![change(Side::Buy, num!(100.5), num!(1.0))],
+                },
+                expected: None,
+            },
+            TestCase {
+                // TC2: update changes are applied to the associated side
+                updater: CoinbaseBookUpdater {
+                    snapshot_received: true,
+                },
+                input: CoinbaseOrderBookL2::Update {
+                    subscription_id: subscription_id.clone(),
+                    time: Utc::now(),
+                    changes: vec![
+                        change(Side::Buy, num!(100.0), num!(0.0)),
+                        change(Side::Buy, num!(99.5), num!(2.0)),
+                        change(Side::Sell, num!(101.0), num!(3.0)),
+                    ],
+                },
+                expected: Some((
+                    vec![
+                        Level::new(num!(99.5), num!(2.0)),
+                        Level::new(num!(99.0), num!(1.0)),
+                    ],
+                    vec![Level::new(num!(101.0), num!(3.0))],
+                )),
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(
+                    Side::Buy,
+                    vec![
+                        Level::new(num!(100.0), num!(1.0)),
+                        Level::new(num!(99.0), num!(1.0)),
+                    ],
+                ),
+                asks: OrderBookSide::new(Side::Sell, vec![Level::new(num!(101.0), num!(1.0))]),
+            };
+
+            let actual = test
+                .updater
+                .update(&mut book, test.input)
+                .unwrap()
+                .map(|book| (book.bids, book.asks));
+            let expected = test.expected.map(|(bids, asks)| {
+                (
+                    OrderBookSide::new(Side::Buy, bids),
+                    OrderBookSide::new(Side::Sell, asks),
+                )
+            });
+
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,719 @@
+use crate::{
+    error::DataError,
+    exchange::{coinbase::channel::CoinbaseChannel, ExchangeSub},
+    number::{Number, ZERO},
+    subscription::book::{Order, OrderBookL3, OrderBookL3Delta},
+    transformer::book::{
+        InstrumentOrderBook, OrderBookL3Change, OrderBookL3DeltaUpdater, OrderBookUpdater,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Coinbase`](super::super::Coinbase) HTTP OrderBook L3 snapshot url.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/reference/exchangerestapi_getproductbook>
+pub const HTTP_BOOK_L3_SNAPSHOT_URL_COINBASE: &str = "https://api.exchange.coinbase.com/products";
+
+/// [`Coinbase`](super::super::Coinbase) HTTP OrderBook L3 snapshot.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/reference/exchangerestapi_getproductbook>
+/// ```json
+/// {
+///     "sequence": 3,
+///     "bids": [["295.96", "0.05088265", "3b0f1225-7f84-490b-a29f-0faef9de823a"]],
+///     "asks": [["295.97", "5.72036512", "da863862-25f4-4868-ac41-005d11ab0a5f"]],
+///     "auction_mode": false,
+///     "time": "2023-06-04T10:24:55.416512Z"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderBookL3Snapshot {
+    pub sequence: u64,
+    pub bids: Vec<CoinbaseOrderL3>,
+    pub asks: Vec<CoinbaseOrderL3>,
+    #[serde(default = "Utc::now")]
+    pub time: DateTime<Utc>,
+}
+
+impl From<CoinbaseOrderBookL3Snapshot> for OrderBookL3 {
+    fn from(snapshot: CoinbaseOrderBookL3Snapshot) -> Self {
+        let time = snapshot.time;
+        let orders = |side: Side, orders: Vec<CoinbaseOrderL3>| {
+            orders.into_iter().map(move |order| Order {
+                id: order.order_id,
+                side,
+                price: order.price,
+                amount: order.amount,
+                time,
+            })
+        };
+
+        Self::new(
+            time,
+            orders(Side::Buy, snapshot.bids).chain(orders(Side::Sell, snapshot.asks)),
+        )
+    }
+}
+
+/// [`Coinbase`](super::super::Coinbase) HTTP OrderBook L3 snapshot order.
+///
+/// #### Raw Payload Examples
+/// ```json
+/// ["295.96", "0.05088265", "3b0f1225-7f84-490b-a29f-0faef9de823a"]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderL3 {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+    pub order_id: String,
+}
+
+/// [`Coinbase`](super::super::Coinbase) real-time OrderBook Level3 WebSocket message from the
+/// "full" channel.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#full-channel>
+/// #### Open
+/// ```json
+/// {
+///     "type": "open",
+///     "time": "2014-11-07T08:19:27.028459Z",
+///     "product_id": "BTC-USD",
+///     "sequence": 10,
+///     "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+///     "price": "200.2",
+///     "remaining_size": "1.00",
+///     "side": "sell"
+/// }
+/// ```
+///
+/// #### Match
+/// ```json
+/// {
+///     "type": "match",
+///     "trade_id": 10,
+///     "sequence": 50,
+///     "maker_order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+///     "taker_order_id": "132fb6ae-456b-4654-b4e0-d681ac05cea1",
+///     "time": "2014-11-07T08:19:27.028459Z",
+///     "product_id": "BTC-USD",
+///     "size": "5.23512",
+///     "price": "400.23",
+///     "side": "sell"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderBookL3 {
+    #[serde(rename = "product_id", deserialize_with = "de_ob_l3_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub sequence: u64,
+    pub time: DateTime<Utc>,
+    #[serde(flatten)]
+    pub kind: CoinbaseOrderBookL3Kind,
+}
+
+/// [`Coinbase`](super::super::Coinbase) "full" channel message variants.
+///
+/// ### Notes
+/// - "received" & "activate" messages refer to orders that are not (yet) resting on the
+///   OrderBook, so they only progress the sequence.
+/// - "change" messages contain a `new_price` if the order was modified rather than resized.
+///
+/// See [`CoinbaseOrderBookL3`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoinbaseOrderBookL3Kind {
+    Received,
+    Open {
+        order_id: String,
+        side: Side,
+        #[serde(deserialize_with = "barter_integration::de::de_str")]
+        price: Number,
+        #[serde(deserialize_with = "barter_integration::de::de_str")]
+        remaining_size: Number,
+    },
+    Done {
+        order_id: String,
+    },
+    Match {
+        maker_order_id: String,
+        #[serde(deserialize_with = "barter_integration::de::de_str")]
+        size: Number,
+    },
+    Change {
+        order_id: String,
+        #[serde(deserialize_with = "barter_integration::de::de_str")]
+        new_size: Number,
+        #[serde(default, deserialize_with = "de_option_str")]
+        new_price: Option<Number>,
+    },
+    Activate,
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL3 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// Deserialize a [`CoinbaseOrderBookL3`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("full|BTC-USD").
+pub fn de_ob_l3_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L3, product_id)).id())
+}
+
+/// Deserialize an optional `String` as an optional [`Number`].
+fn de_option_str<'de, D>(deserializer: D) -> Result<Option<Number>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| value.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// [`Coinbase`](super::super::Coinbase) [`OrderBookL3DeltaUpdater`] that maintains an
+/// [`OrderBookL3`] from the "full" channel, reporting each changed [`Order`].
+///
+/// ### Notes
+/// - The [`OrderBookL3`] is initialised via a HTTP snapshot, and any buffered WebSocket message
+///   with a sequence <= the snapshot sequence is ignored.
+/// - Every subsequent message must have a sequence exactly one greater than the previous,
+///   otherwise a terminal [`DataError::InvalidSequence`] is returned.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#full-channel>
+#[derive(
+    Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct CoinbaseL3BookUpdater {
+    pub sequence: u64,
+}
+
+#[async_trait]
+impl OrderBookUpdater for CoinbaseL3BookUpdater {
+    type OrderBook = OrderBookL3;
+    type Update = CoinbaseOrderBookL3;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        _: Option<usize>,
+    ) -> Result<InstrumentOrderBook<Self, OrderBookL3>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Construct initial OrderBook snapshot GET url using the CoinbaseMarket format
+        // eg/ https://api.exchange.coinbase.com/products/BTC-USD/book?level=3
+        let snapshot_url = format!(
+            "{}/{}-{}/book?level=3",
+            HTTP_BOOK_L3_SNAPSHOT_URL_COINBASE,
+            instrument.base.as_ref().to_uppercase(),
+            instrument.quote.as_ref().to_uppercase(),
+        );
+
+        // Fetch initial OrderBook snapshot via HTTP (Coinbase rejects requests w/o a User-Agent)
+        let snapshot = reqwest::Client::new()
+            .get(snapshot_url)
+            .header(reqwest::header::USER_AGENT, "barter-data")
+            .send()
+            .await
+            .map_err(SocketError::Http)?
+            .json::<CoinbaseOrderBookL3Snapshot>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self {
+                sequence: snapshot.sequence,
+            },
+            book: OrderBookL3::from(snapshot),
+        })
+    }
+}
+
+impl OrderBookL3DeltaUpdater for CoinbaseL3BookUpdater {
+    fn update_delta(
+        &mut self,
+        book: &mut OrderBookL3,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookL3Change>, DataError> {
+        // Drop any buffered message already reflected in the snapshot
+        if update.sequence <= self.sequence {
+            return Ok(None);
+        }
+
+        if update.sequence != self.sequence + 1 {
+            return Err(DataError::InvalidSequence {
+                prev_last_update_id: self.sequence,
+                first_update_id: update.sequence,
+            });
+        }
+        self.sequence = update.sequence;
+
+        let order = match update.kind {
+            CoinbaseOrderBookL3Kind::Received | CoinbaseOrderBookL3Kind::Activate => {
+                return Ok(None)
+            }
+            CoinbaseOrderBookL3Kind::Open {
+                order_id,
+                side,
+                price,
+                remaining_size,
+            } => {
+                let order = Order {
+                    id: order_id,
+                    side,
+                    price,
+                    amount: remaining_size,
+                    time: update.time,
+                };
+                book.upsert(order.clone());
+                order
+            }
+            CoinbaseOrderBookL3Kind::Done { order_id } => match book.remove(&order_id) {
+                Some(order) => Order {
+                    amount: ZERO,
+                    ..order
+                },
+                // Orders that never rested on the OrderBook (eg/ market orders) do not change it
+                None => return Ok(None),
+            },
+            CoinbaseOrderBookL3Kind::Match {
+                maker_order_id,
+                size,
+            } => match book.reduce(&maker_order_id, size) {
+                Some(order) => order,
+                None => return Ok(None),
+            },
+            CoinbaseOrderBookL3Kind::Change {
+                order_id,
+                new_size,
+                new_price,
+            } => match book.orders.get_mut(&order_id) {
+                Some(order) => {
+                    order.amount = new_size;
+                    if let Some(new_price) = new_price {
+                        order.price = new_price;
+                    }
+                    order.clone()
+                }
+                None => return Ok(None),
+            },
+        };
+
+        book.last_update_time = update.time;
+        Ok(Some(OrderBookL3Change::Delta(OrderBookL3Delta {
+            last_update_time: update.time,
+            sequence: Some(update.sequence),
+            orders: vec![order],
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_coinbase_order_book_l3() {
+            struct TestCase {
+                input: &'static str,
+                expected: CoinbaseOrderBookL3,
+            }
+
+            let time = "2014-11-07T08:19:27.028459Z"
+                .parse::<DateTime<Utc>>()
+                .unwrap();
+            let subscription_id = SubscriptionId::from("full|BTC-USD");
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input CoinbaseOrderBookL3Kind::Open is deserialised
+                    input: r#"
+                    {
+                        "type": "open",
+                        "time": "2014-11-07T08:19:27.028459Z",
+                        "product_id": "BTC-USD",
+                        "sequence": 10,
+                        "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+                        "price": "200.2",
+                        "remaining_size": "1.00",
+                        "side": "sell"
+                    }
+                    "#,
+                    expected: CoinbaseOrderBookL3 {
+                        subscription_id: subscription_id.clone(),
+                        sequence: 10,
+                        time,
+                        kind: CoinbaseOrderBookL3Kind::Open {
+                            order_id: "d50ec984-77a8-460a-b958-66f114b0de9b".to_string(),
+                            side: Side::Sell,
+                            price: num!(200.2),
+                            remaining_size: num!(1.0),
+                        },
+                    },
+                },
+                TestCase {
+                    // TC1: input CoinbaseOrderBookL3Kind::Match is deserialised
+                    input: r#"
+                    {
+                        "type": "match",
+                        "trade_id": 10,
+                        "sequence": 50,
+                        "maker_order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+                        "taker_order_id": "132fb6ae-456b-4654-b4e0-d681ac05cea1",
+                        "time": "2014-11-07T08:19:27.028459Z",
+                        "product_id": "BTC-USD",
+                        "size": "5.23512",
+                        "price": "400.23",
+                        "side": "sell"
+                    }
+                    "#,
+                    expected: CoinbaseOrderBookL3 {
+                        subscription_id: subscription_id.clone(),
+                        sequence: 50,
+                        time,
+                        kind: CoinbaseOrderBookL3Kind::Match {
+                            maker_order_id: "ac928c66-ca53-498f-9c13-a110027a60e8".to_string(),
+                            size: num!(5.23512),
+                        },
+                    },
+                },
+                TestCase {
+                    // TC2: input CoinbaseOrderBookL3Kind::Change w/o new_price is deserialised
+                    input: r#"
+                    {
+                        "type": "change",
+                        "time": "2014-11-07T08:19:27.028459Z",
+                        "sequence": 80,
+                        "order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+                        "product_id": "BTC-USD",
+                        "new_size": "5.23512",
+                        "old_size": "12.234412",
+                        "price": "400.23",
+                        "side": "sell"
+                    }
+                    "#,
+                    expected: CoinbaseOrderBookL3 {
+                        subscription_id: subscription_id.clone(),
+                        sequence: 80,
+                        time,
+                        kind: CoinbaseOrderBookL3Kind::Change {
+                            order_id: "ac928c66-ca53-498f-9c13-a110027a60e8".to_string(),
+                            new_size: num!(5.23512),
+                            new_price: None,
+                        },
+                    },
+                },
+                TestCase {
+                    // TC3: input CoinbaseOrderBookL3Kind::Received is deserialised
+                    input: r#"
+                    {
+                        "type": "received",
+                        "time": "2014-11-07T08:19:27.028459Z",
+                        "product_id": "BTC-USD",
+                        "sequence": 9,
+                        "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+                        "size": "1.34",
+                        "price": "502.1",
+                        "side": "buy",
+                        "order_type": "limit"
+                    }
+                    "#,
+                    expected: CoinbaseOrderBookL3 {
+                        subscription_id,
+                        sequence: 9,
+                        time,
+                        kind: CoinbaseOrderBookL3Kind::Received,
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<CoinbaseOrderBookL3>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_coinbase_order_book_l3_snapshot() {
+            let input = r#"
+            {
+                "sequence": 3,
+                "bids": [["295.96", "0.05088265", "3b0f1225-7f84-490b-a29f-0faef9de823a"]],
+                "asks": [["295.97", "5.72036512", "da863862-25f4-4868-ac41-005d11ab0a5f"]],
+                "auction_mode": false,
+                "time": "2023-06-04T10:24:55.416512Z"
+            }
+            "#;
+
+            let actual = serde_json::from_str::<CoinbaseOrderBookL3Snapshot>(input).unwrap();
+            assert_eq!(
+                actual,
+                CoinbaseOrderBookL3Snapshot {
+                    sequence: 3,
+                    bids: vec![CoinbaseOrderL3 {
+                        price: num!(295.96),
+                        amount: num!(0.05088265),
+                        order_id: "3b0f1225-7f84-490b-a29f-0faef9de823a".to_string(),
+                    }],
+                    asks: vec![CoinbaseOrderL3 {
+                        price: num!(295.97),
+                        amount: num!(5.72036512),
+                        order_id: "da863862-25f4-4868-ac41-005d11ab0a5f".to_string(),
+                    }],
+                    time: "2023-06-04T10:24:55.416512Z".parse().unwrap(),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_coinbase_l3_book_updater_update() {
+        struct TestCase {
+            updater: CoinbaseL3BookUpdater,
+            input: CoinbaseOrderBookL3Kind,
+            sequence: u64,
+            expected: Result<Option<Vec<(&'static str, Number)>>, ()>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: message already reflected in the snapshot is ignored
+                updater: CoinbaseL3BookUpdater { sequence: 10 },
+                input: CoinbaseOrderBookL3Kind::Done {
+                    order_id: "a".to_string(),
+                },
+                sequence: 10,
+                expected: Ok(None),
+            },
+            TestCase {
+                // TC1: sequence gap is a terminal error
+                updater: CoinbaseL3BookUpdater { sequence: 10 },
+                input: CoinbaseOrderBookL3Kind::Received,
+                sequence: 12,
+                expected: Err(()),
+            },
+            TestCase {
+                // TC2: open inserts a new Order
+                updater: CoinbaseL3BookUpdater { sequence: 10 },
+                input: CoinbaseOrderBookL3Kind::Open {
+                    order_id: "c".to_string(),
+                    side: Side::Buy,
+                    price: num!(99.0),
+                    remaining_size: num!(3.0),
+                },
+                sequence: 11,
+                expected: Ok(Some(vec![
+                    ("a", num!(1.0)),
+                    ("b", num!(2.0)),
+                    ("c", num!(3.0)),
+                ])),
+            },
+            TestCase {
+                // TC3: match reduces the maker Order
+                updater: CoinbaseL3BookUpdater { sequence: 10 },
+                input: CoinbaseOrderBookL3Kind::Match {
+                    maker_order_id: "b".to_string(),
+                    size: num!(0.5),
+                },
+                sequence: 11,
+                expected: Ok(Some(vec![("a", num!(1.0)), ("b", num!(1.5))])),
+            },
+            TestCase {
+                // TC4: done removes the Order
+                updater: CoinbaseL3BookUpdater { sequence: 10 },
+                input: CoinbaseOrderBookL3Kind::Done {
+                    order_id: "a".to_string(),
+                },
+                sequence: 11,
+                expected: Ok(Some(vec![("b", num!(2.0))])),
+            },
+            TestCase {
+                // TC5: done for an Order not on the OrderBook is ignored
+                updater: CoinbaseL3BookUpdater { sequence: 10 },
+                input: CoinbaseOrderBookL3Kind::Done {
+                    order_id: "market".to_string(),
+                },
+                sequence: 11,
+                expected: Ok(None),
+            },
+            TestCase {
+                // TC6: change resizes the Order
+                updater: CoinbaseL3BookUpdater { sequence: 10 },
+                input: CoinbaseOrderBookL3Kind::Change {
+                    order_id: "a".to_string(),
+                    new_size: num!(0.25),
+                    new_price: None,
+                },
+                sequence: 11,
+                expected: Ok(Some(vec![("a", num!(0.25)), ("b", num!(2.0))])),
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            let mut book = OrderBookL3::new(
+                Utc::now(),
+                vec![
+                    Order {
+                        id: "a".to_string(),
+                        side: Side::Buy,
+                        price: num!(100.0),
+                        amount: num!(1.0),
+                        time: Utc::now(),
+                    },
+                    Order {
+                        id: "b".to_string(),
+                        side: Side::Sell,
+                        price: num!(101.0),
+                        amount: num!(2.0),
+                        time: Utc::now(),
+                    },
+                ],
+            );
+
+            let update = CoinbaseOrderBookL3 {
+                subscription_id: SubscriptionId::from("full|BTC-USD"),
+                sequence: test.sequence,
+                time: Utc::now(),
+                kind: test.input,
+            };
+
+            let actual = test
+                .updater
+                .update(&mut book, update)
+                .map(|snapshot| {
+                    snapshot.map(|snapshot| {
+                        let mut orders = snapshot
+                            .orders
+                            .values()
+                            .map(|order| (order.id.clone(), order.amount))
+                            .collect::<Vec<_>>();
+                        orders.sort_by(|a, b| a.0.cmp(&b.0));
+                        orders
+                    })
+                })
+                .map_err(|_| ());
+            let expected = test.expected.map(|orders| {
+                orders.map(|orders| {
+                    orders
+                        .into_iter()
+                        .map(|(id, amount)| (id.to_string(), amount))
+                        .collect::<Vec<_>>()
+                })
+            });
+
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_coinbase_l3_book_updater_update_delta() {
+        struct TestCase {
+            input: CoinbaseOrderBookL3Kind,
+            expected: Option<Vec<Order>>,
+        }
+
+        let time = Utc::now();
+        let order = |id: &str, side: Side, price: Number, amount: Number| Order {
+            id: id.to_string(),
+            side,
+            price,
+            amount,
+            time,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: received does not change the OrderBookL3
+                input: CoinbaseOrderBookL3Kind::Received,
+                expected: None,
+            },
+            TestCase {
+                // TC1: open yields the new Order
+                input: CoinbaseOrderBookL3Kind::Open {
+                    order_id: "c".to_string(),
+                    side: Side::Buy,
+                    price: num!(99.0),
+                    remaining_size: num!(3.0),
+                },
+                expected: Some(vec![order("c", Side::Buy, num!(99.0), num!(3.0))]),
+            },
+            TestCase {
+                // TC2: partial match yields the reduced maker Order
+                input: CoinbaseOrderBookL3Kind::Match {
+                    maker_order_id: "b".to_string(),
+                    size: num!(0.5),
+                },
+                expected: Some(vec![order("b", Side::Sell, num!(101.0), num!(1.5))]),
+            },
+            TestCase {
+                // TC3: done yields the removed Order w/ a zero amount
+                input: CoinbaseOrderBookL3Kind::Done {
+                    order_id: "a".to_string(),
+                },
+                expected: Some(vec![order("a", Side::Buy, num!(100.0), num!(0.0))]),
+            },
+            TestCase {
+                // TC4: change yields the modified Order
+                input: CoinbaseOrderBookL3Kind::Change {
+                    order_id: "b".to_string(),
+                    new_size: num!(1.0),
+                    new_price: Some(num!(102.0)),
+                },
+                expected: Some(vec![order("b", Side::Sell, num!(102.0), num!(1.0))]),
+            },
+        ];
+
+        let mut updater = CoinbaseL3BookUpdater { sequence: 10 };
+        let mut book = OrderBookL3::new(
+            time,
+            vec![
+                order("a", Side::Buy, num!(100.0), num!(1.0)),
+                order("b", Side::Sell, num!(101.0), num!(2.0)),
+            ],
+        );
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let update = CoinbaseOrderBookL3 {
+                subscription_id: SubscriptionId::from("full|BTC-USD"),
+                sequence: updater.sequence + 1,
+                time,
+                kind: test.input,
+            };
+
+            let actual =
+                updater
+                    .update_delta(&mut book, update)
+                    .unwrap()
+                    .map(|change| match change {
+                        OrderBookL3Change::Delta(delta) => delta.orders,
+                        OrderBookL3Change::Snapshot => panic!("TC{index} failed"),
+                    });
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,8 @@
+/// Level 1 OrderBook types (top of book).
+pub mod l1;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// Level 3 OrderBook types.
+pub mod l3;
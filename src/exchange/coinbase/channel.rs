@@ -1,6 +1,12 @@
 use super::Coinbase;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta, OrderBooksL3},
+        candle::Candles,
+        ticker::Tickers,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +23,26 @@ impl CoinbaseChannel {
     ///
     /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#match>
     pub const TRADES: Self = Self("matches");
+
+    /// [`Coinbase`] real-time ticker channel, used for OrderBook Level1 (top of book).
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#ticker-channel>
+    pub const ORDER_BOOK_L1: Self = Self("ticker");
+
+    /// [`Coinbase`] real-time OrderBook Level2 channel (batched every 50 milliseconds).
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+    pub const ORDER_BOOK_L2: Self = Self("level2_batch");
+
+    /// [`Coinbase`] real-time OrderBook Level3 channel.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#full-channel>
+    pub const ORDER_BOOK_L3: Self = Self("full");
+
+    /// [`Coinbase`] real-time ticker channel, used for rolling 24h [`Tickers`].
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#ticker-channel>
+    pub const TICKERS: Self = Self("ticker");
 }
 
 impl Identifier<CoinbaseChannel> for Subscription<Coinbase, PublicTrades> {
@@ -25,6 +51,44 @@ impl Identifier<CoinbaseChannel> for Subscription<Coinbase, PublicTrades> {
     }
 }
 
+/// [`Candles`] are aggregated from the trades channel since Coinbase does not offer native
+/// candle streams (see [`TradeCandleTransformer`](crate::transformer::candle::TradeCandleTransformer)).
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, Candles> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::TRADES
+    }
+}
+
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, OrderBooksL1> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::ORDER_BOOK_L1
+    }
+}
+
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, OrderBooksL2> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, OrderBooksL2Delta> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, OrderBooksL3> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::ORDER_BOOK_L3
+    }
+}
+
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, Tickers> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::TICKERS
+    }
+}
+
 impl AsRef<str> for CoinbaseChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,19 +1,35 @@
 use self::{
-    channel::CoinbaseChannel, market::CoinbaseMarket, subscription::CoinbaseSubResponse,
+    book::{l1::CoinbaseOrderBookL1, l2::CoinbaseBookUpdater, l3::CoinbaseL3BookUpdater},
+    channel::CoinbaseChannel,
+    market::CoinbaseMarket,
+    subscription::CoinbaseSubResponse,
+    ticker::CoinbaseTicker,
     trade::CoinbaseTrade,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta, OrderBooksL3},
+        candle::Candles,
+        ticker::Tickers,
+        trade::PublicTrades,
+    },
+    transformer::{
+        book::{MultiBookDeltaTransformer, MultiBookL3Transformer, MultiBookTransformer},
+        candle::TradeCandleTransformer,
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, TimedExchangeWsStream,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
 
+/// Order book types for [`Coinbase`].
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -26,6 +42,9 @@ pub mod market;
 /// [`Validator`](barter_integration::Validator) for [`Coinbase`].
 pub mod subscription;
 
+/// Rolling 24h ticker types for [`Coinbase`].
+pub mod ticker;
+
 /// Public trade types for [`Coinbase`].
 pub mod trade;
 
@@ -74,3 +93,33 @@ impl Connector for Coinbase {
 impl StreamSelector<PublicTrades> for Coinbase {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, CoinbaseTrade>>;
 }
+
+impl StreamSelector<Candles> for Coinbase {
+    type Stream = TimedExchangeWsStream<TradeCandleTransformer<Self, CoinbaseTrade>>;
+}
+
+impl StreamSelector<OrderBooksL1> for Coinbase {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, CoinbaseOrderBookL1>>;
+}
+
+impl StreamSelector<Tickers> for Coinbase {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Tickers, CoinbaseTicker>>;
+}
+
+impl StreamSelector<OrderBooksL2> for Coinbase {
+    type Stream =
+        TimedExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, CoinbaseBookUpdater>>;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for Coinbase {
+    type Stream =
+        ExchangeWsStream<MultiBookDeltaTransformer<Self, OrderBooksL2Delta, CoinbaseBookUpdater>>;
+}
+
+impl StreamSelector<OrderBooksL3> for Coinbase {
+    type Stream = ExchangeWsStream<MultiBookL3Transformer<Self, CoinbaseL3BookUpdater>>;
+}
@@ -0,0 +1,148 @@
+use super::channel::CoinbaseChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    number::Number,
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Coinbase`](super::Coinbase) real-time ticker WebSocket message, used to generate rolling
+/// 24h [`Ticker`]s.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#ticker-channel>
+/// ```json
+/// {
+///     "type": "ticker",
+///     "sequence": 37475248783,
+///     "product_id": "ETH-USD",
+///     "price": "1285.22",
+///     "open_24h": "1310.79",
+///     "volume_24h": "245532.79269678",
+///     "low_24h": "1280.52",
+///     "high_24h": "1313.8",
+///     "volume_30d": "9788783.60117027",
+///     "best_bid": "1285.04",
+///     "best_bid_size": "0.46688654",
+///     "best_ask": "1285.27",
+///     "best_ask_size": "1.56637040",
+///     "side": "buy",
+///     "time": "2022-10-19T23:28:22.061769Z",
+///     "trade_id": 370843401,
+///     "last_size": "11.4396987"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseTicker {
+    #[serde(alias = "product_id", deserialize_with = "de_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub time: DateTime<Utc>,
+    #[serde(alias = "price", deserialize_with = "barter_integration::de::de_str")]
+    pub last: Number,
+    #[serde(
+        alias = "open_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub open: Number,
+    #[serde(
+        alias = "high_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub high: Number,
+    #[serde(alias = "low_24h", deserialize_with = "barter_integration::de::de_str")]
+    pub low: Number,
+    #[serde(
+        alias = "volume_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume: Number,
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, CoinbaseTicker)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, CoinbaseTicker)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Ticker {
+                last: ticker.last,
+                open: Some(ticker.open),
+                high: ticker.high,
+                low: ticker.low,
+                volume_base: ticker.volume,
+                volume_quote: None,
+                change_percent: Ticker::change_percent(ticker.open, ticker.last),
+                time: ticker.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`CoinbaseTicker`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("ticker|BTC-USD").
+pub fn de_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::TICKERS, product_id)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_coinbase_ticker() {
+            let input = r#"
+            {
+                "type": "ticker",
+                "sequence": 37475248783,
+                "product_id": "ETH-USD",
+                "price": "1285.22",
+                "open_24h": "1310.79",
+                "volume_24h": "245532.79269678",
+                "low_24h": "1280.52",
+                "high_24h": "1313.8",
+                "volume_30d": "9788783.60117027",
+                "best_bid": "1285.04",
+                "best_bid_size": "0.46688654",
+                "best_ask": "1285.27",
+                "best_ask_size": "1.56637040",
+                "side": "buy",
+                "time": "2022-10-19T23:28:22.061769Z",
+                "trade_id": 370843401,
+                "last_size": "11.4396987"
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<CoinbaseTicker>(input).unwrap(),
+                CoinbaseTicker {
+                    subscription_id: SubscriptionId::from("ticker|ETH-USD"),
+                    time: "2022-10-19T23:28:22.061769Z".parse().unwrap(),
+                    last: num!(1285.22),
+                    open: num!(1310.79),
+                    high: num!(1313.8),
+                    low: num!(1280.52),
+                    volume: num!(245532.79269678),
+                }
+            );
+        }
+    }
+}
@@ -2,6 +2,7 @@ use super::CoinbaseChannel;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
+    number::Number,
     subscription::trade::PublicTrade,
     Identifier,
 };
@@ -36,9 +37,9 @@ pub struct CoinbaseTrade {
     pub id: u64,
     pub time: DateTime<Utc>,
     #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Number,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
     pub side: Side,
 }
 
@@ -78,6 +79,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::number::num;
     use barter_integration::error::SocketError;
     use chrono::NaiveDateTime;
     use serde::de::Error;
@@ -112,8 +114,8 @@ mod tests {
                 expected: Ok(CoinbaseTrade {
                     subscription_id: SubscriptionId::from("matches|BTC-USD"),
                     id: 10,
-                    price: 400.23,
-                    amount: 5.23512,
+                    price: num!(400.23),
+                    amount: num!(5.23512),
                     side: Side::Sell,
                     time: DateTime::from_utc(
                         NaiveDateTime::from_str("2014-11-07T08:19:27.028459").unwrap(),
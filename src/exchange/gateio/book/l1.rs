@@ -0,0 +1,187 @@
+use super::{super::message::GateioMessage, de_str_or_number};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    number::Number,
+    subscription::book::{Level, OrderBookL1},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Gateio`](super::super::Gateio) real-time best bid & ask WebSocket
+/// message, used to generate OrderBook Level1 (top of book) snapshots.
+pub type GateioOrderBookL1 = GateioMessage<GateioBookTicker>;
+
+/// [`Gateio`](super::super::Gateio) best bid & ask "book_ticker" update, common to every
+/// [`ExchangeServer`](crate::exchange::ExchangeServer).
+///
+/// ### Raw Payload Examples
+/// #### Spot
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+/// ```json
+/// {
+///     "t": 1606293275123,
+///     "u": 48733182,
+///     "s": "BTC_USDT",
+///     "b": "19177.79",
+///     "B": "0.0003341504",
+///     "a": "19179.38",
+///     "A": "0.09"
+/// }
+/// ```
+///
+/// #### Futures, Perpetuals & Options
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#best-ask-bid-subscription>
+/// ```json
+/// {
+///     "t": 1615366379123,
+///     "u": 2517661076,
+///     "s": "BTC_USD",
+///     "b": "54696.6",
+///     "B": 37000,
+///     "a": "54696.7",
+///     "A": 47061
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioBookTicker {
+    #[serde(
+        rename = "t",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(rename = "b", deserialize_with = "de_str_or_number")]
+    pub best_bid_price: Number,
+    #[serde(rename = "B", deserialize_with = "de_str_or_number")]
+    pub best_bid_amount: Number,
+    #[serde(rename = "a", deserialize_with = "de_str_or_number")]
+    pub best_ask_price: Number,
+    #[serde(rename = "A", deserialize_with = "de_str_or_number")]
+    pub best_ask_amount: Number,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioOrderBookL1 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+impl From<(ExchangeId, Instrument, GateioOrderBookL1)> for MarketIter<OrderBookL1> {
+    fn from((exchange_id, instrument, book): (ExchangeId, Instrument, GateioOrderBookL1)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: book.data.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: book.data.time,
+                best_bid: Level::new(book.data.best_bid_price, book.data.best_bid_amount),
+                best_ask: Level::new(book.data.best_ask_price, book.data.best_ask_amount),
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: GateioOrderBookL1,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: spot book ticker w/ string amounts
+                    input: r#"
+                    {
+                        "time": 1606293275,
+                        "time_ms": 1606293275723,
+                        "channel": "spot.book_ticker",
+                        "event": "update",
+                        "result": {
+                            "t": 1606293275123,
+                            "u": 48733182,
+                            "s": "BTC_USDT",
+                            "b": "19177.79",
+                            "B": "0.0003341504",
+                            "a": "19179.38",
+                            "A": "0.09"
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL1 {
+                        channel: "spot.book_ticker".to_string(),
+                        error: None,
+                        data: GateioBookTicker {
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1606293275123,
+                            )),
+                            update_id: 48733182,
+                            market: "BTC_USDT".to_string(),
+                            best_bid_price: num!(19177.79),
+                            best_bid_amount: num!(0.0003341504),
+                            best_ask_price: num!(19179.38),
+                            best_ask_amount: num!(0.09),
+                        },
+                    },
+                },
+                TestCase {
+                    // TC1: futures book ticker w/ numeric amounts
+                    input: r#"
+                    {
+                        "time": 1615366379,
+                        "time_ms": 1615366379123,
+                        "channel": "futures.book_ticker",
+                        "event": "update",
+                        "result": {
+                            "t": 1615366379123,
+                            "u": 2517661076,
+                            "s": "BTC_USD",
+                            "b": "54696.6",
+                            "B": 37000,
+                            "a": "54696.7",
+                            "A": 47061
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL1 {
+                        channel: "futures.book_ticker".to_string(),
+                        error: None,
+                        data: GateioBookTicker {
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1615366379123,
+                            )),
+                            update_id: 2517661076,
+                            market: "BTC_USD".to_string(),
+                            best_bid_price: num!(54696.6),
+                            best_bid_amount: num!(37000.0),
+                            best_ask_price: num!(54696.7),
+                            best_ask_amount: num!(47061.0),
+                        },
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioOrderBookL1>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
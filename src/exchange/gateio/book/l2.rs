@@ -0,0 +1,491 @@
+use super::{super::message::GateioMessage, GateioLevel};
+use crate::{
+    error::DataError,
+    exchange::{gateio::market::GateioMarket, ExchangeServer, ExchangeSub},
+    subscription::book::{OrderBook, OrderBookDelta, OrderBookSide},
+    transformer::book::{
+        InstrumentOrderBook, OrderBookChange, OrderBookDeltaUpdater, OrderBookUpdater,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use tokio::sync::mpsc;
+
+/// [`Gateio`](super::super::Gateio) OrderBook Level2 depth used for both the WebSocket
+/// "order_book_update" subscription level and the HTTP snapshot limit.
+pub const GATEIO_ORDER_BOOK_L2_DEPTH: u32 = 50;
+
+/// [`Gateio`](super::super::Gateio) OrderBook Level2 WebSocket "order_book_update" interval.
+pub const GATEIO_ORDER_BOOK_L2_INTERVAL: &str = "100ms";
+
+/// [`Gateio`](super::super::Gateio) [`ExchangeServer`] that publishes OrderBook Level2 depth,
+/// defining where the HTTP OrderBook snapshot used to bootstrap a [`GateioBookUpdater`] is
+/// fetched from.
+pub trait GateioBookServer: ExchangeServer {
+    /// Construct the HTTP OrderBook Level2 snapshot url for the provided [`GateioMarket`].
+    fn http_book_l2_snapshot_url(market: &GateioMarket) -> String;
+}
+
+/// Terse type alias for a [`Gateio`](super::super::Gateio) real-time OrderBook Level2 deltas
+/// WebSocket message.
+pub type GateioOrderBookL2 = GateioMessage<GateioOrderBookL2Delta>;
+
+/// [`Gateio`](super::super::Gateio) OrderBook Level2 deltas "order_book_update", common to
+/// every [`ExchangeServer`].
+///
+/// ### Raw Payload Examples
+/// #### Spot
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+/// ```json
+/// {
+///     "t": 1606294781123,
+///     "e": "depthUpdate",
+///     "E": 1606294781,
+///     "s": "BTC_USDT",
+///     "U": 48776301,
+///     "u": 48776306,
+///     "b": [["19137.74", "0.0001"]],
+///     "a": [["19137.75", "0.6135"]]
+/// }
+/// ```
+///
+/// #### Futures, Perpetuals & Options
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-notification>
+/// ```json
+/// {
+///     "t": 1615366381417,
+///     "s": "BTC_USD",
+///     "U": 2517661101,
+///     "u": 2517661113,
+///     "b": [{"p": "54672.1", "s": 0}],
+///     "a": [{"p": "54743.6", "s": 0}]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOrderBookL2Delta {
+    #[serde(
+        rename = "t",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub last_update_id: u64,
+    #[serde(rename = "b")]
+    pub bids: Vec<GateioLevel>,
+    #[serde(rename = "a")]
+    pub asks: Vec<GateioLevel>,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+/// [`Gateio`](super::super::Gateio) OrderBook Level2 snapshot HTTP message.
+///
+/// Used as the starting [`OrderBook`] before OrderBook Level2 delta WebSocket updates are
+/// applied.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#retrieve-order-book>
+/// ```json
+/// {
+///     "id": 48776300,
+///     "current": 1606294781112,
+///     "update": 1606294781104,
+///     "asks": [["19137.75", "0.6135"]],
+///     "bids": [["19137.74", "0.0001"]]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOrderBookL2Snapshot {
+    pub id: u64,
+    pub bids: Vec<GateioLevel>,
+    pub asks: Vec<GateioLevel>,
+}
+
+impl From<GateioOrderBookL2Snapshot> for OrderBook {
+    fn from(snapshot: GateioOrderBookL2Snapshot) -> Self {
+        Self {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, snapshot.bids),
+            asks: OrderBookSide::new(Side::Sell, snapshot.asks),
+        }
+    }
+}
+
+/// [`Gateio`](super::super::Gateio) [`OrderBookUpdater`] for every [`GateioBookServer`].
+///
+/// Gateio: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the "order_book_update" channel and cache the received updates.
+/// 2. Fetch an HTTP OrderBook snapshot with `with_id=true`, and record it's `id` as the baseID.
+/// 3. Drop any cached update where u < baseID + 1.
+/// 4. The first processed update should have U <= baseID + 1 AND u >= baseID + 1.
+/// 5. Each subsequent update's U should be equal to the previous update's u + 1, otherwise
+///    initialize the process from step 2.
+/// 6. The data in each update is the absolute amount for a price level.
+/// 7. If the amount is 0, remove the price level.
+///
+/// Notes:
+///  - Uppercase U => first_update_id
+///  - Lowercase u => last_update_id
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#how-to-maintain-local-order-book>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GateioBookUpdater<Server> {
+    pub updates_processed: u64,
+    pub last_update_id: u64,
+    pub prev_last_update_id: u64,
+    #[serde(skip)]
+    server: PhantomData<Server>,
+}
+
+impl<Server> GateioBookUpdater<Server> {
+    /// Construct a new Gateio [`OrderBookUpdater`] using the provided last_update_id from
+    /// a HTTP snapshot.
+    pub fn new(last_update_id: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            last_update_id,
+            prev_last_update_id: last_update_id,
+            server: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<Server> OrderBookUpdater for GateioBookUpdater<Server>
+where
+    Server: GateioBookServer + Send,
+{
+    type OrderBook = OrderBook;
+    type Update = GateioOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        _: Option<usize>,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Construct initial OrderBook snapshot GET url
+        let snapshot_url = Server::http_book_l2_snapshot_url(&GateioMarket::from(&instrument));
+
+        // Fetch initial OrderBook snapshot via HTTP
+        let snapshot = reqwest::get(snapshot_url)
+            .await
+            .map_err(SocketError::Http)?
+            .json::<GateioOrderBookL2Snapshot>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.id),
+            book: OrderBook::from(snapshot),
+        })
+    }
+}
+
+impl<Server> OrderBookDeltaUpdater for GateioBookUpdater<Server>
+where
+    Server: GateioBookServer + Send,
+{
+    fn update_delta(
+        &mut self,
+        book: &mut OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookChange>, DataError> {
+        // Gateio: How To Maintain A Local OrderBook
+        // See Self's Rust Docs for more information on each numbered step
+        let update = update.data;
+
+        // 3. Drop any cached update where u < baseID + 1:
+        if update.last_update_id <= self.last_update_id {
+            return Ok(None);
+        }
+
+        let expected_next_id = self.last_update_id + 1;
+        let is_valid_sequence = if self.updates_processed == 0 {
+            // 4. The first processed update should have U <= baseID + 1 AND u >= baseID + 1:
+            update.first_update_id <= expected_next_id && update.last_update_id >= expected_next_id
+        } else {
+            // 5. Each subsequent update's U should be equal to the previous update's u + 1:
+            update.first_update_id == expected_next_id
+        };
+
+        if !is_valid_sequence {
+            return Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.first_update_id,
+            });
+        }
+
+        // Update OrderBook metadata & Levels:
+        // 6. The data in each update is the absolute amount for a price level.
+        // 7. If the amount is 0, remove the price level.
+        book.last_update_time = update.time;
+        let bids = book.bids.upsert_delta(update.bids);
+        let asks = book.asks.upsert_delta(update.asks);
+
+        // Update OrderBookUpdater metadata
+        self.updates_processed += 1;
+        self.prev_last_update_id = self.last_update_id;
+        self.last_update_id = update.last_update_id;
+
+        Ok(Some(OrderBookChange::Delta(OrderBookDelta {
+            last_update_time: book.last_update_time,
+            prev_sequence: Some(self.prev_last_update_id),
+            sequence: Some(self.last_update_id),
+            bids,
+            asks,
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{num, Number};
+    use crate::{exchange::gateio::spot::GateioServerSpot, subscription::book::Level};
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: GateioOrderBookL2,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: spot order_book_update
+                    input: r#"
+                    {
+                        "time": 1606294781,
+                        "time_ms": 1606294781236,
+                        "channel": "spot.order_book_update",
+                        "event": "update",
+                        "result": {
+                            "t": 1606294781123,
+                            "e": "depthUpdate",
+                            "E": 1606294781,
+                            "s": "BTC_USDT",
+                            "U": 48776301,
+                            "u": 48776306,
+                            "b": [["19137.74", "0.0001"]],
+                            "a": [["19137.75", "0.6135"]]
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL2 {
+                        channel: "spot.order_book_update".to_string(),
+                        error: None,
+                        data: GateioOrderBookL2Delta {
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1606294781123,
+                            )),
+                            market: "BTC_USDT".to_string(),
+                            first_update_id: 48776301,
+                            last_update_id: 48776306,
+                            bids: vec![GateioLevel {
+                                price: num!(19137.74),
+                                amount: num!(0.0001),
+                            }],
+                            asks: vec![GateioLevel {
+                                price: num!(19137.75),
+                                amount: num!(0.6135),
+                            }],
+                        },
+                    },
+                },
+                TestCase {
+                    // TC1: futures order_book_update
+                    input: r#"
+                    {
+                        "time": 1615366381,
+                        "time_ms": 1615366381417,
+                        "channel": "futures.order_book_update",
+                        "event": "update",
+                        "result": {
+                            "t": 1615366381417,
+                            "s": "BTC_USD",
+                            "U": 2517661101,
+                            "u": 2517661113,
+                            "b": [{"p": "54672.1", "s": 0}],
+                            "a": [{"p": "54743.6", "s": 95}]
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL2 {
+                        channel: "futures.order_book_update".to_string(),
+                        error: None,
+                        data: GateioOrderBookL2Delta {
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1615366381417,
+                            )),
+                            market: "BTC_USD".to_string(),
+                            first_update_id: 2517661101,
+                            last_update_id: 2517661113,
+                            bids: vec![GateioLevel {
+                                price: num!(54672.1),
+                                amount: num!(0.0),
+                            }],
+                            asks: vec![GateioLevel {
+                                price: num!(54743.6),
+                                amount: num!(95.0),
+                            }],
+                        },
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioOrderBookL2>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+                assert_eq!(
+                    actual.id(),
+                    Some(SubscriptionId(format!(
+                        "{}|{}",
+                        actual.channel, actual.data.market
+                    ))),
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_gateio_book_updater_update() {
+        struct TestCase {
+            updater: GateioBookUpdater<GateioServerSpot>,
+            book: OrderBook,
+            input_update: GateioOrderBookL2,
+            expected: Result<Option<OrderBook>, DataError>,
+        }
+
+        let time = Utc::now();
+
+        let update =
+            |first_update_id, last_update_id, bids: Vec<(Number, Number)>| GateioOrderBookL2 {
+                channel: "spot.order_book_update".to_string(),
+                error: None,
+                data: GateioOrderBookL2Delta {
+                    time,
+                    market: "BTC_USDT".to_string(),
+                    first_update_id,
+                    last_update_id,
+                    bids: bids
+                        .into_iter()
+                        .map(|(price, amount)| GateioLevel { price, amount })
+                        .collect(),
+                    asks: vec![],
+                },
+            };
+
+        let book = |bids: Vec<Level>| OrderBook {
+            last_update_time: time,
+            bids: OrderBookSide::new(Side::Buy, bids),
+            asks: OrderBookSide::new(Side::Sell, vec![Level::new(num!(101.0), num!(1.0))]),
+        };
+
+        let processed = |updates_processed, last_update_id| GateioBookUpdater {
+            updates_processed,
+            last_update_id,
+            prev_last_update_id: last_update_id,
+            server: PhantomData,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: drop update where u < baseID + 1
+                updater: GateioBookUpdater::new(100),
+                book: book(vec![Level::new(num!(100.0), num!(1.0))]),
+                input_update: update(90, 100, vec![(num!(100.0), num!(5.0))]),
+                expected: Ok(None),
+            },
+            TestCase {
+                // TC1: first update w/ U <= baseID + 1 <= u is applied
+                updater: GateioBookUpdater::new(100),
+                book: book(vec![Level::new(num!(100.0), num!(1.0))]),
+                input_update: update(
+                    95,
+                    105,
+                    vec![(num!(100.0), num!(5.0)), (num!(99.0), num!(1.0))],
+                ),
+                expected: Ok(Some(book(vec![
+                    Level::new(num!(100.0), num!(5.0)),
+                    Level::new(num!(99.0), num!(1.0)),
+                ]))),
+            },
+            TestCase {
+                // TC2: first update w/ U > baseID + 1 is an invalid sequence
+                updater: GateioBookUpdater::new(100),
+                book: book(vec![Level::new(num!(100.0), num!(1.0))]),
+                input_update: update(102, 105, vec![(num!(100.0), num!(5.0))]),
+                expected: Err(DataError::InvalidSequence {
+                    prev_last_update_id: 100,
+                    first_update_id: 102,
+                }),
+            },
+            TestCase {
+                // TC3: next update w/ U == prev u + 1 is applied, w/ amount 0 removing level
+                updater: processed(1, 105),
+                book: book(vec![
+                    Level::new(num!(100.0), num!(1.0)),
+                    Level::new(num!(99.0), num!(1.0)),
+                ]),
+                input_update: update(106, 110, vec![(num!(100.0), num!(0.0))]),
+                expected: Ok(Some(book(vec![Level::new(num!(99.0), num!(1.0))]))),
+            },
+            TestCase {
+                // TC4: next update w/ U != prev u + 1 is an invalid sequence
+                updater: processed(1, 105),
+                book: book(vec![Level::new(num!(100.0), num!(1.0))]),
+                input_update: update(108, 110, vec![(num!(100.0), num!(0.0))]),
+                expected: Err(DataError::InvalidSequence {
+                    prev_last_update_id: 105,
+                    first_update_id: 108,
+                }),
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            let actual = test.updater.update(&mut test.book, test.input_update);
+
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
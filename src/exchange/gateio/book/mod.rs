@@ -0,0 +1,112 @@
+use crate::{number::Number, subscription::book::Level};
+use serde::{Deserialize, Serialize};
+
+/// Level 1 OrderBook types (top of book).
+pub mod l1;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Gateio`](super::Gateio) OrderBook level.
+///
+/// ### Notes
+/// - [`GateioSpot`](super::spot::GateioSpot) levels are arrays of strings, whereas futures,
+///   perpetual & option levels are objects with a string price and a numeric size.
+///
+/// #### Raw Payload Examples
+/// #### Spot
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+/// ```json
+/// ["19137.74", "0.0001"]
+/// ```
+///
+/// #### Futures, Perpetuals & Options
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-notification>
+/// ```json
+/// {"p": "54672.1", "s": 95}
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioLevel {
+    #[serde(alias = "p", deserialize_with = "de_str_or_number")]
+    pub price: Number,
+    #[serde(alias = "s", deserialize_with = "de_str_or_number")]
+    pub amount: Number,
+}
+
+impl From<GateioLevel> for Level {
+    fn from(level: GateioLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// Deserialize a [`Gateio`](super::Gateio) numeric field that is either encoded as a string
+/// (eg/ "0.0001") or as a number (eg/ 95) into a [`Number`].
+pub fn de_str_or_number<'de, D>(deserializer: D) -> Result<Number, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StrOrNumber {
+        Str(String),
+        Number(Number),
+    }
+
+    match StrOrNumber::deserialize(deserializer)? {
+        StrOrNumber::Str(value) => value.parse().map_err(serde::de::Error::custom),
+        StrOrNumber::Number(value) => Ok(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_level() {
+            struct TestCase {
+                input: &'static str,
+                expected: GateioLevel,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: spot level
+                    input: r#"["19137.74", "0.0001"]"#,
+                    expected: GateioLevel {
+                        price: num!(19137.74),
+                        amount: num!(0.0001),
+                    },
+                },
+                TestCase {
+                    // TC1: futures level
+                    input: r#"{"p": "54672.1", "s": 95}"#,
+                    expected: GateioLevel {
+                        price: num!(54672.1),
+                        amount: num!(95.0),
+                    },
+                },
+                TestCase {
+                    // TC2: futures level removal
+                    input: r#"{"p": "54672.1", "s": 0}"#,
+                    expected: GateioLevel {
+                        price: num!(54672.1),
+                        amount: num!(0.0),
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioLevel>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
@@ -1,5 +1,19 @@
+use super::{
+    option::GateioOptions,
+    perpetual::{GateioPerpetualsBtc, GateioPerpetualsUsd},
+    spot::GateioSpot,
+};
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        candle::Candles,
+        funding::FundingRates,
+        liquidation::Liquidations,
+        mark_price::MarkPrices,
+        ticker::Tickers,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use barter_integration::model::instrument::kind::InstrumentKind;
@@ -28,6 +42,67 @@ impl GateioChannel {
     ///
     /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#public-contract-trades-channel>
     pub const OPTION_TRADES: Self = Self("options.trades");
+
+    /// Gateio [`InstrumentKind::Spot`] real-time best bid & ask channel, used for OrderBook
+    /// Level1 (top of book).
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+    pub const SPOT_ORDER_BOOK_L1: Self = Self("spot.book_ticker");
+
+    /// Gateio [`InstrumentKind::Future`] & [`InstrumentKind::Perpetual`] real-time best bid &
+    /// ask channel, used for OrderBook Level1 (top of book).
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#best-ask-bid-subscription>
+    /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/#best-ask-bid-subscription>
+    pub const FUTURE_ORDER_BOOK_L1: Self = Self("futures.book_ticker");
+
+    /// Gateio [`InstrumentKind::Option`] real-time best bid & ask channel, used for OrderBook
+    /// Level1 (top of book).
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#best-bid-or-ask-price>
+    pub const OPTION_ORDER_BOOK_L1: Self = Self("options.book_ticker");
+
+    /// Gateio [`InstrumentKind::Spot`] real-time OrderBook Level2 deltas channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+    pub const SPOT_ORDER_BOOK_L2: Self = Self("spot.order_book_update");
+
+    /// Gateio [`InstrumentKind::Future`] & [`InstrumentKind::Perpetual`] real-time OrderBook
+    /// Level2 deltas channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-subscription>
+    /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/#order-book-update-subscription>
+    pub const FUTURE_ORDER_BOOK_L2: Self = Self("futures.order_book_update");
+
+    /// Gateio [`InstrumentKind::Option`] real-time OrderBook Level2 deltas channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#order-book-update-channel>
+    pub const OPTION_ORDER_BOOK_L2: Self = Self("options.order_book_update");
+
+    /// Gateio [`InstrumentKind::Spot`] real-time rolling 24h tickers channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#tickers-channel>
+    pub const SPOT_TICKERS: Self = Self("spot.tickers");
+
+    /// Gateio [`InstrumentKind::Perpetual`] real-time tickers channel, used for funding rates,
+    /// mark prices & rolling 24h tickers.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#tickers-api>
+    pub const FUTURE_TICKERS: Self = Self("futures.tickers");
+
+    /// Gateio [`InstrumentKind::Perpetual`] real-time public liquidations channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#public-liquidates-notification>
+    pub const FUTURE_LIQUIDATIONS: Self = Self("futures.public_liquidates");
+
+    /// Return the real-time OrderBook Level2 deltas channel for the provided [`InstrumentKind`].
+    pub fn order_book_l2(kind: &InstrumentKind) -> Self {
+        match kind {
+            InstrumentKind::Spot => Self::SPOT_ORDER_BOOK_L2,
+            InstrumentKind::Future(_) | InstrumentKind::Perpetual => Self::FUTURE_ORDER_BOOK_L2,
+            InstrumentKind::Option(_) => Self::OPTION_ORDER_BOOK_L2,
+        }
+    }
 }
 
 impl<GateioExchange> Identifier<GateioChannel> for Subscription<GateioExchange, PublicTrades> {
@@ -40,6 +115,93 @@ impl<GateioExchange> Identifier<GateioChannel> for Subscription<GateioExchange,
     }
 }
 
+impl<GateioExchange> Identifier<GateioChannel> for Subscription<GateioExchange, OrderBooksL1> {
+    fn id(&self) -> GateioChannel {
+        match self.instrument.kind {
+            InstrumentKind::Spot => GateioChannel::SPOT_ORDER_BOOK_L1,
+            InstrumentKind::Future(_) | InstrumentKind::Perpetual => {
+                GateioChannel::FUTURE_ORDER_BOOK_L1
+            }
+            InstrumentKind::Option(_) => GateioChannel::OPTION_ORDER_BOOK_L1,
+        }
+    }
+}
+
+impl<GateioExchange> Identifier<GateioChannel> for Subscription<GateioExchange, OrderBooksL2> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::order_book_l2(&self.instrument.kind)
+    }
+}
+
+impl<GateioExchange> Identifier<GateioChannel> for Subscription<GateioExchange, OrderBooksL2Delta> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::order_book_l2(&self.instrument.kind)
+    }
+}
+
+/// [`Candles`] are aggregated from the trades channel since [`GateioOptions`] does not offer
+/// native candle streams (see
+/// [`TradeCandleTransformer`](crate::transformer::candle::TradeCandleTransformer)).
+impl Identifier<GateioChannel> for Subscription<GateioOptions, Candles> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::OPTION_TRADES
+    }
+}
+
+impl Identifier<GateioChannel> for Subscription<GateioPerpetualsUsd, FundingRates> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_TICKERS
+    }
+}
+
+impl Identifier<GateioChannel> for Subscription<GateioPerpetualsBtc, FundingRates> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_TICKERS
+    }
+}
+
+impl Identifier<GateioChannel> for Subscription<GateioPerpetualsUsd, MarkPrices> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_TICKERS
+    }
+}
+
+impl Identifier<GateioChannel> for Subscription<GateioPerpetualsBtc, MarkPrices> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_TICKERS
+    }
+}
+
+impl Identifier<GateioChannel> for Subscription<GateioSpot, Tickers> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::SPOT_TICKERS
+    }
+}
+
+impl Identifier<GateioChannel> for Subscription<GateioPerpetualsUsd, Tickers> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_TICKERS
+    }
+}
+
+impl Identifier<GateioChannel> for Subscription<GateioPerpetualsBtc, Tickers> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_TICKERS
+    }
+}
+
+impl Identifier<GateioChannel> for Subscription<GateioPerpetualsUsd, Liquidations> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_LIQUIDATIONS
+    }
+}
+
+impl Identifier<GateioChannel> for Subscription<GateioPerpetualsBtc, Liquidations> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_LIQUIDATIONS
+    }
+}
+
 impl AsRef<str> for GateioChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,11 +1,25 @@
 use crate::{
     exchange::{
-        gateio::{perpetual::trade::GateioFuturesTrades, Gateio},
+        gateio::{
+            book::{
+                l1::GateioOrderBookL1,
+                l2::{GateioBookServer, GateioBookUpdater, GATEIO_ORDER_BOOK_L2_DEPTH},
+            },
+            market::GateioMarket,
+            perpetual::trade::GateioFuturesTrades,
+            Gateio,
+        },
         ExchangeId, ExchangeServer, StreamSelector,
     },
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        trade::PublicTrades,
+    },
+    transformer::{
+        book::{MultiBookDeltaTransformer, MultiBookTransformer},
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, TimedExchangeWsStream,
 };
 
 /// [`GateioFuturesUsd`] WebSocket server base url.
@@ -13,6 +27,12 @@ use crate::{
 /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_FUTURES_USD: &str = "wss://fx-ws.gateio.ws/v4/ws/delivery/usdt";
 
+/// [`GateioFuturesUsd`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book-2>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_FUTURES_USD: &str =
+    "https://api.gateio.ws/api/v4/delivery/usdt/order_book";
+
 /// [`Gateio`] perpetual usd exchange.
 pub type GateioFuturesUsd = Gateio<GateioServerFuturesUsd>;
 
@@ -32,11 +52,50 @@ impl StreamSelector<PublicTrades> for GateioFuturesUsd {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioFuturesTrades>>;
 }
 
+impl GateioBookServer for GateioServerFuturesUsd {
+    fn http_book_l2_snapshot_url(market: &GateioMarket) -> String {
+        format!(
+            "{HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_FUTURES_USD}?contract={}&limit={GATEIO_ORDER_BOOK_L2_DEPTH}&with_id=true",
+            market.as_ref()
+        )
+    }
+}
+
+impl StreamSelector<OrderBooksL1> for GateioFuturesUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, GateioOrderBookL1>>;
+}
+
+impl StreamSelector<OrderBooksL2> for GateioFuturesUsd {
+    type Stream = TimedExchangeWsStream<
+        MultiBookTransformer<Self, OrderBooksL2, GateioBookUpdater<GateioServerFuturesUsd>>,
+    >;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for GateioFuturesUsd {
+    type Stream = ExchangeWsStream<
+        MultiBookDeltaTransformer<
+            Self,
+            OrderBooksL2Delta,
+            GateioBookUpdater<GateioServerFuturesUsd>,
+        >,
+    >;
+}
+
 /// [`GateioFuturesBtc`] WebSocket server base url.
 ///
 /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_FUTURES_BTC: &str = "wss://fx-ws.gateio.ws/v4/ws/delivery/btc";
 
+/// [`GateioFuturesBtc`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book-2>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_FUTURES_BTC: &str =
+    "https://api.gateio.ws/api/v4/delivery/btc/order_book";
+
 /// [`Gateio`] perpetual btc exchange.
 pub type GateioFuturesBtc = Gateio<GateioServerFuturesBtc>;
 
@@ -55,3 +114,36 @@ impl ExchangeServer for GateioServerFuturesBtc {
 impl StreamSelector<PublicTrades> for GateioFuturesBtc {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioFuturesTrades>>;
 }
+
+impl GateioBookServer for GateioServerFuturesBtc {
+    fn http_book_l2_snapshot_url(market: &GateioMarket) -> String {
+        format!(
+            "{HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_FUTURES_BTC}?contract={}&limit={GATEIO_ORDER_BOOK_L2_DEPTH}&with_id=true",
+            market.as_ref()
+        )
+    }
+}
+
+impl StreamSelector<OrderBooksL1> for GateioFuturesBtc {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, GateioOrderBookL1>>;
+}
+
+impl StreamSelector<OrderBooksL2> for GateioFuturesBtc {
+    type Stream = TimedExchangeWsStream<
+        MultiBookTransformer<Self, OrderBooksL2, GateioBookUpdater<GateioServerFuturesBtc>>,
+    >;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for GateioFuturesBtc {
+    type Stream = ExchangeWsStream<
+        MultiBookDeltaTransformer<
+            Self,
+            OrderBooksL2Delta,
+            GateioBookUpdater<GateioServerFuturesBtc>,
+        >,
+    >;
+}
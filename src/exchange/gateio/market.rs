@@ -19,10 +19,16 @@ pub struct GateioMarket(pub String);
 
 impl<Server, Kind> Identifier<GateioMarket> for Subscription<Gateio<Server>, Kind> {
     fn id(&self) -> GateioMarket {
+        GateioMarket::from(&self.instrument)
+    }
+}
+
+impl From<&Instrument> for GateioMarket {
+    fn from(instrument: &Instrument) -> Self {
         use InstrumentKind::*;
-        let Instrument { base, quote, kind } = &self.instrument;
+        let Instrument { base, quote, kind } = instrument;
 
-        GateioMarket(
+        Self(
             match kind {
                 Spot | Perpetual => format!("{base}_{quote}"),
                 Future(future) => {
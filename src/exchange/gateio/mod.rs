@@ -1,4 +1,9 @@
-use self::{channel::GateioChannel, market::GateioMarket, subscription::GateioSubResponse};
+use self::{
+    book::l2::{GATEIO_ORDER_BOOK_L2_DEPTH, GATEIO_ORDER_BOOK_L2_INTERVAL},
+    channel::GateioChannel,
+    market::GateioMarket,
+    subscription::GateioSubResponse,
+};
 use crate::{
     exchange::{subscription::ExchangeSub, Connector, ExchangeId, ExchangeServer},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
@@ -8,6 +13,9 @@ use serde_json::json;
 use std::{fmt::Debug, marker::PhantomData};
 use url::Url;
 
+/// Order book types common to every [`Gateio<Server>`](Gateio).
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -75,12 +83,28 @@ where
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
+                // OrderBook Level2 deltas channels require an update interval, and futures &
+                // options additionally require the depth level
+                let payload = match channel {
+                    GateioChannel::SPOT_ORDER_BOOK_L2 => {
+                        json!([market.as_ref(), GATEIO_ORDER_BOOK_L2_INTERVAL])
+                    }
+                    GateioChannel::FUTURE_ORDER_BOOK_L2 | GateioChannel::OPTION_ORDER_BOOK_L2 => {
+                        json!([
+                            market.as_ref(),
+                            GATEIO_ORDER_BOOK_L2_INTERVAL,
+                            GATEIO_ORDER_BOOK_L2_DEPTH.to_string()
+                        ])
+                    }
+                    _ => json!([market.as_ref()]),
+                };
+
                 WsMessage::Text(
                     json!({
                         "time": chrono::Utc::now().timestamp_millis(),
                         "channel": channel.as_ref(),
                         "event": "subscribe",
-                        "payload": [market.as_ref()]
+                        "payload": payload
                     })
                     .to_string(),
                 )
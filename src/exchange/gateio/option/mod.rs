@@ -1,11 +1,27 @@
 use crate::{
     exchange::{
-        gateio::{perpetual::trade::GateioFuturesTrades, Gateio},
+        gateio::{
+            book::{
+                l1::GateioOrderBookL1,
+                l2::{GateioBookServer, GateioBookUpdater, GATEIO_ORDER_BOOK_L2_DEPTH},
+            },
+            market::GateioMarket,
+            perpetual::trade::GateioFuturesTrades,
+            Gateio,
+        },
         ExchangeId, ExchangeServer, StreamSelector,
     },
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        candle::Candles,
+        trade::PublicTrades,
+    },
+    transformer::{
+        book::{MultiBookDeltaTransformer, MultiBookTransformer},
+        candle::TradeCandleTransformer,
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, TimedExchangeWsStream,
 };
 
 /// [`GateioOptions`] WebSocket server base url.
@@ -13,6 +29,12 @@ use crate::{
 /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_OPTIONS_USD: &str = "wss://op-ws.gateio.live/v4/ws";
 
+/// [`GateioOptions`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#options-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_OPTIONS: &str =
+    "https://api.gateio.ws/api/v4/options/order_book";
+
 /// [`Gateio`] options exchange.
 pub type GateioOptions = Gateio<GateioServerOptions>;
 
@@ -31,3 +53,36 @@ impl ExchangeServer for GateioServerOptions {
 impl StreamSelector<PublicTrades> for GateioOptions {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioFuturesTrades>>;
 }
+
+impl GateioBookServer for GateioServerOptions {
+    fn http_book_l2_snapshot_url(market: &GateioMarket) -> String {
+        format!(
+            "{HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_OPTIONS}?contract={}&limit={GATEIO_ORDER_BOOK_L2_DEPTH}&with_id=true",
+            market.as_ref()
+        )
+    }
+}
+
+impl StreamSelector<OrderBooksL1> for GateioOptions {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, GateioOrderBookL1>>;
+}
+
+impl StreamSelector<OrderBooksL2> for GateioOptions {
+    type Stream = TimedExchangeWsStream<
+        MultiBookTransformer<Self, OrderBooksL2, GateioBookUpdater<GateioServerOptions>>,
+    >;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for GateioOptions {
+    type Stream = ExchangeWsStream<
+        MultiBookDeltaTransformer<Self, OrderBooksL2Delta, GateioBookUpdater<GateioServerOptions>>,
+    >;
+}
+
+impl StreamSelector<Candles> for GateioOptions {
+    type Stream = TimedExchangeWsStream<TradeCandleTransformer<Self, GateioFuturesTrades>>;
+}
@@ -0,0 +1,195 @@
+use super::super::message::GateioMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    number::Number,
+    subscription::liquidation::Liquidation,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`GateioPerpetualsUsd`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualsBtc`](super::GateioPerpetualsBtc) real-time public liquidations WebSocket
+/// message.
+pub type GateioFuturesLiquidations = GateioMessage<Vec<GateioFuturesLiquidation>>;
+
+/// [`GateioPerpetualsUsd`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualsBtc`](super::GateioPerpetualsBtc) real-time public liquidation.
+///
+/// ### Notes
+/// The `size` is the signed size of the liquidated position, ie/ positive means a long position
+/// has been liquidated.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#public-liquidates-notification>
+/// ```json
+/// {
+///   "channel": "futures.public_liquidates",
+///   "event": "update",
+///   "time": 1541505434,
+///   "time_ms": 1541505434123,
+///   "result": [
+///     {
+///       "price": 215.1,
+///       "size": -124,
+///       "time_ms": 1541486601123,
+///       "contract": "BTC_USDT"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesLiquidation {
+    #[serde(rename = "contract")]
+    pub market: String,
+    pub price: Number,
+    pub size: Number,
+    #[serde(
+        rename = "time_ms",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioFuturesLiquidations {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|liquidation| ExchangeSub::from((&self.channel, &liquidation.market)).id())
+    }
+}
+
+impl From<(ExchangeId, Instrument, GateioFuturesLiquidations)> for MarketIter<Liquidation> {
+    fn from(
+        (exchange_id, instrument, liquidations): (
+            ExchangeId,
+            Instrument,
+            GateioFuturesLiquidations,
+        ),
+    ) -> Self {
+        liquidations
+            .data
+            .into_iter()
+            .map(|liquidation| MarketEvent {
+                exchange_time: liquidation.time,
+                received_time: Utc::now(),
+                exchange: Exchange::from(exchange_id),
+                instrument: instrument.clone(),
+                kind: Liquidation {
+                    // Normalise the liquidated position size to the liquidation order side
+                    side: if liquidation.size.is_sign_positive() {
+                        Side::Sell
+                    } else {
+                        Side::Buy
+                    },
+                    price: liquidation.price,
+                    quantity: liquidation.size.abs(),
+                    time: liquidation.time,
+                    average_price: None,
+                    filled_quantity: None,
+                    status: None,
+                },
+            })
+            .map(Ok)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, model::instrument::kind::InstrumentKind,
+    };
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_futures_liquidations() {
+            let input = r#"
+            {
+                "channel": "futures.public_liquidates",
+                "event": "update",
+                "time": 1541505434,
+                "time_ms": 1541505434123,
+                "result": [
+                    {
+                        "price": 215.1,
+                        "size": -124,
+                        "time_ms": 1541486601123,
+                        "contract": "BTC_USDT"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<GateioFuturesLiquidations>(input).unwrap();
+            assert_eq!(
+                actual.data,
+                vec![GateioFuturesLiquidation {
+                    market: "BTC_USDT".to_string(),
+                    price: num!(215.1),
+                    size: num!(-124.0),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1541486601123)),
+                }]
+            );
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("futures.public_liquidates|BTC_USDT"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_gateio_futures_liquidation_side_is_normalised() {
+        struct TestCase {
+            size: Number,
+            expected: (Side, Number),
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: liquidated long position yields a Side::Sell liquidation
+                size: num!(124.0),
+                expected: (Side::Sell, num!(124.0)),
+            },
+            TestCase {
+                // TC1: liquidated short position yields a Side::Buy liquidation
+                size: num!(-124.0),
+                expected: (Side::Buy, num!(124.0)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let liquidations = GateioFuturesLiquidations {
+                channel: "futures.public_liquidates".to_string(),
+                error: None,
+                data: vec![GateioFuturesLiquidation {
+                    market: "BTC_USDT".to_string(),
+                    price: num!(215.1),
+                    size: test.size,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1541486601123)),
+                }],
+            };
+
+            let actual = MarketIter::<Liquidation>::from((
+                ExchangeId::GateioPerpetualsUsd,
+                Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                liquidations,
+            ));
+
+            let kind = actual.0[0].as_ref().unwrap().kind;
+            assert_eq!(
+                (kind.side, kind.quantity),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
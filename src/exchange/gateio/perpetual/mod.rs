@@ -1,12 +1,38 @@
-use self::trade::GateioFuturesTrades;
-use super::Gateio;
+use self::{
+    liquidation::GateioFuturesLiquidations, ticker::GateioFuturesTickers,
+    trade::GateioFuturesTrades,
+};
+use super::{
+    book::{
+        l1::GateioOrderBookL1,
+        l2::{GateioBookServer, GateioBookUpdater, GATEIO_ORDER_BOOK_L2_DEPTH},
+    },
+    market::GateioMarket,
+    Gateio,
+};
 use crate::{
     exchange::{ExchangeId, ExchangeServer, StreamSelector},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        funding::FundingRates,
+        liquidation::Liquidations,
+        mark_price::MarkPrices,
+        ticker::Tickers,
+        trade::PublicTrades,
+    },
+    transformer::{
+        book::{MultiBookDeltaTransformer, MultiBookTransformer},
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, TimedExchangeWsStream,
 };
 
+/// Public liquidation types.
+pub mod liquidation;
+
+/// Ticker types, used for funding rates, mark prices & rolling 24h tickers.
+pub mod ticker;
+
 /// Public trades types.
 pub mod trade;
 
@@ -15,6 +41,12 @@ pub mod trade;
 /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_PERPETUALS_USD: &str = "wss://fx-ws.gateio.ws/v4/ws/usdt";
 
+/// [`GateioPerpetualsUsd`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_USD: &str =
+    "https://api.gateio.ws/api/v4/futures/usdt/order_book";
+
 /// [`Gateio`] perpetual usd exchange.
 pub type GateioPerpetualsUsd = Gateio<GateioServerPerpetualsUsd>;
 
@@ -34,11 +66,67 @@ impl StreamSelector<PublicTrades> for GateioPerpetualsUsd {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioFuturesTrades>>;
 }
 
+impl StreamSelector<FundingRates> for GateioPerpetualsUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, FundingRates, GateioFuturesTickers>>;
+}
+
+impl StreamSelector<MarkPrices> for GateioPerpetualsUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, MarkPrices, GateioFuturesTickers>>;
+}
+
+impl StreamSelector<Tickers> for GateioPerpetualsUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Tickers, GateioFuturesTickers>>;
+}
+
+impl StreamSelector<Liquidations> for GateioPerpetualsUsd {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Liquidations, GateioFuturesLiquidations>>;
+}
+
+impl GateioBookServer for GateioServerPerpetualsUsd {
+    fn http_book_l2_snapshot_url(market: &GateioMarket) -> String {
+        format!(
+            "{HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_USD}?contract={}&limit={GATEIO_ORDER_BOOK_L2_DEPTH}&with_id=true",
+            market.as_ref()
+        )
+    }
+}
+
+impl StreamSelector<OrderBooksL1> for GateioPerpetualsUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, GateioOrderBookL1>>;
+}
+
+impl StreamSelector<OrderBooksL2> for GateioPerpetualsUsd {
+    type Stream = TimedExchangeWsStream<
+        MultiBookTransformer<Self, OrderBooksL2, GateioBookUpdater<GateioServerPerpetualsUsd>>,
+    >;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for GateioPerpetualsUsd {
+    type Stream = ExchangeWsStream<
+        MultiBookDeltaTransformer<
+            Self,
+            OrderBooksL2Delta,
+            GateioBookUpdater<GateioServerPerpetualsUsd>,
+        >,
+    >;
+}
+
 /// [`GateioPerpetualsBtc`] WebSocket server base url.
 ///
 /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_PERPETUALS_BTC: &str = "wss://fx-ws.gateio.ws/v4/ws/btc";
 
+/// [`GateioPerpetualsBtc`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_BTC: &str =
+    "https://api.gateio.ws/api/v4/futures/btc/order_book";
+
 /// [`Gateio`] perpetual btc exchange.
 pub type GateioPerpetualsBtc = Gateio<GateioServerPerpetualsBtc>;
 
@@ -57,3 +145,53 @@ impl ExchangeServer for GateioServerPerpetualsBtc {
 impl StreamSelector<PublicTrades> for GateioPerpetualsBtc {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioFuturesTrades>>;
 }
+
+impl StreamSelector<FundingRates> for GateioPerpetualsBtc {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, FundingRates, GateioFuturesTickers>>;
+}
+
+impl StreamSelector<MarkPrices> for GateioPerpetualsBtc {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, MarkPrices, GateioFuturesTickers>>;
+}
+
+impl StreamSelector<Tickers> for GateioPerpetualsBtc {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Tickers, GateioFuturesTickers>>;
+}
+
+impl StreamSelector<Liquidations> for GateioPerpetualsBtc {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Liquidations, GateioFuturesLiquidations>>;
+}
+
+impl GateioBookServer for GateioServerPerpetualsBtc {
+    fn http_book_l2_snapshot_url(market: &GateioMarket) -> String {
+        format!(
+            "{HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_BTC}?contract={}&limit={GATEIO_ORDER_BOOK_L2_DEPTH}&with_id=true",
+            market.as_ref()
+        )
+    }
+}
+
+impl StreamSelector<OrderBooksL1> for GateioPerpetualsBtc {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, GateioOrderBookL1>>;
+}
+
+impl StreamSelector<OrderBooksL2> for GateioPerpetualsBtc {
+    type Stream = TimedExchangeWsStream<
+        MultiBookTransformer<Self, OrderBooksL2, GateioBookUpdater<GateioServerPerpetualsBtc>>,
+    >;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for GateioPerpetualsBtc {
+    type Stream = ExchangeWsStream<
+        MultiBookDeltaTransformer<
+            Self,
+            OrderBooksL2Delta,
+            GateioBookUpdater<GateioServerPerpetualsBtc>,
+        >,
+    >;
+}
@@ -0,0 +1,239 @@
+use super::super::message::GateioMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    number::Number,
+    subscription::{funding::FundingRate, mark_price::MarkPrice, ticker::Ticker},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`GateioPerpetualUsdt`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualBtc`](super::GateioPerpetualsBtc) real-time tickers WebSocket message.
+pub type GateioFuturesTickers = GateioMessage<Vec<GateioFuturesTicker>>;
+
+/// [`GateioPerpetualUsdt`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualBtc`](super::GateioPerpetualsBtc) real-time ticker WebSocket message, used
+/// for [`FundingRates`](crate::subscription::funding::FundingRates),
+/// [`MarkPrices`](crate::subscription::mark_price::MarkPrices) and
+/// [`Tickers`](crate::subscription::ticker::Tickers).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#tickers-api>
+/// ```json
+/// {
+///   "contract": "BTC_USD",
+///   "last": "118.4",
+///   "change_percentage": "0.77",
+///   "funding_rate": "-0.000114",
+///   "funding_rate_indicative": "0.01875",
+///   "mark_price": "118.35",
+///   "index_price": "118.36",
+///   "total_size": "73648",
+///   "volume_24h": "745487577",
+///   "volume_24h_base": "7454.87577",
+///   "volume_24h_quote": "882623.47",
+///   "volume_24h_settle": "7454.87577",
+///   "low_24h": "99.2",
+///   "high_24h": "132.5"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesTicker {
+    #[serde(rename = "contract")]
+    pub market: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub last: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub change_percentage: Number,
+    #[serde(
+        rename = "high_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub high: Number,
+    #[serde(
+        rename = "low_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub low: Number,
+    #[serde(
+        rename = "volume_24h_base",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume_base: Number,
+    #[serde(
+        rename = "volume_24h_quote",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume_quote: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub funding_rate: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub funding_rate_indicative: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub index_price: Number,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioFuturesTickers {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|ticker| ExchangeSub::from((&self.channel, &ticker.market)).id())
+    }
+}
+
+impl From<(ExchangeId, Instrument, GateioFuturesTickers)> for MarketIter<FundingRate> {
+    fn from(
+        (exchange_id, instrument, tickers): (ExchangeId, Instrument, GateioFuturesTickers),
+    ) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                // Gateio tickers do not contain a timestamp, nor the next funding time
+                MarketEvent {
+                    exchange_time: Utc::now(),
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: FundingRate {
+                        rate: ticker.funding_rate,
+                        predicted_rate: Some(ticker.funding_rate_indicative),
+                        next_funding_time: None,
+                        mark_price: Some(ticker.mark_price),
+                    },
+                }
+            })
+            .map(Ok)
+            .collect()
+    }
+}
+
+impl From<(ExchangeId, Instrument, GateioFuturesTickers)> for MarketIter<MarkPrice> {
+    fn from(
+        (exchange_id, instrument, tickers): (ExchangeId, Instrument, GateioFuturesTickers),
+    ) -> Self {
+        // Gateio tickers do not contain a timestamp
+        let time = Utc::now();
+
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| MarketEvent {
+                exchange_time: time,
+                received_time: time,
+                exchange: Exchange::from(exchange_id),
+                instrument: instrument.clone(),
+                kind: MarkPrice {
+                    mark: ticker.mark_price,
+                    index: Some(ticker.index_price),
+                    estimated_settle: None,
+                    time,
+                },
+            })
+            .map(Ok)
+            .collect()
+    }
+}
+
+impl From<(ExchangeId, Instrument, GateioFuturesTickers)> for MarketIter<Ticker> {
+    fn from(
+        (exchange_id, instrument, tickers): (ExchangeId, Instrument, GateioFuturesTickers),
+    ) -> Self {
+        // Gateio tickers do not contain a timestamp
+        let time = Utc::now();
+
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| MarketEvent {
+                exchange_time: time,
+                received_time: time,
+                exchange: Exchange::from(exchange_id),
+                instrument: instrument.clone(),
+                kind: Ticker {
+                    last: ticker.last,
+                    open: None,
+                    high: ticker.high,
+                    low: ticker.low,
+                    volume_base: ticker.volume_base,
+                    volume_quote: Some(ticker.volume_quote),
+                    change_percent: Some(ticker.change_percentage),
+                    time,
+                },
+            })
+            .map(Ok)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_message_futures_ticker() {
+            let input = r#"
+            {
+                "time": 1541659086,
+                "time_ms": 1541659086123,
+                "channel": "futures.tickers",
+                "event": "update",
+                "error": null,
+                "result": [
+                    {
+                        "contract": "BTC_USD",
+                        "last": "118.4",
+                        "change_percentage": "0.77",
+                        "funding_rate": "-0.000114",
+                        "funding_rate_indicative": "0.01875",
+                        "mark_price": "118.35",
+                        "index_price": "118.36",
+                        "total_size": "73648",
+                        "volume_24h": "745487577",
+                        "volume_24h_base": "7454.87577",
+                        "volume_24h_quote": "882623.47",
+                        "volume_24h_settle": "7454.87577",
+                        "low_24h": "99.2",
+                        "high_24h": "132.5"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<GateioFuturesTickers>(input).unwrap();
+            assert_eq!(
+                actual,
+                GateioFuturesTickers {
+                    channel: "futures.tickers".to_string(),
+                    error: None,
+                    data: vec![GateioFuturesTicker {
+                        market: "BTC_USD".to_string(),
+                        last: num!(118.4),
+                        change_percentage: num!(0.77),
+                        high: num!(132.5),
+                        low: num!(99.2),
+                        volume_base: num!(7454.87577),
+                        volume_quote: num!(882623.47),
+                        funding_rate: num!(-0.000114),
+                        funding_rate_indicative: num!(0.01875),
+                        mark_price: num!(118.35),
+                        index_price: num!(118.36),
+                    }],
+                }
+            );
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("futures.tickers|BTC_USD"))
+            );
+        }
+    }
+}
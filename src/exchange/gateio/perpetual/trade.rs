@@ -2,6 +2,7 @@ use super::super::message::GateioMessage;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
+    number::Number,
     subscription::trade::PublicTrade,
     Identifier,
 };
@@ -58,9 +59,9 @@ pub struct GateioFuturesTradeInner {
     pub time: DateTime<Utc>,
     pub id: u64,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "size")]
-    pub amount: f64,
+    pub amount: Number,
 }
 
 impl Identifier<Option<SubscriptionId>> for GateioFuturesTrades {
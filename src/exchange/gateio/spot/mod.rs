@@ -1,13 +1,30 @@
-use self::trade::GateioSpotTrade;
-use super::Gateio;
+use self::{ticker::GateioSpotTicker, trade::GateioSpotTrade};
+use super::{
+    book::{
+        l1::GateioOrderBookL1,
+        l2::{GateioBookServer, GateioBookUpdater, GATEIO_ORDER_BOOK_L2_DEPTH},
+    },
+    market::GateioMarket,
+    Gateio,
+};
 use crate::{
     exchange::{ExchangeId, ExchangeServer, StreamSelector},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        ticker::Tickers,
+        trade::PublicTrades,
+    },
+    transformer::{
+        book::{MultiBookDeltaTransformer, MultiBookTransformer},
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, TimedExchangeWsStream,
 };
 use barter_macro::{DeExchange, SerExchange};
 
+/// Rolling 24h ticker types.
+pub mod ticker;
+
 /// Public trades types.
 pub mod trade;
 
@@ -16,6 +33,12 @@ pub mod trade;
 /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_SPOT: &str = "wss://api.gateio.ws/ws/v4/";
 
+/// [`GateioSpot`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#retrieve-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_SPOT: &str =
+    "https://api.gateio.ws/api/v4/spot/order_book";
+
 /// [`Gateio`](super::Gateio) spot exchange.
 pub type GateioSpot = Gateio<GateioServerSpot>;
 
@@ -36,3 +59,36 @@ impl ExchangeServer for GateioServerSpot {
 impl StreamSelector<PublicTrades> for GateioSpot {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioSpotTrade>>;
 }
+
+impl StreamSelector<Tickers> for GateioSpot {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Tickers, GateioSpotTicker>>;
+}
+
+impl GateioBookServer for GateioServerSpot {
+    fn http_book_l2_snapshot_url(market: &GateioMarket) -> String {
+        format!(
+            "{HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_SPOT}?currency_pair={}&limit={GATEIO_ORDER_BOOK_L2_DEPTH}&with_id=true",
+            market.as_ref()
+        )
+    }
+}
+
+impl StreamSelector<OrderBooksL1> for GateioSpot {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, GateioOrderBookL1>>;
+}
+
+impl StreamSelector<OrderBooksL2> for GateioSpot {
+    type Stream = TimedExchangeWsStream<
+        MultiBookTransformer<Self, OrderBooksL2, GateioBookUpdater<GateioServerSpot>>,
+    >;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for GateioSpot {
+    type Stream = ExchangeWsStream<
+        MultiBookDeltaTransformer<Self, OrderBooksL2Delta, GateioBookUpdater<GateioServerSpot>>,
+    >;
+}
@@ -0,0 +1,147 @@
+use super::super::message::GateioMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    number::Number,
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`GateioSpot`](super::GateioSpot) real-time rolling 24h tickers
+/// WebSocket message.
+pub type GateioSpotTicker = GateioMessage<GateioSpotTickerInner>;
+
+/// [`GateioSpot`](super::GateioSpot) real-time rolling 24h ticker WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#tickers-channel>
+/// ```json
+/// {
+///   "currency_pair": "BTC_USDT",
+///   "last": "19106.55",
+///   "lowest_ask": "19108.71",
+///   "highest_bid": "19106.55",
+///   "change_percentage": "3.66",
+///   "base_volume": "2811.3042155865",
+///   "quote_volume": "53441606.52411221454674732293",
+///   "high_24h": "19417.74",
+///   "low_24h": "18434.21"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioSpotTickerInner {
+    #[serde(rename = "currency_pair")]
+    pub market: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub last: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub change_percentage: Number,
+    #[serde(
+        rename = "high_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub high: Number,
+    #[serde(
+        rename = "low_24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub low: Number,
+    #[serde(
+        rename = "base_volume",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume_base: Number,
+    #[serde(
+        rename = "quote_volume",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume_quote: Number,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioSpotTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+impl From<(ExchangeId, Instrument, GateioSpotTicker)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, GateioSpotTicker)) -> Self {
+        // Gateio ticker payloads do not contain a timestamp
+        let time = Utc::now();
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Ticker {
+                last: ticker.data.last,
+                open: None,
+                high: ticker.data.high,
+                low: ticker.data.low,
+                volume_base: ticker.data.volume_base,
+                volume_quote: Some(ticker.data.volume_quote),
+                change_percent: Some(ticker.data.change_percentage),
+                time,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_message_spot_ticker() {
+            let input = r#"
+            {
+                "time": 1606291803,
+                "time_ms": 1606291803123,
+                "channel": "spot.tickers",
+                "event": "update",
+                "result": {
+                    "currency_pair": "BTC_USDT",
+                    "last": "19106.55",
+                    "lowest_ask": "19108.71",
+                    "highest_bid": "19106.55",
+                    "change_percentage": "3.66",
+                    "base_volume": "2811.3042155865",
+                    "quote_volume": "53441606.52411221454674732293",
+                    "high_24h": "19417.74",
+                    "low_24h": "18434.21"
+                }
+            }
+            "#;
+
+            let actual = serde_json::from_str::<GateioSpotTicker>(input).unwrap();
+            assert_eq!(
+                actual,
+                GateioSpotTicker {
+                    channel: "spot.tickers".to_string(),
+                    error: None,
+                    data: GateioSpotTickerInner {
+                        market: "BTC_USDT".to_string(),
+                        last: num!(19106.55),
+                        change_percentage: num!(3.66),
+                        high: num!(19417.74),
+                        low: num!(18434.21),
+                        volume_base: num!(2811.3042155865),
+                        volume_quote: num!(53441606.52411221454674732293),
+                    },
+                }
+            );
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("spot.tickers|BTC_USDT"))
+            );
+        }
+    }
+}
@@ -2,6 +2,7 @@ use super::super::message::GateioMessage;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
+    number::Number,
     subscription::trade::PublicTrade,
     Identifier,
 };
@@ -38,10 +39,10 @@ pub struct GateioSpotTradeInner {
     pub time: DateTime<Utc>,
     pub id: u64,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
 
     #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Number,
 
     /// Taker [`Side`] of the trade.
     pub side: Side,
@@ -2,6 +2,7 @@ use super::super::KrakenMessage;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{kraken::channel::KrakenChannel, subscription::ExchangeSub, ExchangeId},
+    number::Number,
     subscription::book::{Level, OrderBookL1},
     Identifier,
 };
@@ -36,15 +37,15 @@ pub struct KrakenOrderBookL1Inner {
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct KrakenSpread {
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub best_bid_price: f64,
+    pub best_bid_price: Number,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub best_ask_price: f64,
+    pub best_ask_price: Number,
     #[serde(deserialize_with = "barter_integration::de::de_str_f64_epoch_s_as_datetime_utc")]
     pub time: DateTime<Utc>,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub best_bid_amount: f64,
+    pub best_bid_amount: Number,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub best_ask_amount: f64,
+    pub best_ask_amount: Number,
 }
 
 impl Identifier<Option<SubscriptionId>> for KrakenOrderBookL1Inner {
@@ -129,6 +130,7 @@ impl<'de> serde::de::Deserialize<'de> for KrakenOrderBookL1Inner {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::number::num;
 
     mod de {
         use super::*;
@@ -162,13 +164,13 @@ mod tests {
                 expected: Ok(KrakenOrderBookL1::Data(KrakenOrderBookL1Inner {
                     subscription_id: SubscriptionId::from("spread|XBT/USD"),
                     spread: KrakenSpread {
-                        best_bid_price: 5698.4,
-                        best_bid_amount: 1.01234567,
+                        best_bid_price: num!(5698.4),
+                        best_bid_amount: num!(1.01234567),
                         time: datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(
                             1542057299.545897,
                         )),
-                        best_ask_price: 5700.0,
-                        best_ask_amount: 0.98765432,
+                        best_ask_price: num!(5700.0),
+                        best_ask_amount: num!(0.98765432),
                     },
                 })),
             }];
@@ -0,0 +1,691 @@
+use super::super::{channel::KrakenChannel, message::KrakenMessage};
+use crate::{
+    error::DataError,
+    exchange::subscription::ExchangeSub,
+    number::Number,
+    subscription::book::{Level, OrderBook, OrderBookDelta, OrderBookSide},
+    transformer::book::{
+        InstrumentOrderBook, OrderBookChange, OrderBookDeltaUpdater, OrderBookUpdater, RawLevel,
+        RawLevels,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::{datetime_utc_from_epoch_duration, extract_next},
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// Default [`Kraken`](super::super::Kraken) OrderBook Level2 depth subscribed to, and therefore
+/// the depth each local [`OrderBook`] must be truncated to.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
+pub const KRAKEN_ORDER_BOOK_L2_DEPTH: usize = 10;
+
+/// Number of [`Level`]s on each side of the [`OrderBook`] used to generate the
+/// [`Kraken`](super::super::Kraken) OrderBook checksum.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+const KRAKEN_CHECKSUM_DEPTH: usize = 10;
+
+/// Terse type alias for an [`Kraken`](super::super::Kraken) real-time OrderBook Level2
+/// WebSocket message.
+pub type KrakenOrderBookL2 = KrakenMessage<KrakenOrderBookL2Inner>;
+
+/// [`Kraken`](super::super::Kraken) real-time OrderBook Level2 snapshot or delta and the
+/// associated [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+/// #### Snapshot
+/// ```json
+/// [
+///     0,
+///     {
+///         "as": [["5541.30000", "2.50700000", "1534614248.123678"]],
+///         "bs": [["5541.20000", "1.52900000", "1534614248.765567"]]
+///     },
+///     "book-10",
+///     "XBT/USD"
+/// ]
+/// ```
+///
+/// #### Delta
+/// ```json
+/// [
+///     1234,
+///     {"a": [["5541.30000", "2.50700000", "1534614248.456738"]]},
+///     {"b": [["5541.30000", "0.00000000", "1534614335.345903"]], "c": "974942666"},
+///     "book-10",
+///     "XBT/USD"
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenOrderBookL2Inner {
+    pub subscription_id: SubscriptionId,
+    pub update: KrakenBookUpdate,
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenOrderBookL2Inner {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// [`Kraken`](super::super::Kraken) OrderBook Level2 snapshot or delta.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum KrakenBookUpdate {
+    Snapshot {
+        bids: Vec<KrakenLevel>,
+        asks: Vec<KrakenLevel>,
+    },
+    Delta {
+        bids: Vec<KrakenLevel>,
+        asks: Vec<KrakenLevel>,
+        checksum: u32,
+    },
+}
+
+/// [`Kraken`](super::super::Kraken) OrderBook Level2 payload object. A delta may be split across
+/// two payload objects (one for each side), with the checksum in the final object.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize)]
+struct KrakenBookPayload {
+    #[serde(rename = "bs")]
+    snapshot_bids: Option<Vec<KrakenLevel>>,
+    #[serde(rename = "as")]
+    snapshot_asks: Option<Vec<KrakenLevel>>,
+    #[serde(rename = "b", default)]
+    bids: Vec<KrakenLevel>,
+    #[serde(rename = "a", default)]
+    asks: Vec<KrakenLevel>,
+    #[serde(rename = "c")]
+    checksum: Option<String>,
+}
+
+/// Second element of a [`Kraken`](super::super::Kraken) OrderBook Level2 delta, which is either
+/// a second payload object, or the channelName if the delta contains only one side.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KrakenBookPayloadOrChannel {
+    Payload(KrakenBookPayload),
+    Channel(serde::de::IgnoredAny),
+}
+
+/// [`Kraken`](super::super::Kraken) OrderBook Level2 [`Level`].
+///
+/// The raw price and amount strings are retained since they are required to generate the
+/// [`Kraken`](super::super::Kraken) OrderBook checksum.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// ["5541.30000", "2.50700000", "1534614248.456738", "r"]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenLevel {
+    pub price: Number,
+    pub amount: Number,
+    pub time: DateTime<Utc>,
+    pub raw_price: String,
+    pub raw_amount: String,
+}
+
+impl From<&KrakenLevel> for Level {
+    fn from(level: &KrakenLevel) -> Self {
+        Self::new(level.price, level.amount)
+    }
+}
+
+/// [`Kraken`](super::super::Kraken) [`OrderBookUpdater`] that maintains an OrderBook Level2
+/// truncated to [`KRAKEN_ORDER_BOOK_L2_DEPTH`], and validates the exchange checksum after every
+/// delta.
+///
+/// ### Notes
+/// - No HTTP snapshot is required since [`Kraken`](super::super::Kraken) sends a snapshot as the
+///   first WebSocket message after subscribing.
+/// - Levels pushed beyond the subscribed depth are not explicitly removed by the exchange, so
+///   each side must be truncated after every delta.
+/// - A checksum mismatch returns a terminal [`DataError::InvalidChecksum`] so the
+///   [`MarketStream`](crate::MarketStream) is re-initialised.
+/// - The raw price & amount strings of each level are kept alongside the [`OrderBook`] to
+///   generate the checksum.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub struct KrakenBookUpdater {
+    pub depth: usize,
+    raw_bids: RawLevels,
+    raw_asks: RawLevels,
+}
+
+impl KrakenBookUpdater {
+    /// Construct a new [`Kraken`](super::super::Kraken) [`OrderBookUpdater`] that truncates the
+    /// local [`OrderBook`] to the provided depth.
+    pub fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            raw_bids: RawLevels::new(Side::Buy),
+            raw_asks: RawLevels::new(Side::Sell),
+        }
+    }
+
+    /// Generate the [`Kraken`](super::super::Kraken) CRC32 checksum of the top 10 asks (ascending)
+    /// followed by the top 10 bids (descending), with each price and amount having its decimal
+    /// point removed & leading zeros trimmed.
+    pub fn checksum(&self) -> u32 {
+        let mut input = String::new();
+        for level in self
+            .raw_asks
+            .levels()
+            .take(KRAKEN_CHECKSUM_DEPTH)
+            .chain(self.raw_bids.levels().take(KRAKEN_CHECKSUM_DEPTH))
+        {
+            for raw in [&level.price, &level.amount] {
+                let digits = raw.replace('.', "");
+                input.push_str(digits.trim_start_matches('0'));
+            }
+        }
+
+        crc32fast::hash(input.as_bytes())
+    }
+
+    /// Upsert the raw strings of the [`KrakenLevel`]s into one side of the [`RawLevels`] before
+    /// truncating it to the provided depth, returning the normalised [`Level`]s to apply to the
+    /// [`OrderBook`].
+    fn upsert(raw_levels: &mut RawLevels, depth: usize, levels: Vec<KrakenLevel>) -> Vec<Level> {
+        let levels = levels
+            .into_iter()
+            .map(|level| {
+                raw_levels.upsert(
+                    level.price,
+                    level.amount,
+                    RawLevel {
+                        price: level.raw_price,
+                        amount: level.raw_amount,
+                    },
+                );
+                Level::new(level.price, level.amount)
+            })
+            .collect();
+
+        raw_levels.truncate(depth);
+        levels
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for KrakenBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = KrakenOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        _: Option<usize>,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Initial OrderBook snapshot is sent as the first WebSocket message
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(KRAKEN_ORDER_BOOK_L2_DEPTH),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+}
+
+impl OrderBookDeltaUpdater for KrakenBookUpdater {
+    fn update_delta(
+        &mut self,
+        book: &mut OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookChange>, DataError> {
+        let update = match update {
+            KrakenOrderBookL2::Data(data) => data.update,
+            KrakenOrderBookL2::Event(_) => return Ok(None),
+        };
+
+        match update {
+            KrakenBookUpdate::Snapshot { bids, asks } => {
+                let last_update_time = bids
+                    .iter()
+                    .chain(asks.iter())
+                    .map(|level| level.time)
+                    .max()
+                    .unwrap_or_else(Utc::now);
+
+                self.raw_bids.clear();
+                self.raw_asks.clear();
+                let bids = Self::upsert(&mut self.raw_bids, self.depth, bids);
+                let asks = Self::upsert(&mut self.raw_asks, self.depth, asks);
+
+                *book = OrderBook {
+                    last_update_time,
+                    bids: OrderBookSide::new(Side::Buy, bids),
+                    asks: OrderBookSide::new(Side::Sell, asks),
+                };
+                book.truncate(self.depth);
+                Ok(Some(OrderBookChange::Snapshot))
+            }
+            KrakenBookUpdate::Delta {
+                bids,
+                asks,
+                checksum,
+            } => {
+                let last_update_time = bids
+                    .iter()
+                    .chain(asks.iter())
+                    .map(|level| level.time)
+                    .fold(book.last_update_time, DateTime::max);
+
+                let bids = Self::upsert(&mut self.raw_bids, self.depth, bids);
+                let asks = Self::upsert(&mut self.raw_asks, self.depth, asks);
+
+                let actual = self.checksum();
+                if actual != checksum {
+                    return Err(DataError::InvalidChecksum {
+                        expected: checksum,
+                        actual,
+                    });
+                }
+
+                let mut delta_bids = book.bids.upsert_delta(bids);
+                let mut delta_asks = book.asks.upsert_delta(asks);
+
+                // Levels pushed beyond the subscribed depth are removed from the OrderBook
+                delta_bids.extend(book.bids.truncate_delta(self.depth));
+                delta_asks.extend(book.asks.truncate_delta(self.depth));
+                book.last_update_time = last_update_time;
+
+                Ok(Some(OrderBookChange::Delta(OrderBookDelta {
+                    last_update_time,
+                    prev_sequence: None,
+                    sequence: None,
+                    bids: delta_bids,
+                    asks: delta_asks,
+                })))
+            }
+        }
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for KrakenOrderBookL2Inner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenOrderBookL2Inner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenOrderBookL2Inner struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenOrderBookL2Inner Sequence Format:
+                // [channelID, {payload}, ({payload},) channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-book>
+
+                // Extract deprecated channelID & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract first payload
+                let first: KrakenBookPayload = extract_next(&mut seq, "payload")?;
+
+                // Extract optional second payload, or channelName (eg/ "book-10") & ignore
+                let second = match extract_next(&mut seq, "payload or channelName")? {
+                    KrakenBookPayloadOrChannel::Payload(second) => {
+                        let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelName")?;
+                        second
+                    }
+                    KrakenBookPayloadOrChannel::Channel(_) => KrakenBookPayload::default(),
+                };
+
+                // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (ie/ "book|{pair}")
+                let subscription_id = extract_next::<SeqAccessor, String>(&mut seq, "pair")
+                    .map(|market| ExchangeSub::from((KrakenChannel::ORDER_BOOK_L2, market)).id())?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                let update = match (first.snapshot_bids, first.snapshot_asks) {
+                    (None, None) => {
+                        let checksum = second
+                            .checksum
+                            .or(first.checksum)
+                            .ok_or_else(|| serde::de::Error::missing_field("c"))?
+                            .parse()
+                            .map_err(serde::de::Error::custom)?;
+
+                        KrakenBookUpdate::Delta {
+                            bids: first.bids.into_iter().chain(second.bids).collect(),
+                            asks: first.asks.into_iter().chain(second.asks).collect(),
+                            checksum,
+                        }
+                    }
+                    (bids, asks) => KrakenBookUpdate::Snapshot {
+                        bids: bids.unwrap_or_default(),
+                        asks: asks.unwrap_or_default(),
+                    },
+                };
+
+                Ok(KrakenOrderBookL2Inner {
+                    subscription_id,
+                    update,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialize the KrakenOrderBookL2Inner
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for KrakenLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenLevel struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenLevel Sequence Format:
+                // [price, volume, timestamp, (updateType)]
+                // <https://docs.kraken.com/websockets/#message-book>
+
+                // Extract String price & parse to Number
+                let raw_price = extract_next::<SeqAccessor, String>(&mut seq, "price")?;
+                let price = raw_price.parse().map_err(serde::de::Error::custom)?;
+
+                // Extract String amount & parse to Number
+                let raw_amount = extract_next::<SeqAccessor, String>(&mut seq, "volume")?;
+                let amount = raw_amount.parse().map_err(serde::de::Error::custom)?;
+
+                // Extract String timestamp, parse to f64, map to DateTime<Utc>
+                let time = extract_next::<SeqAccessor, String>(&mut seq, "timestamp")?
+                    .parse()
+                    .map(|time| {
+                        datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(time))
+                    })
+                    .map_err(serde::de::Error::custom)?;
+
+                // Ignore any additional elements (eg/ "r" republish updateType)
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenLevel {
+                    price,
+                    amount,
+                    time,
+                    raw_price,
+                    raw_amount,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KrakenLevel
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    fn level(raw_price: &str, raw_amount: &str) -> KrakenLevel {
+        KrakenLevel {
+            price: raw_price.parse().unwrap(),
+            amount: raw_amount.parse().unwrap(),
+            time: datetime_utc_from_epoch_duration(std::time::Duration::from_secs(1534614248)),
+            raw_price: raw_price.to_string(),
+            raw_amount: raw_amount.to_string(),
+        }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kraken_message_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: KrakenOrderBookL2,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid KrakenOrderBookL2 snapshot
+                    input: r#"
+                    [
+                        0,
+                        {
+                            "as": [["5541.30000", "2.50700000", "1534614248"]],
+                            "bs": [["5541.20000", "1.52900000", "1534614248"]]
+                        },
+                        "book-10",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book|XBT/USD"),
+                        update: KrakenBookUpdate::Snapshot {
+                            bids: vec![level("5541.20000", "1.52900000")],
+                            asks: vec![level("5541.30000", "2.50700000")],
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: valid KrakenOrderBookL2 delta w/ one side
+                    input: r#"
+                    [
+                        1234,
+                        {"a": [["5541.30000", "2.50700000", "1534614248", "r"]], "c": "974942666"},
+                        "book-10",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book|XBT/USD"),
+                        update: KrakenBookUpdate::Delta {
+                            bids: vec![],
+                            asks: vec![level("5541.30000", "2.50700000")],
+                            checksum: 974942666,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC2: valid KrakenOrderBookL2 delta w/ both sides
+                    input: r#"
+                    [
+                        1234,
+                        {"a": [["5541.30000", "2.50700000", "1534614248"]]},
+                        {"b": [["5541.20000", "0.00000000", "1534614248"]], "c": "974942666"},
+                        "book-10",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book|XBT/USD"),
+                        update: KrakenBookUpdate::Delta {
+                            bids: vec![level("5541.20000", "0.00000000")],
+                            asks: vec![level("5541.30000", "2.50700000")],
+                            checksum: 974942666,
+                        },
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenOrderBookL2>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    fn updater(bids: Vec<KrakenLevel>, asks: Vec<KrakenLevel>) -> KrakenBookUpdater {
+        let mut updater = KrakenBookUpdater::new(KRAKEN_ORDER_BOOK_L2_DEPTH);
+        KrakenBookUpdater::upsert(&mut updater.raw_bids, updater.depth, bids);
+        KrakenBookUpdater::upsert(&mut updater.raw_asks, updater.depth, asks);
+        updater
+    }
+
+    #[test]
+    fn test_kraken_book_updater_checksum() {
+        // Example OrderBook from the Kraken checksum docs
+        // <https://docs.kraken.com/websockets/#book-checksum>
+        let updater = updater(
+            [
+                "0.05000", "0.04995", "0.04990", "0.04980", "0.04975", "0.04970", "0.04965",
+                "0.04960", "0.04955", "0.04950",
+            ]
+            .into_iter()
+            .map(|price| level(price, "0.00000500"))
+            .collect(),
+            [
+                "0.05005", "0.05010", "0.05015", "0.05020", "0.05025", "0.05030", "0.05035",
+                "0.05040", "0.05045", "0.05050",
+            ]
+            .into_iter()
+            .map(|price| level(price, "0.00000500"))
+            .collect(),
+        );
+
+        assert_eq!(updater.checksum(), 974947235);
+    }
+
+    #[test]
+    fn test_kraken_book_updater_update() {
+        struct TestCase {
+            update: KrakenBookUpdate,
+            expected_bids: Vec<Level>,
+            expected_asks: Vec<Level>,
+            expected_error: bool,
+        }
+
+        let snapshot = KrakenBookUpdate::Snapshot {
+            bids: vec![level("99.0", "1.0"), level("100.0", "1.0")],
+            asks: vec![level("102.0", "1.0"), level("101.0", "1.0")],
+        };
+
+        // Generate the checksum for the expected book after a delta
+        let checksum =
+            |bids: Vec<KrakenLevel>, asks: Vec<KrakenLevel>| updater(bids, asks).checksum();
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot is sorted
+                update: snapshot.clone(),
+                expected_bids: vec![
+                    Level::new(num!(100.0), num!(1.0)),
+                    Level::new(num!(99.0), num!(1.0)),
+                ],
+                expected_asks: vec![
+                    Level::new(num!(101.0), num!(1.0)),
+                    Level::new(num!(102.0), num!(1.0)),
+                ],
+                expected_error: false,
+            },
+            TestCase {
+                // TC1: delta w/ valid checksum upserts & removes levels
+                update: KrakenBookUpdate::Delta {
+                    bids: vec![level("99.0", "0.0"), level("100.0", "2.0")],
+                    asks: vec![level("100.5", "3.0")],
+                    checksum: checksum(
+                        vec![level("100.0", "2.0")],
+                        vec![
+                            level("100.5", "3.0"),
+                            level("101.0", "1.0"),
+                            level("102.0", "1.0"),
+                        ],
+                    ),
+                },
+                expected_bids: vec![Level::new(num!(100.0), num!(2.0))],
+                expected_asks: vec![
+                    Level::new(num!(100.5), num!(3.0)),
+                    Level::new(num!(101.0), num!(1.0)),
+                    Level::new(num!(102.0), num!(1.0)),
+                ],
+                expected_error: false,
+            },
+            TestCase {
+                // TC2: delta w/ invalid checksum returns terminal DataError
+                update: KrakenBookUpdate::Delta {
+                    bids: vec![level("100.0", "2.0")],
+                    asks: vec![],
+                    checksum: 0,
+                },
+                expected_bids: vec![],
+                expected_asks: vec![],
+                expected_error: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut updater = KrakenBookUpdater::new(KRAKEN_ORDER_BOOK_L2_DEPTH);
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            };
+
+            if let KrakenBookUpdate::Delta { .. } = test.update {
+                updater.update(&mut book, data(snapshot.clone())).unwrap();
+            }
+
+            match updater.update(&mut book, data(test.update)) {
+                Ok(Some(actual)) => {
+                    assert!(!test.expected_error, "TC{} failed", index);
+                    let expected = OrderBook {
+                        last_update_time: actual.last_update_time,
+                        bids: OrderBookSide::new(Side::Buy, test.expected_bids),
+                        asks: OrderBookSide::new(Side::Sell, test.expected_asks),
+                    };
+                    assert_eq!(actual, expected, "TC{} failed", index);
+                }
+                Err(error) => {
+                    assert!(test.expected_error, "TC{} failed", index);
+                    assert!(error.is_terminal(), "TC{} failed", index);
+                }
+                Ok(None) => panic!("TC{index} failed because no OrderBook was generated"),
+            }
+        }
+
+        fn data(update: KrakenBookUpdate) -> KrakenOrderBookL2 {
+            KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                subscription_id: SubscriptionId::from("book|XBT/USD"),
+                update,
+            })
+        }
+    }
+}
@@ -1,2 +1,5 @@
 /// Level 1 OrderBook types (top of book).
 pub mod l1;
+
+/// Level 2 OrderBook types.
+pub mod l2;
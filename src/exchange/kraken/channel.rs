@@ -1,6 +1,11 @@
 use super::Kraken;
 use crate::{
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        ticker::Tickers,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -22,6 +27,16 @@ impl KrakenChannel {
     ///
     /// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
     pub const ORDER_BOOK_L1: Self = Self("spread");
+
+    /// [`Kraken`] real-time OrderBook Level2 channel name.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
+    pub const ORDER_BOOK_L2: Self = Self("book");
+
+    /// [`Kraken`] rolling 24h ticker channel name.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
+    pub const TICKERS: Self = Self("ticker");
 }
 
 impl Identifier<KrakenChannel> for Subscription<Kraken, PublicTrades> {
@@ -36,6 +51,24 @@ impl Identifier<KrakenChannel> for Subscription<Kraken, OrderBooksL1> {
     }
 }
 
+impl Identifier<KrakenChannel> for Subscription<Kraken, OrderBooksL2> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<KrakenChannel> for Subscription<Kraken, OrderBooksL2Delta> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<KrakenChannel> for Subscription<Kraken, Tickers> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::TICKERS
+    }
+}
+
 impl AsRef<str> for KrakenChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,13 +1,25 @@
 use self::{
-    book::l1::KrakenOrderBookL1, channel::KrakenChannel, market::KrakenMarket,
-    message::KrakenMessage, subscription::KrakenSubResponse, trade::KrakenTrades,
+    book::{l1::KrakenOrderBookL1, l2::KrakenBookUpdater},
+    channel::KrakenChannel,
+    market::KrakenMarket,
+    message::KrakenMessage,
+    subscription::KrakenSubResponse,
+    ticker::KrakenTicker,
+    trade::KrakenTrades,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades},
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        ticker::Tickers,
+        trade::PublicTrades,
+    },
+    transformer::{
+        book::{MultiBookDeltaTransformer, MultiBookTransformer},
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, TimedExchangeWsStream,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use barter_macro::{DeExchange, SerExchange};
@@ -32,6 +44,9 @@ pub mod message;
 /// [`Validator`](barter_integration) for [`Kraken`].
 pub mod subscription;
 
+/// Rolling 24h ticker types for [`Kraken`].
+pub mod ticker;
+
 /// Public trade types for [`Kraken`].
 pub mod trade;
 
@@ -86,3 +101,21 @@ impl StreamSelector<PublicTrades> for Kraken {
 impl StreamSelector<OrderBooksL1> for Kraken {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, KrakenOrderBookL1>>;
 }
+
+impl StreamSelector<OrderBooksL2> for Kraken {
+    type Stream =
+        TimedExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, KrakenBookUpdater>>;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for Kraken {
+    type Stream =
+        ExchangeWsStream<MultiBookDeltaTransformer<Self, OrderBooksL2Delta, KrakenBookUpdater>>;
+}
+
+impl StreamSelector<Tickers> for Kraken {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Tickers, KrakenTicker>>;
+}
@@ -0,0 +1,266 @@
+use super::KrakenMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{kraken::channel::KrakenChannel, subscription::ExchangeSub, ExchangeId},
+    number::Number,
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_integration::{
+    de::extract_next,
+    model::{instrument::Instrument, Exchange, SubscriptionId},
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Kraken`](super::Kraken) rolling 24h ticker WebSocket message.
+pub type KrakenTicker = KrakenMessage<KrakenTickerInner>;
+
+/// [`Kraken`](super::Kraken) rolling 24h ticker data and the associated [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-ticker>
+/// ```json
+/// [
+///     0,
+///     {
+///         "a": ["5525.40000", 1, "1.000"],
+///         "b": ["5525.10000", 1, "1.000"],
+///         "c": ["5525.10000", "0.00398963"],
+///         "v": ["2634.11501494", "3591.17907851"],
+///         "p": ["5631.44067", "5653.78939"],
+///         "t": [11493, 16267],
+///         "l": ["5505.00000", "5505.00000"],
+///         "h": ["5783.00000", "5783.00000"],
+///         "o": ["5760.70000", "5763.40000"]
+///     },
+///     "ticker",
+///     "XBT/USD"
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenTickerInner {
+    pub subscription_id: SubscriptionId,
+    pub ticker: KrakenTickerStats,
+}
+
+/// [`Kraken`](super::Kraken) rolling 24h ticker statistics.
+///
+/// See [`KrakenTickerInner`] for full raw payload examples.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-ticker>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenTickerStats {
+    #[serde(alias = "c", deserialize_with = "de_today")]
+    pub last: Number,
+    #[serde(alias = "o", deserialize_with = "de_last_24h")]
+    pub open: Number,
+    #[serde(alias = "h", deserialize_with = "de_last_24h")]
+    pub high: Number,
+    #[serde(alias = "l", deserialize_with = "de_last_24h")]
+    pub low: Number,
+    #[serde(alias = "v", deserialize_with = "de_last_24h")]
+    pub volume: Number,
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenTickerInner {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, KrakenTicker)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, KrakenTicker)) -> Self {
+        match ticker {
+            KrakenTicker::Data(ticker) => {
+                // Kraken tickers do not contain a timestamp, so use the received time
+                let time = Utc::now();
+
+                Self(vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    exchange: Exchange::from(exchange_id),
+                    instrument,
+                    kind: Ticker {
+                        last: ticker.ticker.last,
+                        open: Some(ticker.ticker.open),
+                        high: ticker.ticker.high,
+                        low: ticker.ticker.low,
+                        volume_base: ticker.ticker.volume,
+                        volume_quote: None,
+                        change_percent: Ticker::change_percent(
+                            ticker.ticker.open,
+                            ticker.ticker.last,
+                        ),
+                        time,
+                    },
+                })])
+            }
+            KrakenTicker::Event(_) => MarketIter(vec![]),
+        }
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for KrakenTickerInner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenTickerInner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenTickerInner struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenTickerInner Sequence Format:
+                // [channelID, {a, b, c, v, p, t, l, h, o}, channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-ticker>
+
+                // Extract deprecated channelID & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract ticker statistics
+                let ticker = extract_next(&mut seq, "ticker")?;
+
+                // Extract channelName (eg/ "ticker") & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelName")?;
+
+                // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (ie/ "ticker|{pair}")
+                let subscription_id = extract_next::<SeqAccessor, String>(&mut seq, "pair")
+                    .map(|market| ExchangeSub::from((KrakenChannel::TICKERS, market)).id())?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenTickerInner {
+                    subscription_id,
+                    ticker,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialize the KrakenTickerInner
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// Deserialize the first ("today") value of a [`Kraken`](super::Kraken) ticker array
+/// (eg/ ["5525.10000", "0.00398963"]) as a [`Number`].
+fn de_today<'de, D>(deserializer: D) -> Result<Number, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let (today, _) = <(String, serde::de::IgnoredAny)>::deserialize(deserializer)?;
+    today.parse().map_err(serde::de::Error::custom)
+}
+
+/// Deserialize the second ("last 24 hours") value of a [`Kraken`](super::Kraken) ticker array
+/// (eg/ ["2634.11501494", "3591.17907851"]) as a [`Number`].
+fn de_last_24h<'de, D>(deserializer: D) -> Result<Number, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let (_, last_24h) = <(serde::de::IgnoredAny, String)>::deserialize(deserializer)?;
+    last_24h.parse().map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_kraken_message_ticker() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenTicker, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid KrakenTicker::Data(KrakenTickerInner)
+                    input: r#"
+                    [
+                        0,
+                        {
+                            "a": ["5525.40000", 1, "1.000"],
+                            "b": ["5525.10000", 1, "1.000"],
+                            "c": ["5525.10000", "0.00398963"],
+                            "v": ["2634.11501494", "3591.17907851"],
+                            "p": ["5631.44067", "5653.78939"],
+                            "t": [11493, 16267],
+                            "l": ["5505.00000", "5504.00000"],
+                            "h": ["5783.00000", "5784.00000"],
+                            "o": ["5760.70000", "5763.40000"]
+                        },
+                        "ticker",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Ok(KrakenTicker::Data(KrakenTickerInner {
+                        subscription_id: SubscriptionId::from("ticker|XBT/USD"),
+                        ticker: KrakenTickerStats {
+                            last: num!(5525.1),
+                            open: num!(5763.4),
+                            high: num!(5784.0),
+                            low: num!(5504.0),
+                            volume: num!(3591.17907851),
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: invalid KrakenTicker w/ non-numeric last price
+                    input: r#"
+                    [
+                        0,
+                        {
+                            "c": ["invalid", "0.00398963"],
+                            "v": ["2634.11501494", "3591.17907851"],
+                            "l": ["5505.00000", "5504.00000"],
+                            "h": ["5783.00000", "5784.00000"],
+                            "o": ["5760.70000", "5763.40000"]
+                        },
+                        "ticker",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenTicker>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
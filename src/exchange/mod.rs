@@ -62,6 +62,17 @@ where
     Kind: SubKind,
 {
     type Stream: MarketStream<Self, Kind>;
+
+    /// Validate the exchange supports the configuration of the provided [`SubKind`]
+    /// (eg/ the [`CandleInterval`](crate::subscription::candle::CandleInterval) of
+    /// [`Candles`](crate::subscription::candle::Candles)).
+    ///
+    /// Returns the unsupported configuration as an `Err` description, which is surfaced as a
+    /// [`SocketError::Unsupported`] by [`Subscription`](crate::subscription::Subscription)
+    /// validation. Defaults to `Ok(())` since most [`SubKind`]s carry no configuration.
+    fn validate_kind(_: &Kind) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 /// Primary exchange abstraction. Defines how to translate Barter types into exchange specific
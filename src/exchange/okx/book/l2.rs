@@ -0,0 +1,568 @@
+use crate::{
+    error::DataError,
+    number::Number,
+    subscription::book::{Level, OrderBook, OrderBookDelta, OrderBookSide},
+    transformer::book::{
+        InstrumentOrderBook, OrderBookChange, OrderBookDeltaUpdater, OrderBookUpdater, RawLevel,
+        RawLevels,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// Number of [`Level`]s on each side of the [`OrderBook`] used to generate the
+/// [`Okx`](super::super::Okx) OrderBook checksum.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+const OKX_CHECKSUM_DEPTH: usize = 25;
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 snapshot or update WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+/// ```json
+/// {
+///     "arg": {
+///         "channel": "books",
+///         "instId": "BTC-USDT"
+///     },
+///     "action": "snapshot",
+///     "data": [
+///         {
+///             "asks": [["8476.98", "415", "0", "13"]],
+///             "bids": [["8476.97", "256", "0", "12"]],
+///             "ts": "1597026383085",
+///             "checksum": -855196043,
+///             "prevSeqId": -1,
+///             "seqId": 123456
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookL2 {
+    #[serde(
+        rename = "arg",
+        deserialize_with = "super::super::trade::de_okx_message_arg_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub action: OkxBookAction,
+    pub data: Vec<OkxBookData>,
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 message action.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OkxBookAction {
+    Snapshot,
+    Update,
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 snapshot or update data.
+///
+/// See [`OkxOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxBookData {
+    pub bids: Vec<OkxLevel>,
+    pub asks: Vec<OkxLevel>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub checksum: i32,
+    pub prev_seq_id: i64,
+    pub seq_id: i64,
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 [`Level`].
+///
+/// The raw price and amount strings are retained since they are required to generate the
+/// [`Okx`](super::super::Okx) OrderBook checksum.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// ["8476.98", "415", "0", "13"]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct OkxLevel {
+    pub price: Number,
+    pub amount: Number,
+    pub raw_price: String,
+    pub raw_amount: String,
+}
+
+impl From<&OkxLevel> for Level {
+    fn from(level: &OkxLevel) -> Self {
+        Self::new(level.price, level.amount)
+    }
+}
+
+/// [`Okx`](super::super::Okx) [`OrderBookUpdater`] that maintains an OrderBook Level2 from the
+/// "books" channel, validating `seqId` continuity and the exchange checksum after every message.
+///
+/// ### Notes
+/// - No HTTP snapshot is required since [`Okx`](super::super::Okx) sends a snapshot as the
+///   first WebSocket message after subscribing.
+/// - Each update's `prevSeqId` must equal the previous message `seqId`, otherwise a terminal
+///   [`DataError::InvalidSequence`] is returned.
+/// - A checksum mismatch returns a terminal [`DataError::InvalidChecksum`].
+/// - The raw price & amount strings of each level are kept alongside the [`OrderBook`] to
+///   generate the checksum.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub struct OkxBookUpdater {
+    pub last_seq_id: Option<i64>,
+    raw_bids: RawLevels,
+    raw_asks: RawLevels,
+}
+
+impl OkxBookUpdater {
+    /// Construct a new [`Okx`](super::super::Okx) [`OrderBookUpdater`] awaiting it's initial
+    /// snapshot.
+    pub fn new() -> Self {
+        Self {
+            last_seq_id: None,
+            raw_bids: RawLevels::new(Side::Buy),
+            raw_asks: RawLevels::new(Side::Sell),
+        }
+    }
+
+    /// Generate the [`Okx`](super::super::Okx) checksum input by interleaving the top 25 bids and
+    /// asks as "bidPrice:bidSize:askPrice:askSize:...".
+    pub fn checksum_input(&self) -> String {
+        let (mut bids, mut asks) = (self.raw_bids.levels(), self.raw_asks.levels());
+
+        let mut fields = Vec::with_capacity(OKX_CHECKSUM_DEPTH * 4);
+        for _ in 0..OKX_CHECKSUM_DEPTH {
+            for level in [bids.next(), asks.next()].into_iter().flatten() {
+                fields.push(level.price.as_str());
+                fields.push(level.amount.as_str());
+            }
+        }
+
+        fields.join(":")
+    }
+
+    /// Generate the [`Okx`](super::super::Okx) signed CRC32 checksum of the local OrderBook.
+    pub fn checksum(&self) -> i32 {
+        crc32fast::hash(self.checksum_input().as_bytes()) as i32
+    }
+
+    /// Upsert the raw strings of the [`OkxLevel`]s into one side of the [`RawLevels`], returning
+    /// the normalised [`Level`]s to apply to the [`OrderBook`].
+    fn upsert(raw_levels: &mut RawLevels, levels: Vec<OkxLevel>) -> Vec<Level> {
+        levels
+            .into_iter()
+            .map(|level| {
+                raw_levels.upsert(
+                    level.price,
+                    level.amount,
+                    RawLevel {
+                        price: level.raw_price,
+                        amount: level.raw_amount,
+                    },
+                );
+                Level::new(level.price, level.amount)
+            })
+            .collect()
+    }
+}
+
+impl Default for OkxBookUpdater {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for OkxBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = OkxOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        _: Option<usize>,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Initial OrderBook snapshot is sent as the first WebSocket message
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+}
+
+impl OrderBookDeltaUpdater for OkxBookUpdater {
+    fn update_delta(
+        &mut self,
+        book: &mut OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookChange>, DataError> {
+        let mut change = None;
+
+        for data in update.data {
+            match update.action {
+                OkxBookAction::Snapshot => {
+                    self.raw_bids.clear();
+                    self.raw_asks.clear();
+                }
+                // Validate the update follows on from the last processed seqId
+                OkxBookAction::Update if self.last_seq_id != Some(data.prev_seq_id) => {
+                    return Err(DataError::InvalidSequence {
+                        prev_last_update_id: self.last_seq_id.unwrap_or_default() as u64,
+                        first_update_id: data.prev_seq_id as u64,
+                    });
+                }
+                OkxBookAction::Update => {}
+            }
+
+            let bids = Self::upsert(&mut self.raw_bids, data.bids);
+            let asks = Self::upsert(&mut self.raw_asks, data.asks);
+
+            let actual = self.checksum();
+            if actual != data.checksum {
+                return Err(DataError::InvalidChecksum {
+                    expected: data.checksum as u32,
+                    actual: actual as u32,
+                });
+            }
+
+            let prev_sequence = self.last_seq_id.map(|seq_id| seq_id as u64);
+            self.last_seq_id = Some(data.seq_id);
+
+            // Replace the OrderBook with the snapshot Levels
+            if update.action == OkxBookAction::Snapshot {
+                *book = OrderBook {
+                    last_update_time: data.time,
+                    bids: OrderBookSide::new(Side::Buy, bids),
+                    asks: OrderBookSide::new(Side::Sell, asks),
+                };
+                change = Some(OrderBookChange::Snapshot);
+                continue;
+            }
+
+            book.last_update_time = data.time;
+            let bids = book.bids.upsert_delta(bids);
+            let asks = book.asks.upsert_delta(asks);
+
+            change = match change {
+                Some(OrderBookChange::Snapshot) => Some(OrderBookChange::Snapshot),
+                Some(OrderBookChange::Delta(mut delta)) => {
+                    delta.last_update_time = data.time;
+                    delta.sequence = Some(data.seq_id as u64);
+                    delta.bids.extend(bids);
+                    delta.asks.extend(asks);
+                    Some(OrderBookChange::Delta(delta))
+                }
+                None => Some(OrderBookChange::Delta(OrderBookDelta {
+                    last_update_time: data.time,
+                    prev_sequence,
+                    sequence: Some(data.seq_id as u64),
+                    bids,
+                    asks,
+                })),
+            };
+        }
+
+        Ok(change)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for OkxLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = OkxLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("OkxLevel struct from the Okx WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // OkxLevel Sequence Format:
+                // [price, size, deprecated, numOrders]
+                // <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+
+                // Extract String price & parse to Number
+                let raw_price = extract_next::<SeqAccessor, String>(&mut seq, "price")?;
+                let price = raw_price.parse().map_err(serde::de::Error::custom)?;
+
+                // Extract String amount & parse to Number
+                let raw_amount = extract_next::<SeqAccessor, String>(&mut seq, "size")?;
+                let amount = raw_amount.parse().map_err(serde::de::Error::custom)?;
+
+                // Ignore any additional elements or SerDe will fail
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(OkxLevel {
+                    price,
+                    amount,
+                    raw_price,
+                    raw_amount,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the OkxLevel
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    fn level(raw_price: &str, raw_amount: &str) -> OkxLevel {
+        OkxLevel {
+            price: raw_price.parse().unwrap(),
+            amount: raw_amount.parse().unwrap(),
+            raw_price: raw_price.to_string(),
+            raw_amount: raw_amount.to_string(),
+        }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_order_book_l2() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "books",
+                    "instId": "BTC-USDT"
+                },
+                "action": "snapshot",
+                "data": [
+                    {
+                        "asks": [["8476.98", "415", "0", "13"]],
+                        "bids": [["8476.97", "256", "0", "12"]],
+                        "ts": "1597026383085",
+                        "checksum": -855196043,
+                        "prevSeqId": -1,
+                        "seqId": 123456
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxOrderBookL2>(input).unwrap(),
+                OkxOrderBookL2 {
+                    subscription_id: SubscriptionId::from("books|BTC-USDT"),
+                    action: OkxBookAction::Snapshot,
+                    data: vec![OkxBookData {
+                        bids: vec![level("8476.97", "256")],
+                        asks: vec![level("8476.98", "415")],
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1597026383085
+                        )),
+                        checksum: -855196043,
+                        prev_seq_id: -1,
+                        seq_id: 123456,
+                    }],
+                }
+            );
+        }
+    }
+
+    fn updater(bids: Vec<OkxLevel>, asks: Vec<OkxLevel>) -> OkxBookUpdater {
+        let mut updater = OkxBookUpdater::new();
+        OkxBookUpdater::upsert(&mut updater.raw_bids, bids);
+        OkxBookUpdater::upsert(&mut updater.raw_asks, asks);
+        updater
+    }
+
+    #[test]
+    fn test_okx_book_updater_checksum_input() {
+        // Example OrderBook from the Okx checksum docs
+        let updater = updater(
+            vec![level("3366.1", "7"), level("3366", "6")],
+            vec![level("3366.8", "9"), level("3368", "8"), level("3372", "8")],
+        );
+
+        assert_eq!(
+            updater.checksum_input(),
+            "3366.1:7:3366.8:9:3366:6:3368:8:3372:8"
+        );
+    }
+
+    #[test]
+    fn test_okx_book_updater_update() {
+        struct TestCase {
+            action: OkxBookAction,
+            bids: Vec<OkxLevel>,
+            asks: Vec<OkxLevel>,
+            prev_seq_id: i64,
+            expected: Result<(Vec<Level>, Vec<Level>), ()>,
+        }
+
+        // Generate the checksum for the expected book after an update
+        let checksum = |bids: Vec<OkxLevel>, asks: Vec<OkxLevel>| updater(bids, asks).checksum();
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot is applied
+                action: OkxBookAction::Snapshot,
+                bids: vec![level("100", "1"), level("99", "1")],
+                asks: vec![level("101", "1")],
+                prev_seq_id: -1,
+                expected: Ok((
+                    vec![
+                        Level::new(num!(100), num!(1)),
+                        Level::new(num!(99), num!(1)),
+                    ],
+                    vec![Level::new(num!(101), num!(1))],
+                )),
+            },
+            TestCase {
+                // TC1: update w/ valid sequence & checksum upserts & removes levels
+                action: OkxBookAction::Update,
+                bids: vec![level("100", "0"), level("99.5", "2")],
+                asks: vec![level("100.5", "3")],
+                prev_seq_id: 1,
+                expected: Ok((
+                    vec![
+                        Level::new(num!(99.5), num!(2)),
+                        Level::new(num!(99), num!(1)),
+                    ],
+                    vec![
+                        Level::new(num!(100.5), num!(3)),
+                        Level::new(num!(101), num!(1)),
+                    ],
+                )),
+            },
+            TestCase {
+                // TC2: update w/ sequence gap returns terminal DataError
+                action: OkxBookAction::Update,
+                bids: vec![],
+                asks: vec![],
+                prev_seq_id: 5,
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut updater = OkxBookUpdater::new();
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            };
+
+            // Apply initial snapshot w/ seqId 1 for update TestCases
+            if test.action == OkxBookAction::Update {
+                let bids = vec![level("100", "1"), level("99", "1")];
+                let asks = vec![level("101", "1")];
+                let snapshot = message(
+                    OkxBookAction::Snapshot,
+                    bids.clone(),
+                    asks.clone(),
+                    -1,
+                    checksum(bids, asks),
+                );
+                updater.update(&mut book, snapshot).unwrap();
+            }
+
+            let expected_checksum = match &test.expected {
+                Ok((bids, asks)) => checksum(
+                    bids.iter()
+                        .map(|l| level(&l.price.to_string(), &l.amount.to_string()))
+                        .collect(),
+                    asks.iter()
+                        .map(|l| level(&l.price.to_string(), &l.amount.to_string()))
+                        .collect(),
+                ),
+                Err(_) => 0,
+            };
+            let update = message(
+                test.action,
+                test.bids,
+                test.asks,
+                test.prev_seq_id,
+                expected_checksum,
+            );
+
+            match (updater.update(&mut book, update), test.expected) {
+                (Ok(Some(actual)), Ok((bids, asks))) => {
+                    assert_eq!(
+                        actual.bids,
+                        OrderBookSide::new(Side::Buy, bids),
+                        "TC{} failed",
+                        index
+                    );
+                    assert_eq!(
+                        actual.asks,
+                        OrderBookSide::new(Side::Sell, asks),
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (Err(error), Err(_)) => assert!(error.is_terminal(), "TC{} failed", index),
+                (actual, expected) => {
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+
+        fn message(
+            action: OkxBookAction,
+            bids: Vec<OkxLevel>,
+            asks: Vec<OkxLevel>,
+            prev_seq_id: i64,
+            checksum: i32,
+        ) -> OkxOrderBookL2 {
+            OkxOrderBookL2 {
+                subscription_id: SubscriptionId::from("books|BTC-USDT"),
+                action,
+                data: vec![OkxBookData {
+                    bids,
+                    asks,
+                    time: Utc::now(),
+                    checksum,
+                    prev_seq_id,
+                    seq_id: 1,
+                }],
+            }
+        }
+    }
+}
@@ -1,6 +1,15 @@
 use super::Okx;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        funding::FundingRates,
+        liquidation::Liquidations,
+        mark_price::MarkPrices,
+        open_interest::OpenInterests,
+        ticker::Tickers,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +26,43 @@ impl OkxChannel {
     ///
     /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Okx`] real-time OrderBook Level2 channel (400 depth snapshot followed by incremental
+    /// updates).
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+    pub const ORDER_BOOK_L2: Self = Self("books");
+
+    /// [`Okx`] real-time perpetual swap funding rate channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-funding-rate-channel>
+    pub const FUNDING_RATE: Self = Self("funding-rate");
+
+    /// [`Okx`] real-time mark price channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-channel>
+    pub const MARK_PRICE: Self = Self("mark-price");
+
+    /// [`Okx`] real-time index tickers channel, subscribed to alongside every
+    /// [`OkxChannel::MARK_PRICE`] subscription.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-index-tickers-channel>
+    pub const INDEX_TICKERS: Self = Self("index-tickers");
+
+    /// [`Okx`] real-time open interest channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-open-interest-channel>
+    pub const OPEN_INTEREST: Self = Self("open-interest");
+
+    /// [`Okx`] real-time rolling 24h tickers channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-tickers-channel>
+    pub const TICKERS: Self = Self("tickers");
+
+    /// [`Okx`] real-time liquidation orders channel, subscribed to per instrument type.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+    pub const LIQUIDATIONS: Self = Self("liquidation-orders");
 }
 
 impl Identifier<OkxChannel> for Subscription<Okx, PublicTrades> {
@@ -25,6 +71,62 @@ impl Identifier<OkxChannel> for Subscription<Okx, PublicTrades> {
     }
 }
 
+/// [`OrderBooksL1`] are synthesised from the checksum validated OrderBook Level2 channel (see
+/// [`OrderBookL1Transformer`](crate::transformer::book::OrderBookL1Transformer)) rather than the
+/// native `bbo-tbt` or `books5` channels, so every [`OrderBookL1`](crate::subscription::book::OrderBookL1)
+/// is consistent with the maintained [`OrderBook`](crate::subscription::book::OrderBook).
+impl Identifier<OkxChannel> for Subscription<Okx, OrderBooksL1> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, OrderBooksL2> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, OrderBooksL2Delta> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, FundingRates> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::FUNDING_RATE
+    }
+}
+
+/// [`MarkPrices`] also subscribe to the associated [`OkxChannel::INDEX_TICKERS`] (see
+/// [`OkxMarkPriceTransformer`](super::mark_price::OkxMarkPriceTransformer)).
+impl Identifier<OkxChannel> for Subscription<Okx, MarkPrices> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::MARK_PRICE
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, OpenInterests> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::OPEN_INTEREST
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, Tickers> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::TICKERS
+    }
+}
+
+/// [`Liquidations`] are subscribed to per instrument type (see
+/// [`OkxLiquidationTransformer`](super::liquidation::OkxLiquidationTransformer)).
+impl Identifier<OkxChannel> for Subscription<Okx, Liquidations> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::LIQUIDATIONS
+    }
+}
+
 impl AsRef<str> for OkxChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -0,0 +1,186 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    number::Number,
+    subscription::funding::FundingRate,
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time funding rate WebSocket message.
+pub type OkxFundingRates = OkxMessage<OkxFundingRate>;
+
+/// [`Okx`](super::Okx) real-time funding rate WebSocket message.
+///
+/// ### Notes
+/// `nextFundingRate` is an empty string when the exchange does not publish a predicted rate
+/// (eg/ when the "current_period" funding method is used).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-funding-rate-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "funding-rate",
+///     "instId": "BTC-USDT-SWAP"
+///   },
+///   "data": [
+///     {
+///       "fundingRate": "0.0001875391284828",
+///       "fundingTime": "1700726400000",
+///       "instId": "BTC-USDT-SWAP",
+///       "instType": "SWAP",
+///       "method": "current_period",
+///       "nextFundingRate": "",
+///       "nextFundingTime": "1700755200000",
+///       "ts": "1700724675402"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxFundingRate {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub funding_rate: Number,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub next_funding_rate: Option<Number>,
+    #[serde(deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc")]
+    pub funding_time: DateTime<Utc>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, OkxFundingRates)> for MarketIter<FundingRate> {
+    fn from(
+        (exchange_id, instrument, funding_rates): (ExchangeId, Instrument, OkxFundingRates),
+    ) -> Self {
+        funding_rates
+            .data
+            .into_iter()
+            .map(|funding| MarketEvent {
+                exchange_time: funding.time,
+                received_time: Utc::now(),
+                exchange: Exchange::from(exchange_id),
+                instrument: instrument.clone(),
+                kind: FundingRate {
+                    rate: funding.funding_rate,
+                    predicted_rate: funding.next_funding_rate,
+                    next_funding_time: Some(funding.funding_time),
+                    mark_price: None,
+                },
+            })
+            .map(Ok)
+            .collect()
+    }
+}
+
+/// Deserialize an optional, possibly empty, `String` as an optional [`Number`].
+fn de_option_str<'de, D>(deserializer: D) -> Result<Option<Number>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_message_funding_rate() {
+            struct TestCase {
+                input: &'static str,
+                expected: OkxFundingRates,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input with empty nextFundingRate is deserialised
+                    input: r#"
+                    {
+                        "arg": {"channel": "funding-rate", "instId": "BTC-USDT-SWAP"},
+                        "data": [
+                            {
+                                "fundingRate": "0.0001875391284828",
+                                "fundingTime": "1700726400000",
+                                "instId": "BTC-USDT-SWAP",
+                                "instType": "SWAP",
+                                "method": "current_period",
+                                "nextFundingRate": "",
+                                "nextFundingTime": "1700755200000",
+                                "ts": "1700724675402"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: OkxFundingRates {
+                        subscription_id: SubscriptionId::from("funding-rate|BTC-USDT-SWAP"),
+                        data: vec![OkxFundingRate {
+                            funding_rate: num!(0.0001875391284828),
+                            next_funding_rate: None,
+                            funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1700726400000,
+                            )),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1700724675402,
+                            )),
+                        }],
+                    },
+                },
+                TestCase {
+                    // TC1: input with predicted nextFundingRate is deserialised
+                    input: r#"
+                    {
+                        "arg": {"channel": "funding-rate", "instId": "BTC-USD-SWAP"},
+                        "data": [
+                            {
+                                "fundingRate": "0.0001",
+                                "fundingTime": "1700726400000",
+                                "instId": "BTC-USD-SWAP",
+                                "instType": "SWAP",
+                                "method": "next_period",
+                                "nextFundingRate": "0.00015",
+                                "nextFundingTime": "1700755200000",
+                                "ts": "1700724675402"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: OkxFundingRates {
+                        subscription_id: SubscriptionId::from("funding-rate|BTC-USD-SWAP"),
+                        data: vec![OkxFundingRate {
+                            funding_rate: num!(0.0001),
+                            next_funding_rate: Some(num!(0.00015)),
+                            funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1700726400000,
+                            )),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1700724675402,
+                            )),
+                        }],
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxFundingRates>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use super::{channel::OkxChannel, market::OkxMarket, Okx};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{Connector, ExchangeSub},
+    number::Number,
+    subscription::{
+        liquidation::{Liquidation, Liquidations},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Exchange, Side},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Okx`] real-time liquidation orders WebSocket message.
+///
+/// ### Notes
+/// The "liquidation-orders" channel is subscribed to per instrument type (eg/ "SWAP") rather than
+/// per instrument, so a message may contain liquidations for any market of that type.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "liquidation-orders",
+///     "instType": "SWAP"
+///   },
+///   "data": [
+///     {
+///       "details": [
+///         {
+///           "bkLoss": "0",
+///           "bkPx": "0.007831",
+///           "ccy": "",
+///           "posSide": "short",
+///           "side": "buy",
+///           "sz": "13",
+///           "ts": "1692266434010"
+///         }
+///       ],
+///       "instFamily": "IOST-USDT",
+///       "instId": "IOST-USDT-SWAP",
+///       "instType": "SWAP",
+///       "uly": "IOST-USDT"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidations {
+    pub data: Vec<OkxLiquidation>,
+}
+
+/// [`Okx`] liquidation orders associated with a single market.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidation {
+    #[serde(rename = "instId")]
+    pub market: String,
+    pub details: Vec<OkxLiquidationDetail>,
+}
+
+/// [`Okx`] liquidation order.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidationDetail {
+    pub side: Side,
+    #[serde(rename = "bkPx", deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: Number,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`] [`Liquidations`] [`ExchangeTransformer`] that routes each liquidation to the
+/// subscribed [`Instrument`] it is associated with.
+///
+/// ### Notes
+/// Liquidations of markets that were not subscribed to are received since the channel is
+/// subscribed to per instrument type, and are silently ignored.
+#[derive(Clone, PartialEq, Debug)]
+pub struct OkxLiquidationTransformer {
+    instrument_map: Map<Instrument>,
+}
+
+#[async_trait]
+impl ExchangeTransformer<Okx, Liquidations> for OkxLiquidationTransformer {
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+        _: Map<Liquidations>,
+    ) -> Result<Self, DataError> {
+        Ok(Self { instrument_map })
+    }
+}
+
+impl Transformer for OkxLiquidationTransformer {
+    type Error = DataError;
+    type Input = OkxLiquidations;
+    type Output = MarketEvent<Liquidation>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        input
+            .data
+            .into_iter()
+            .filter_map(|liquidation| {
+                let subscription_id =
+                    ExchangeSub::from((OkxChannel::LIQUIDATIONS, OkxMarket(liquidation.market)))
+                        .id();
+
+                self.instrument_map
+                    .find(&subscription_id)
+                    .ok()
+                    .map(|instrument| (instrument, liquidation.details))
+            })
+            .flat_map(|(instrument, details)| {
+                details.into_iter().map(move |detail| MarketEvent {
+                    exchange_time: detail.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(Okx::ID),
+                    instrument: instrument.clone(),
+                    kind: Liquidation {
+                        side: detail.side,
+                        price: detail.price,
+                        quantity: detail.quantity,
+                        time: detail.time,
+                        average_price: None,
+                        filled_quantity: None,
+                        status: None,
+                    },
+                })
+            })
+            .map(Ok)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration,
+        model::{instrument::kind::InstrumentKind, SubscriptionId},
+    };
+    use std::{collections::HashMap, time::Duration};
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_liquidations() {
+            struct TestCase {
+                input: &'static str,
+                expected: OkxLiquidations,
+            }
+
+            let tests = vec![TestCase {
+                // TC0: input OkxLiquidations is deserialised
+                input: r#"
+                {
+                    "arg": {"channel": "liquidation-orders", "instType": "SWAP"},
+                    "data": [
+                        {
+                            "details": [
+                                {
+                                    "bkLoss": "0",
+                                    "bkPx": "0.007831",
+                                    "ccy": "",
+                                    "posSide": "short",
+                                    "side": "buy",
+                                    "sz": "13",
+                                    "ts": "1692266434010"
+                                }
+                            ],
+                            "instFamily": "IOST-USDT",
+                            "instId": "IOST-USDT-SWAP",
+                            "instType": "SWAP",
+                            "uly": "IOST-USDT"
+                        }
+                    ]
+                }
+                "#,
+                expected: OkxLiquidations {
+                    data: vec![OkxLiquidation {
+                        market: "IOST-USDT-SWAP".to_string(),
+                        details: vec![OkxLiquidationDetail {
+                            side: Side::Buy,
+                            price: num!(0.007831),
+                            quantity: num!(13.0),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1692266434010,
+                            )),
+                        }],
+                    }],
+                },
+            }];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxLiquidations>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_liquidation_transformer() {
+        struct TestCase {
+            input: OkxLiquidations,
+            expected: Vec<Instrument>,
+        }
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let mut transformer = OkxLiquidationTransformer {
+            instrument_map: Map(HashMap::from([(
+                SubscriptionId::from("liquidation-orders|BTC-USDT-SWAP"),
+                instrument.clone(),
+            )])),
+        };
+
+        let detail = OkxLiquidationDetail {
+            side: Side::Sell,
+            price: num!(26000.0),
+            quantity: num!(2.0),
+            time: datetime_utc_from_epoch_duration(Duration::from_millis(1692266434010)),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: liquidations of a subscribed market are yielded
+                input: OkxLiquidations {
+                    data: vec![OkxLiquidation {
+                        market: "BTC-USDT-SWAP".to_string(),
+                        details: vec![detail, detail],
+                    }],
+                },
+                expected: vec![instrument.clone(), instrument.clone()],
+            },
+            TestCase {
+                // TC1: liquidations of an unsubscribed market are ignored
+                input: OkxLiquidations {
+                    data: vec![
+                        OkxLiquidation {
+                            market: "ETH-USDT-SWAP".to_string(),
+                            details: vec![detail],
+                        },
+                        OkxLiquidation {
+                            market: "BTC-USDT-SWAP".to_string(),
+                            details: vec![detail],
+                        },
+                    ],
+                },
+                expected: vec![instrument],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.unwrap().instrument)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use super::{
+    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxMessage, Okx,
+};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{Connector, ExchangeSub},
+    number::Number,
+    subscription::{
+        mark_price::{MarkPrice, MarkPrices},
+        Map, Subscription,
+    },
+    transformer::ExchangeTransformer,
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Exchange, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// Terse type alias for an [`Okx`] real-time mark price WebSocket message.
+pub type OkxMarkPrices = OkxMessage<OkxMarkPrice>;
+
+/// Terse type alias for an [`Okx`] real-time index tickers WebSocket message.
+pub type OkxIndexTickers = OkxMessage<OkxIndexTicker>;
+
+/// [`Okx`] WebSocket message received on a [`MarkPrices`] connection.
+///
+/// ### Notes
+/// Every [`MarkPrices`] subscription also subscribes to the associated "index-tickers" channel
+/// (see [`Okx::requests`](crate::exchange::Connector::requests)), so the surplus subscription
+/// [`OkxSubResponse`]s may also be received after the subscriptions have been validated.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum OkxMarkPriceMessage {
+    MarkPrice(OkxMarkPrices),
+    IndexTicker(OkxIndexTickers),
+    Response(OkxSubResponse),
+}
+
+/// [`Okx`] real-time mark price.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "mark-price",
+///     "instId": "BTC-USDT-SWAP"
+///   },
+///   "data": [
+///     {
+///       "instType": "SWAP",
+///       "instId": "BTC-USDT-SWAP",
+///       "markPx": "42310.6",
+///       "ts": "1630049139746"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxMarkPrice {
+    #[serde(rename = "markPx", deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: Number,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`] real-time index ticker.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-index-tickers-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "index-tickers",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     {
+///       "instId": "BTC-USDT",
+///       "idxPx": "42290.3",
+///       "high24h": "43000.0",
+///       "low24h": "41000.0",
+///       "open24h": "41500.0",
+///       "sodUtc0": "41800.0",
+///       "sodUtc8": "41900.0",
+///       "ts": "1630049139746"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxIndexTicker {
+    #[serde(rename = "idxPx", deserialize_with = "barter_integration::de::de_str")]
+    pub index_price: Number,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`] [`MarkPrices`] [`ExchangeTransformer`] that combines the "mark-price" channel with the
+/// latest index price from the associated "index-tickers" channel.
+///
+/// ### Notes
+/// [`MarkPrice`]s are only yielded on "mark-price" updates, and the index price is `None` until
+/// the first "index-tickers" update has been received.
+#[derive(Clone, PartialEq, Debug)]
+pub struct OkxMarkPriceTransformer {
+    instrument_map: Map<(Instrument, SubscriptionId)>,
+    index_prices: HashMap<SubscriptionId, Number>,
+}
+
+#[async_trait]
+impl ExchangeTransformer<Okx, MarkPrices> for OkxMarkPriceTransformer {
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+        _: Map<MarkPrices>,
+    ) -> Result<Self, DataError> {
+        // Determine the index-tickers SubscriptionId associated with each mark-price Subscription
+        let instrument_map = instrument_map
+            .0
+            .into_iter()
+            .map(|(subscription_id, instrument)| {
+                let market: OkxMarket = Subscription {
+                    exchange: Okx,
+                    instrument: instrument.clone(),
+                    kind: MarkPrices,
+                }
+                .id();
+                let index_id = ExchangeSub::from((OkxChannel::INDEX_TICKERS, market.index())).id();
+
+                (subscription_id, (instrument, index_id))
+            })
+            .collect();
+
+        Ok(Self {
+            instrument_map: Map(instrument_map),
+            index_prices: HashMap::new(),
+        })
+    }
+}
+
+impl Transformer for OkxMarkPriceTransformer {
+    type Error = DataError;
+    type Input = OkxMarkPriceMessage;
+    type Output = MarketEvent<MarkPrice>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            OkxMarkPriceMessage::MarkPrice(mark_prices) => {
+                // Find Instrument & index-tickers SubscriptionId associated with the mark-price
+                let (instrument, index_id) =
+                    match self.instrument_map.find(&mark_prices.subscription_id) {
+                        Ok(state) => state,
+                        Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+                    };
+
+                let index = self.index_prices.get(&index_id).copied();
+
+                mark_prices
+                    .data
+                    .into_iter()
+                    .map(|mark_price| MarketEvent {
+                        exchange_time: mark_price.time,
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(Okx::ID),
+                        instrument: instrument.clone(),
+                        kind: MarkPrice {
+                            mark: mark_price.mark_price,
+                            index,
+                            estimated_settle: None,
+                            time: mark_price.time,
+                        },
+                    })
+                    .map(Ok)
+                    .collect::<MarketIter<MarkPrice>>()
+                    .0
+            }
+            OkxMarkPriceMessage::IndexTicker(index_tickers) => {
+                if let Some(index_ticker) = index_tickers.data.last() {
+                    self.index_prices
+                        .insert(index_tickers.subscription_id, index_ticker.index_price);
+                }
+                vec![]
+            }
+            OkxMarkPriceMessage::Response(_) => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, model::instrument::kind::InstrumentKind,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_okx_mark_price_transformer() {
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<Option<Number>>,
+        }
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let mut transformer = OkxMarkPriceTransformer {
+            instrument_map: Map(HashMap::from([(
+                SubscriptionId::from("mark-price|BTC-USDT-SWAP"),
+                (instrument, SubscriptionId::from("index-tickers|BTC-USDT")),
+            )])),
+            index_prices: HashMap::new(),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: mark-price before any index-tickers update has no index price
+                input: r#"
+                {
+                    "arg": {"channel": "mark-price", "instId": "BTC-USDT-SWAP"},
+                    "data": [{"instType": "SWAP", "instId": "BTC-USDT-SWAP", "markPx": "42310.6", "ts": "1630049139746"}]
+                }
+                "#,
+                expected: vec![None],
+            },
+            TestCase {
+                // TC1: index-tickers update yields nothing
+                input: r#"
+                {
+                    "arg": {"channel": "index-tickers", "instId": "BTC-USDT"},
+                    "data": [{"instId": "BTC-USDT", "idxPx": "42290.3", "ts": "1630049139746"}]
+                }
+                "#,
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: subsequent mark-price contains the latest index price
+                input: r#"
+                {
+                    "arg": {"channel": "mark-price", "instId": "BTC-USDT-SWAP"},
+                    "data": [{"instType": "SWAP", "instId": "BTC-USDT-SWAP", "markPx": "42311.0", "ts": "1630049139846"}]
+                }
+                "#,
+                expected: vec![Some(num!(42290.3))],
+            },
+            TestCase {
+                // TC3: surplus subscription response yields nothing
+                input: r#"{"event": "subscribe", "arg": {"channel": "index-tickers", "instId": "BTC-USDT"}}"#,
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str::<OkxMarkPriceMessage>(test.input).unwrap();
+            let actual = transformer
+                .transform(input)
+                .into_iter()
+                .map(|event| event.unwrap().kind.index)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_message_mark_price() {
+            let input = r#"
+            {
+                "arg": {"channel": "mark-price", "instId": "BTC-USDT-SWAP"},
+                "data": [{"instType": "SWAP", "instId": "BTC-USDT-SWAP", "markPx": "42310.6", "ts": "1630049139746"}]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxMarkPriceMessage>(input).unwrap(),
+                OkxMarkPriceMessage::MarkPrice(OkxMarkPrices {
+                    subscription_id: SubscriptionId::from("mark-price|BTC-USDT-SWAP"),
+                    data: vec![OkxMarkPrice {
+                        mark_price: num!(42310.6),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1630049139746
+                        )),
+                    }],
+                })
+            );
+        }
+    }
+}
@@ -42,6 +42,28 @@ impl<Kind> Identifier<OkxMarket> for Subscription<Okx, Kind> {
     }
 }
 
+impl OkxMarket {
+    /// Return the [`OkxMarket`] of the underlying index (eg/ "BTC-USDT-SWAP" -> "BTC-USDT").
+    pub fn index(&self) -> Self {
+        Self(self.0.splitn(3, '-').take(2).collect::<Vec<_>>().join("-"))
+    }
+
+    /// Return the Okx instrument type of the [`OkxMarket`] (eg/ "BTC-USDT-SWAP" -> "SWAP").
+    ///
+    /// Spot markets map to "MARGIN" since only margin positions can be liquidated.
+    pub fn inst_type(&self) -> &'static str {
+        if self.0.ends_with("-SWAP") {
+            return "SWAP";
+        }
+
+        match self.0.split('-').count() {
+            3 => "FUTURES",
+            5 => "OPTION",
+            _ => "MARGIN",
+        }
+    }
+}
+
 impl AsRef<str> for OkxMarket {
     fn as_ref(&self) -> &str {
         &self.0
@@ -56,3 +78,75 @@ impl AsRef<str> for OkxMarket {
 fn format_expiry<'a>(expiry: DateTime<Utc>) -> DelayedFormat<StrftimeItems<'a>> {
     expiry.date_naive().format("%g%m%d")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_okx_market_index() {
+        struct TestCase {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: spot market is it's own index
+                input: "BTC-USDT",
+                expected: "BTC-USDT",
+            },
+            TestCase {
+                // TC1: perpetual market
+                input: "BTC-USDT-SWAP",
+                expected: "BTC-USDT",
+            },
+            TestCase {
+                // TC2: future market
+                input: "BTC-USD-230526",
+                expected: "BTC-USD",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = OkxMarket(test.input.to_string()).index();
+            assert_eq!(actual.as_ref(), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_okx_market_inst_type() {
+        struct TestCase {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: spot market
+                input: "BTC-USDT",
+                expected: "MARGIN",
+            },
+            TestCase {
+                // TC1: perpetual market
+                input: "BTC-USDT-SWAP",
+                expected: "SWAP",
+            },
+            TestCase {
+                // TC2: future market
+                input: "BTC-USD-230526",
+                expected: "FUTURES",
+            },
+            TestCase {
+                // TC3: option market
+                input: "BTC-USD-231229-35000-C",
+                expected: "OPTION",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = OkxMarket(test.input.to_string()).inst_type();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
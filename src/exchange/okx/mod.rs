@@ -1,31 +1,66 @@
 use self::{
-    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxTrades,
+    book::l2::OkxBookUpdater, channel::OkxChannel, funding::OkxFundingRates,
+    liquidation::OkxLiquidationTransformer, mark_price::OkxMarkPriceTransformer, market::OkxMarket,
+    open_interest::OkxOpenInterests, subscription::OkxSubResponse, ticker::OkxTickers,
+    trade::OkxTrades,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, PingInterval, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2, OrderBooksL2Delta},
+        funding::FundingRates,
+        liquidation::Liquidations,
+        mark_price::MarkPrices,
+        open_interest::OpenInterests,
+        ticker::Tickers,
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{
+        book::{MultiBookDeltaTransformer, MultiBookTransformer, OrderBookL1Transformer},
+        stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, TimedExchangeWsStream,
+};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
-use std::time::Duration;
+use std::{collections::HashSet, time::Duration};
 use url::Url;
 
+/// Order book types for [`Okx`].
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Funding rate types for [`Okx`].
+pub mod funding;
+
+/// Liquidation types and [`Liquidations`] transformer for [`Okx`].
+pub mod liquidation;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// Mark price types and [`MarkPrices`] transformer for [`Okx`].
+pub mod mark_price;
+
+/// Open interest types for [`Okx`].
+pub mod open_interest;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration::Validator) for [`Okx`].
 pub mod subscription;
 
+/// Rolling 24h ticker types for [`Okx`].
+pub mod ticker;
+
 /// Public trade types for [`Okx`].
 pub mod trade;
 
@@ -66,7 +101,26 @@ impl Connector for Okx {
         })
     }
 
-    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+    fn requests(
+        mut exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        // MarkPrices also subscribe to the index-tickers of the associated underlying index
+        let mut index_subs = exchange_subs
+            .iter()
+            .filter(|sub| sub.channel == OkxChannel::MARK_PRICE)
+            .map(|sub| ExchangeSub::from((OkxChannel::INDEX_TICKERS, sub.market.index())))
+            .collect::<Vec<_>>();
+        index_subs.sort();
+        index_subs.dedup();
+        exchange_subs.extend(index_subs);
+
+        // Liquidations are subscribed to once per instrument type
+        let mut liquidation_inst_types = HashSet::new();
+        exchange_subs.retain(|sub| {
+            sub.channel != OkxChannel::LIQUIDATIONS
+                || liquidation_inst_types.insert(sub.market.inst_type())
+        });
+
         vec![WsMessage::Text(
             json!({
                 "op": "subscribe",
@@ -75,8 +129,135 @@ impl Connector for Okx {
             .to_string(),
         )]
     }
+
+    fn expected_responses(map: &Map<Instrument>) -> usize {
+        // Liquidations only receive one response per instrument type
+        let mut liquidation_inst_types = HashSet::new();
+
+        // MarkPrices also receive one index-tickers response per distinct underlying index
+        let mut mark_price_indexes = HashSet::new();
+
+        let responses = map
+            .0
+            .keys()
+            .filter(|subscription_id| match subscription_id.0.split_once('|') {
+                Some((channel, market)) if channel == OkxChannel::LIQUIDATIONS.as_ref() => {
+                    liquidation_inst_types.insert(OkxMarket(market.to_string()).inst_type())
+                }
+                Some((channel, market)) if channel == OkxChannel::MARK_PRICE.as_ref() => {
+                    mark_price_indexes.insert(OkxMarket(market.to_string()).index());
+                    true
+                }
+                _ => true,
+            })
+            .count();
+
+        responses + mark_price_indexes.len()
+    }
 }
 
 impl StreamSelector<PublicTrades> for Okx {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, OkxTrades>>;
 }
+
+impl StreamSelector<OrderBooksL1> for Okx {
+    type Stream = ExchangeWsStream<OrderBookL1Transformer<Self, OkxBookUpdater>>;
+}
+
+impl StreamSelector<OrderBooksL2> for Okx {
+    type Stream = TimedExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, OkxBookUpdater>>;
+
+    fn validate_kind(kind: &OrderBooksL2) -> Result<(), String> {
+        kind.validate_no_snapshot_depth()
+    }
+}
+
+impl StreamSelector<OrderBooksL2Delta> for Okx {
+    type Stream =
+        ExchangeWsStream<MultiBookDeltaTransformer<Self, OrderBooksL2Delta, OkxBookUpdater>>;
+}
+
+impl StreamSelector<FundingRates> for Okx {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, FundingRates, OkxFundingRates>>;
+}
+
+impl StreamSelector<OpenInterests> for Okx {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OpenInterests, OkxOpenInterests>>;
+}
+
+impl StreamSelector<Tickers> for Okx {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Tickers, OkxTickers>>;
+}
+
+impl StreamSelector<MarkPrices> for Okx {
+    type Stream = ExchangeWsStream<OkxMarkPriceTransformer>;
+}
+
+impl StreamSelector<Liquidations> for Okx {
+    type Stream = ExchangeWsStream<OkxLiquidationTransformer>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+
+    #[test]
+    fn test_expected_responses() {
+        struct TestCase {
+            input: Vec<&'static str>,
+            expected: usize,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: one response per subscription
+                input: vec!["trades|BTC-USDT", "books|BTC-USDT"],
+                expected: 2,
+            },
+            TestCase {
+                // TC1: one liquidation response per instrument type
+                input: vec![
+                    "liquidation-orders|BTC-USDT-SWAP",
+                    "liquidation-orders|ETH-USDT-SWAP",
+                    "liquidation-orders|BTC-USDT-240329",
+                ],
+                expected: 2,
+            },
+            TestCase {
+                // TC2: mark price also receives an index-tickers response
+                input: vec!["mark-price|BTC-USDT-SWAP"],
+                expected: 2,
+            },
+            TestCase {
+                // TC3: mark prices sharing an underlying index receive one index-tickers response
+                input: vec![
+                    "mark-price|BTC-USDT-SWAP",
+                    "mark-price|BTC-USDT-240329",
+                    "mark-price|ETH-USDT-SWAP",
+                ],
+                expected: 5,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let map = Map(test
+                .input
+                .into_iter()
+                .map(|subscription_id| {
+                    (
+                        SubscriptionId::from(subscription_id),
+                        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                    )
+                })
+                .collect());
+
+            assert_eq!(
+                Okx::expected_responses(&map),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
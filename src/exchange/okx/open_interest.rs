@@ -0,0 +1,168 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    number::Number,
+    subscription::open_interest::OpenInterest,
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time open interest WebSocket message.
+pub type OkxOpenInterests = OkxMessage<OkxOpenInterest>;
+
+/// [`Okx`](super::Okx) real-time open interest WebSocket message.
+///
+/// ### Notes
+/// `oiUsd` is the USD value of the open interest, and is not sent by older API versions.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-open-interest-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "open-interest",
+///     "instId": "BTC-USDT-SWAP"
+///   },
+///   "data": [
+///     {
+///       "instType": "SWAP",
+///       "instId": "BTC-USDT-SWAP",
+///       "oi": "2216113.01000000000004",
+///       "oiCcy": "22161.1301000000000004",
+///       "oiUsd": "1485798175.1601",
+///       "ts": "1693556325000"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOpenInterest {
+    #[serde(rename = "oi", deserialize_with = "barter_integration::de::de_str")]
+    pub contracts: Number,
+    #[serde(rename = "oiUsd", default, deserialize_with = "de_option_str")]
+    pub notional: Option<Number>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, OkxOpenInterests)> for MarketIter<OpenInterest> {
+    fn from(
+        (exchange_id, instrument, open_interests): (ExchangeId, Instrument, OkxOpenInterests),
+    ) -> Self {
+        open_interests
+            .data
+            .into_iter()
+            .map(|open_interest| MarketEvent {
+                exchange_time: open_interest.time,
+                received_time: Utc::now(),
+                exchange: Exchange::from(exchange_id),
+                instrument: instrument.clone(),
+                kind: OpenInterest {
+                    contracts: open_interest.contracts,
+                    notional: open_interest.notional,
+                    time: open_interest.time,
+                },
+            })
+            .map(Ok)
+            .collect()
+    }
+}
+
+/// Deserialize an optional, possibly empty, `String` as an optional [`Number`].
+fn de_option_str<'de, D>(deserializer: D) -> Result<Option<Number>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_message_open_interest() {
+            struct TestCase {
+                input: &'static str,
+                expected: OkxOpenInterests,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input with oiUsd is deserialised
+                    input: r#"
+                    {
+                        "arg": {"channel": "open-interest", "instId": "BTC-USDT-SWAP"},
+                        "data": [
+                            {
+                                "instType": "SWAP",
+                                "instId": "BTC-USDT-SWAP",
+                                "oi": "2216113.01",
+                                "oiCcy": "22161.1301",
+                                "oiUsd": "1485798175.1601",
+                                "ts": "1693556325000"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: OkxOpenInterests {
+                        subscription_id: SubscriptionId::from("open-interest|BTC-USDT-SWAP"),
+                        data: vec![OkxOpenInterest {
+                            contracts: num!(2216113.01),
+                            notional: Some(num!(1485798175.1601)),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1693556325000,
+                            )),
+                        }],
+                    },
+                },
+                TestCase {
+                    // TC1: input without oiUsd is deserialised
+                    input: r#"
+                    {
+                        "arg": {"channel": "open-interest", "instId": "BTC-USD-SWAP"},
+                        "data": [
+                            {
+                                "instType": "SWAP",
+                                "instId": "BTC-USD-SWAP",
+                                "oi": "5000",
+                                "oiCcy": "555.55",
+                                "ts": "1597026383085"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: OkxOpenInterests {
+                        subscription_id: SubscriptionId::from("open-interest|BTC-USD-SWAP"),
+                        data: vec![OkxOpenInterest {
+                            contracts: num!(5000.0),
+                            notional: None,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383085,
+                            )),
+                        }],
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxOpenInterests>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
@@ -11,7 +11,11 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
     {
         let mut state = serializer.serialize_struct("OkxSubArg", 2)?;
         state.serialize_field("channel", self.channel.as_ref())?;
-        state.serialize_field("instId", self.market.as_ref())?;
+        if self.channel == OkxChannel::LIQUIDATIONS {
+            state.serialize_field("instType", self.market.inst_type())?;
+        } else {
+            state.serialize_field("instId", self.market.as_ref())?;
+        }
         state.end()
     }
 }
@@ -0,0 +1,237 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    number::Number,
+    subscription::ticker::Ticker,
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Exchange,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time rolling 24h ticker WebSocket message.
+pub type OkxTickers = OkxMessage<OkxTicker>;
+
+/// [`Okx`](super::Okx) real-time rolling 24h ticker WebSocket message.
+///
+/// ### Notes
+/// For [`InstrumentKind::Spot`] `vol24h` is in the base currency & `volCcy24h` in the quote
+/// currency. For derivatives `vol24h` is in contracts & `volCcy24h` in the base currency.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-tickers-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "tickers",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     {
+///       "instType": "SPOT",
+///       "instId": "BTC-USDT",
+///       "last": "9999.99",
+///       "lastSz": "0.1",
+///       "askPx": "9999.99",
+///       "askSz": "11",
+///       "bidPx": "8888.88",
+///       "bidSz": "5",
+///       "open24h": "9000",
+///       "high24h": "10000",
+///       "low24h": "8888.88",
+///       "volCcy24h": "2222",
+///       "vol24h": "2222",
+///       "sodUtc0": "2222",
+///       "sodUtc8": "2222",
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxTicker {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub last: Number,
+    #[serde(
+        rename = "open24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub open: Number,
+    #[serde(
+        rename = "high24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub high: Number,
+    #[serde(rename = "low24h", deserialize_with = "barter_integration::de::de_str")]
+    pub low: Number,
+    #[serde(rename = "vol24h", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: Number,
+    #[serde(
+        rename = "volCcy24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume_currency: Number,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, OkxTickers)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, tickers): (ExchangeId, Instrument, OkxTickers)) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                let (volume_base, volume_quote) = match instrument.kind {
+                    InstrumentKind::Spot => (ticker.volume, Some(ticker.volume_currency)),
+                    _ => (ticker.volume_currency, None),
+                };
+
+                MarketEvent {
+                    exchange_time: ticker.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Ticker {
+                        last: ticker.last,
+                        open: Some(ticker.open),
+                        high: ticker.high,
+                        low: ticker.low,
+                        volume_base,
+                        volume_quote,
+                        change_percent: Ticker::change_percent(ticker.open, ticker.last),
+                        time: ticker.time,
+                    },
+                }
+            })
+            .map(Ok)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_message_tickers() {
+            let input = r#"
+            {
+                "arg": {"channel": "tickers", "instId": "BTC-USDT"},
+                "data": [
+                    {
+                        "instType": "SPOT",
+                        "instId": "BTC-USDT",
+                        "last": "9999.99",
+                        "lastSz": "0.1",
+                        "askPx": "9999.99",
+                        "askSz": "11",
+                        "bidPx": "8888.88",
+                        "bidSz": "5",
+                        "open24h": "9000",
+                        "high24h": "10000",
+                        "low24h": "8888.88",
+                        "volCcy24h": "22220000",
+                        "vol24h": "2222",
+                        "sodUtc0": "2222",
+                        "sodUtc8": "2222",
+                        "ts": "1597026383085"
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxTickers>(input).unwrap(),
+                OkxTickers {
+                    subscription_id: SubscriptionId::from("tickers|BTC-USDT"),
+                    data: vec![OkxTicker {
+                        last: num!(9999.99),
+                        open: num!(9000.0),
+                        high: num!(10000.0),
+                        low: num!(8888.88),
+                        volume: num!(2222.0),
+                        volume_currency: num!(22220000.0),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1597026383085,
+                        )),
+                    }],
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_okx_tickers_volume_by_instrument_kind() {
+        struct TestCase {
+            kind: InstrumentKind,
+            expected_volume_base: Number,
+            expected_volume_quote: Option<Number>,
+        }
+
+        let tickers = OkxTickers {
+            subscription_id: barter_integration::model::SubscriptionId::from("tickers|BTC-USDT"),
+            data: vec![OkxTicker {
+                last: num!(110.0),
+                open: num!(100.0),
+                high: num!(120.0),
+                low: num!(90.0),
+                volume: num!(2000.0),
+                volume_currency: num!(20.0),
+                time: Utc::now(),
+            }],
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: Spot vol24h is base volume & volCcy24h is quote volume
+                kind: InstrumentKind::Spot,
+                expected_volume_base: num!(2000.0),
+                expected_volume_quote: Some(num!(20.0)),
+            },
+            TestCase {
+                // TC1: Perpetual volCcy24h is base volume & quote volume is unknown
+                kind: InstrumentKind::Perpetual,
+                expected_volume_base: num!(20.0),
+                expected_volume_quote: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let instrument = Instrument::from(("btc", "usdt", test.kind));
+            let actual = MarketIter::<Ticker>::from((ExchangeId::Okx, instrument, tickers.clone()))
+                .0
+                .remove(0)
+                .unwrap()
+                .kind;
+
+            assert_eq!(
+                actual.volume_base, test.expected_volume_base,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                actual.volume_quote, test.expected_volume_quote,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                actual.change_percent,
+                Some(num!(10.0)),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
+    number::Number,
     subscription::trade::PublicTrade,
     Identifier,
 };
@@ -80,9 +81,9 @@ pub struct OkxTrade {
     #[serde(rename = "tradeId")]
     pub id: String,
     #[serde(rename = "px", deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Number,
     pub side: Side,
     #[serde(
         rename = "ts",
@@ -115,7 +116,7 @@ impl From<(ExchangeId, Instrument, OkxTrades)> for MarketIter<PublicTrade> {
 }
 
 /// Deserialize an [`OkxMessage`] "arg" field as a Barter [`SubscriptionId`].
-fn de_okx_message_arg_as_subscription_id<'de, D>(
+pub fn de_okx_message_arg_as_subscription_id<'de, D>(
     deserializer: D,
 ) -> Result<SubscriptionId, D::Error>
 where
@@ -135,6 +136,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::number::num;
 
     mod de {
         use super::*;
@@ -167,8 +169,8 @@ mod tests {
                 subscription_id: SubscriptionId::from("trades|BTC-USDT"),
                 data: vec![OkxTrade {
                     id: "130639474".to_string(),
-                    price: 42219.9,
-                    amount: 0.12060306,
+                    price: num!(42219.9),
+                    amount: num!(0.12060306),
                     side: Side::Buy,
                     time: datetime_utc_from_epoch_duration(Duration::from_millis(1630048897897)),
                 }],
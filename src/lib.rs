@@ -87,17 +87,32 @@ use crate::{
     exchange::{Connector, ExchangeId, PingInterval},
     subscriber::Subscriber,
     subscription::{SubKind, Subscription},
-    transformer::ExchangeTransformer,
+    transformer::{ExchangeTransformer, TimedTransformer},
 };
 use async_trait::async_trait;
 use barter_integration::{
-    protocol::websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
+    protocol::{
+        websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
+        StreamParser,
+    },
     ExchangeStream,
 };
+use chrono::Utc;
 use futures::{SinkExt, Stream, StreamExt};
-use tokio::sync::mpsc;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::{
+    sync::mpsc,
+    time::{Interval, MissedTickBehavior},
+};
 use tracing::{debug, error};
 
+/// Aggregators that build normalised Barter data types (eg/ [`Candle`](subscription::candle::Candle))
+/// from other [`MarketEvent<T>`](event::MarketEvent) streams.
+pub mod aggregator;
+
 /// All [`Error`](std::error::Error)s generated in Barter-Data.
 pub mod error;
 
@@ -107,6 +122,14 @@ pub mod event;
 /// [`Connector`] implementations for each exchange.
 pub mod exchange;
 
+/// [`Number`](number::Number) type used for normalised prices & amounts, which is `f64` by
+/// default or `rust_decimal::Decimal` with the "decimal" cargo feature.
+pub mod number;
+
+/// Generic [`PollingStream`](poller::PollingStream) [`MarketStream`] that periodically polls an
+/// exchange HTTP endpoint, for data that an exchange does not serve over WebSocket.
+pub mod poller;
+
 /// High-level API types used for building [`MarketStream`]s from collections
 /// of Barter [`Subscription`]s.
 pub mod streams;
@@ -130,14 +153,67 @@ pub mod subscription;
 ///   [`PublicTrades`](crate::subscription::trade::PublicTrades)
 ///   and [`OrderBooksL1`](crate::subscription::book::OrderBooksL1) streams. <br>
 /// - [`MultiBookTransformer`](transformer::book::MultiBookTransformer) for
-///   [`OrderBooksL2`](crate::subscription::book::OrderBooksL2) and
-///   [`OrderBooksL3`](crate::subscription::book::OrderBooksL3) streams.
+///   [`OrderBooksL2`](crate::subscription::book::OrderBooksL2) streams. <br>
+/// - [`MultiBookL3Transformer`](transformer::book::MultiBookL3Transformer) for
+///   [`OrderBooksL3`](crate::subscription::book::OrderBooksL3) streams. <br>
+/// - [`CandleTransformer`](transformer::candle::CandleTransformer) and
+///   [`TradeCandleTransformer`](transformer::candle::TradeCandleTransformer) for
+///   [`Candles`](crate::subscription::candle::Candles) streams. <br>
+/// - [`MergeTransformer`](transformer::merge::MergeTransformer) for exchange channels that send
+///   partial updates, such as some [`FundingRates`](crate::subscription::funding::FundingRates)
+///   streams.
 pub mod transformer;
 
 /// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite
 /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
 pub type ExchangeWsStream<Transformer> = ExchangeStream<WebSocketParser, WsStream, Transformer>;
 
+/// [`ExchangeWsStream`] that also drives a [`TimedTransformer`] at it's
+/// [`tick_interval`](TimedTransformer::tick_interval), so outputs that depend on the passage of
+/// time are yielded even when no messages are received from the exchange.
+pub struct TimedExchangeWsStream<Transformer>
+where
+    Transformer: barter_integration::Transformer,
+{
+    pub stream: ExchangeWsStream<Transformer>,
+    pub interval: Option<Interval>,
+}
+
+impl<Transformer> std::fmt::Debug for TimedExchangeWsStream<Transformer>
+where
+    Transformer: barter_integration::Transformer + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimedExchangeWsStream")
+            .field("transformer", &self.stream.transformer)
+            .field("interval", &self.interval)
+            .finish()
+    }
+}
+
+impl<Transformer> Stream for TimedExchangeWsStream<Transformer>
+where
+    Transformer: TimedTransformer,
+    ExchangeWsStream<Transformer>:
+        Stream<Item = Result<Transformer::Output, Transformer::Error>> + Unpin,
+{
+    type Item = Result<Transformer::Output, Transformer::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        // Buffer the outputs of any elapsed ticks ahead of the next exchange message
+        if let Some(interval) = &mut this.interval {
+            while interval.poll_tick(cx).is_ready() {
+                let outputs = this.stream.transformer.tick(Utc::now());
+                this.stream.buffer.extend(outputs);
+            }
+        }
+
+        this.stream.poll_next_unpin(cx)
+    }
+}
+
 /// Defines a generic identification type for the implementor.
 pub trait Identifier<T> {
     fn id(&self) -> T;
@@ -170,7 +246,11 @@ where
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
         // Connect & subscribe
-        let (websocket, map) = Exchange::Subscriber::subscribe(subscriptions).await?;
+        let (websocket, instrument_map, buffered) =
+            Exchange::Subscriber::subscribe(subscriptions).await?;
+
+        // Link each SubscriptionId to the SubKind configuration of it's Subscription
+        let kind_map = subscriber::mapper::kind_map(subscriptions, &instrument_map);
 
         // Split WebSocket into WsStream & WsSink components
         let (ws_sink, ws_stream) = websocket.split();
@@ -193,9 +273,50 @@ where
         }
 
         // Construct Transformer associated with this Exchange and SubKind
-        let transformer = Transformer::new(ws_sink_tx, map).await?;
+        let transformer = Transformer::new(ws_sink_tx, instrument_map, kind_map).await?;
+        let mut stream = ExchangeWsStream::new(ws_stream, transformer);
+
+        // Transform market data received during subscription validation (eg/ initial snapshots)
+        for message in buffered {
+            match WebSocketParser::parse::<Transformer::Input>(Ok(message)) {
+                Some(Ok(input)) => {
+                    let outputs = stream.transformer.transform(input);
+                    stream.buffer.extend(outputs);
+                }
+                Some(Err(error)) => stream.buffer.push_back(Err(DataError::from(error))),
+                None => continue,
+            }
+        }
+
+        Ok(stream)
+    }
+}
+
+#[async_trait]
+impl<Exchange, Kind, Transformer> MarketStream<Exchange, Kind>
+    for TimedExchangeWsStream<Transformer>
+where
+    Exchange: Connector + Send + Sync,
+    Kind: SubKind + Send + Sync,
+    Transformer: ExchangeTransformer<Exchange, Kind> + TimedTransformer + Send,
+    Kind::Event: Send,
+{
+    async fn init(subscriptions: &[Subscription<Exchange, Kind>]) -> Result<Self, DataError>
+    where
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let stream =
+            <ExchangeWsStream<Transformer> as MarketStream<Exchange, Kind>>::init(subscriptions)
+                .await?;
+
+        // Construct tick Interval, delaying missed ticks rather than bursting to catch up
+        let interval = stream.transformer.tick_interval().map(|period| {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval
+        });
 
-        Ok(ExchangeWsStream::new(ws_stream, transformer))
+        Ok(Self { stream, interval })
     }
 }
 
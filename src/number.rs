@@ -0,0 +1,120 @@
+use std::cmp::Ordering;
+
+/// Numeric type used for every normalised Barter-Data price & amount, such as those of a
+/// [`Level`](crate::subscription::book::Level),
+/// [`PublicTrade`](crate::subscription::trade::PublicTrade),
+/// [`Liquidation`](crate::subscription::liquidation::Liquidation) and
+/// [`Candle`](crate::subscription::candle::Candle).
+///
+/// Defaults to `f64`, but is [`rust_decimal::Decimal`] if the "decimal" cargo feature is enabled
+/// so that price identity is exact for large prices and tiny tick sizes.
+pub type Number = repr::Number;
+
+/// Zero value [`Number`].
+pub const ZERO: Number = repr::ZERO;
+
+/// One hundred [`Number`], used to convert ratios into percentages.
+pub const ONE_HUNDRED: Number = repr::ONE_HUNDRED;
+
+#[cfg(not(feature = "decimal"))]
+mod repr {
+    pub type Number = f64;
+    pub const ZERO: Number = 0.0;
+    pub const ONE_HUNDRED: Number = 100.0;
+
+    #[cfg(test)]
+    pub fn from_literal(literal: &str) -> Number {
+        literal.replace('_', "").parse().unwrap()
+    }
+}
+
+#[cfg(feature = "decimal")]
+mod repr {
+    pub type Number = rust_decimal::Decimal;
+    pub const ZERO: Number = rust_decimal::Decimal::ZERO;
+    pub const ONE_HUNDRED: Number = rust_decimal::Decimal::ONE_HUNDRED;
+
+    #[cfg(test)]
+    pub fn from_literal(literal: &str) -> Number {
+        let literal = literal.replace('_', "");
+        Number::from_str_exact(&literal)
+            .or_else(|_| Number::from_scientific(&literal))
+            .unwrap()
+    }
+}
+
+/// Total ordering of two [`Number`]s.
+pub fn total_cmp(a: &Number, b: &Number) -> Ordering {
+    #[cfg(not(feature = "decimal"))]
+    return a.total_cmp(b);
+
+    #[cfg(feature = "decimal")]
+    return a.cmp(b);
+}
+
+/// Convert a [`Number`] into an `f64`, for calculations that are not precision sensitive.
+pub fn to_f64(number: Number) -> f64 {
+    #[cfg(not(feature = "decimal"))]
+    return number;
+
+    #[cfg(feature = "decimal")]
+    return rust_decimal::prelude::ToPrimitive::to_f64(&number).unwrap_or(f64::NAN);
+}
+
+/// Construct a [`Number`] from a numeric literal, regardless of the "decimal" cargo feature.
+#[cfg(test)]
+pub(crate) fn from_literal(literal: &str) -> Number {
+    repr::from_literal(literal)
+}
+
+/// Construct a [`Number`] from a numeric literal (eg/ `num!(0.1)`), regardless of the "decimal"
+/// cargo feature.
+#[cfg(test)]
+macro_rules! num {
+    ($literal:literal) => {
+        $crate::number::from_literal(stringify!($literal))
+    };
+}
+
+#[cfg(test)]
+pub(crate) use num;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num() {
+        struct TestCase {
+            input: Number,
+            expected: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: float literal
+                input: num!(0.1),
+                expected: 0.1,
+            },
+            TestCase {
+                // TC1: negative float literal
+                input: num!(-5.25),
+                expected: -5.25,
+            },
+            TestCase {
+                // TC2: integer literal w/ separators
+                input: num!(1_000_000),
+                expected: 1_000_000.0,
+            },
+            TestCase {
+                // TC3: scientific literal
+                input: num!(1e-8),
+                expected: 1e-8,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(to_f64(test.input), test.expected, "TC{} failed", index);
+        }
+    }
+}
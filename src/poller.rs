@@ -0,0 +1,290 @@
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{Connector, ExchangeId},
+    subscription::{SubKind, Subscription},
+    Identifier, MarketStream,
+};
+use async_trait::async_trait;
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use futures::Stream;
+use serde::de::DeserializeOwned;
+use std::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{sync::mpsc, time::MissedTickBehavior};
+use tracing::debug;
+
+/// Defines how a [`PollingStream`] periodically polls an exchange HTTP endpoint for the latest
+/// data of each [`Subscription`].
+///
+/// ### Notes
+/// Used for [`SubKind`]s that an exchange only serves over HTTP (eg/ BinanceFuturesUsd
+/// [`OpenInterests`](crate::subscription::open_interest::OpenInterests)).
+pub trait HttpPoller<Exchange, Kind>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+{
+    /// Exchange HTTP response that is translated into normalised Barter types.
+    type Response: DeserializeOwned + Send;
+
+    /// Interval between consecutive polls of every [`Subscription`].
+    fn interval() -> Duration;
+
+    /// HTTP GET url used to poll the latest data of the provided exchange market.
+    fn url(market: &Exchange::Market) -> String;
+}
+
+/// Generic [`MarketStream`] that periodically polls an exchange HTTP endpoint, as defined by
+/// the associated [`HttpPoller`], and yields the normalised [`MarketEvent<T>`](MarketEvent)s.
+///
+/// ### Notes
+/// - Every [`Subscription`] is polled once during [`MarketStream::init`], so invalid
+///   [`Subscription`]s fail initialisation in the same way as WebSocket subscriptions.
+/// - Subsequent poll failures are yielded as non-terminal [`DataError`]s, and polling continues
+///   at the next interval.
+#[derive(Debug)]
+pub struct PollingStream<Exchange, Kind, Poller>
+where
+    Kind: SubKind,
+{
+    event_rx: mpsc::UnboundedReceiver<Result<MarketEvent<Kind::Event>, DataError>>,
+    phantom: PhantomData<(Exchange, Poller)>,
+}
+
+impl<Exchange, Kind, Poller> Stream for PollingStream<Exchange, Kind, Poller>
+where
+    Kind: SubKind,
+    Self: Unpin,
+{
+    type Item = Result<MarketEvent<Kind::Event>, DataError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.event_rx.poll_recv(cx)
+    }
+}
+
+#[async_trait]
+impl<Exchange, Kind, Poller> MarketStream<Exchange, Kind> for PollingStream<Exchange, Kind, Poller>
+where
+    Exchange: Connector + Send + Sync + Unpin + 'static,
+    Kind: SubKind + Send + Sync + 'static,
+    Kind::Event: Send,
+    Poller: HttpPoller<Exchange, Kind> + Send + Sync + Unpin + 'static,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Poller::Response)>,
+{
+    async fn init(subscriptions: &[Subscription<Exchange, Kind>]) -> Result<Self, DataError>
+    where
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Determine the HTTP url to poll for each Subscription
+        let markets = subscriptions
+            .iter()
+            .map(|subscription| {
+                let market: Exchange::Market = subscription.id();
+                (subscription.instrument.clone(), Poller::url(&market))
+            })
+            .collect::<Vec<_>>();
+
+        let client = reqwest::Client::new();
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+
+        // Poll every Subscription once to validate them before spawning the polling task
+        for (instrument, url) in &markets {
+            let response = fetch::<Poller::Response>(&client, url).await?;
+            for event in
+                MarketIter::<Kind::Event>::from((Exchange::ID, instrument.clone(), response)).0
+            {
+                let _ = event_tx.send(event);
+            }
+        }
+
+        tokio::spawn(poll_markets::<Exchange, Kind, Poller>(
+            client, markets, event_tx,
+        ));
+
+        Ok(Self {
+            event_rx,
+            phantom: PhantomData,
+        })
+    }
+}
+
+/// Poll every market at the [`HttpPoller::interval`], sending the normalised
+/// [`MarketEvent<T>`](MarketEvent)s to the associated [`PollingStream`] until it is dropped.
+async fn poll_markets<Exchange, Kind, Poller>(
+    client: reqwest::Client,
+    markets: Vec<(Instrument, String)>,
+    event_tx: mpsc::UnboundedSender<Result<MarketEvent<Kind::Event>, DataError>>,
+) where
+    Exchange: Connector,
+    Kind: SubKind,
+    Poller: HttpPoller<Exchange, Kind>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Poller::Response)>,
+{
+    let mut interval = tokio::time::interval(Poller::interval());
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    // First tick completes immediately, and every market was already polled during init
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let fetch_market = |url| fetch::<Poller::Response>(&client, url);
+        if !poll_once::<Exchange, Kind, Poller, _, _>(&markets, fetch_market, &event_tx).await {
+            debug!(
+                exchange = %Exchange::ID,
+                "PollingStream dropped - stopping HTTP polling task"
+            );
+            return;
+        }
+    }
+}
+
+/// Poll every market once using the provided `fetch` function, sending the normalised
+/// [`MarketEvent<T>`](MarketEvent)s to the associated [`PollingStream`]. Poll failures are sent
+/// as non-terminal [`DataError`]s.
+///
+/// Returns false if the associated [`PollingStream`] has been dropped.
+async fn poll_once<'a, Exchange, Kind, Poller, Fetch, FetchFut>(
+    markets: &'a [(Instrument, String)],
+    fetch: Fetch,
+    event_tx: &mpsc::UnboundedSender<Result<MarketEvent<Kind::Event>, DataError>>,
+) -> bool
+where
+    Exchange: Connector,
+    Kind: SubKind,
+    Poller: HttpPoller<Exchange, Kind>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Poller::Response)>,
+    Fetch: Fn(&'a str) -> FetchFut,
+    FetchFut: Future<Output = Result<Poller::Response, SocketError>>,
+{
+    for (instrument, url) in markets {
+        let events = match fetch(url).await {
+            Ok(response) => {
+                MarketIter::<Kind::Event>::from((Exchange::ID, instrument.clone(), response)).0
+            }
+            Err(error) => vec![Err(DataError::Socket(error))],
+        };
+
+        for event in events {
+            if event_tx.send(event).is_err() {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Fetch & deserialise the `Response` from the provided HTTP url.
+async fn fetch<Response>(client: &reqwest::Client, url: &str) -> Result<Response, SocketError>
+where
+    Response: DeserializeOwned,
+{
+    client
+        .get(url)
+        .send()
+        .await
+        .map_err(SocketError::Http)?
+        .error_for_status()
+        .map_err(SocketError::Http)?
+        .json::<Response>()
+        .await
+        .map_err(SocketError::Http)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::{
+            futures::{open_interest::BinanceOpenInterest, BinanceFuturesUsd},
+            market::BinanceMarket,
+        },
+        number::num,
+        subscription::open_interest::{OpenInterest, OpenInterests},
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange};
+    use chrono::{TimeZone, Utc};
+
+    struct TestPoller;
+
+    impl HttpPoller<BinanceFuturesUsd, OpenInterests> for TestPoller {
+        type Response = BinanceOpenInterest;
+
+        fn interval() -> Duration {
+            Duration::from_millis(1)
+        }
+
+        fn url(market: &BinanceMarket) -> String {
+            market.as_ref().to_string()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_once() {
+        let time = Utc.timestamp_millis_opt(1589437530011).unwrap();
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Perpetual));
+        let markets = vec![
+            (btc.clone(), "BTCUSDT".to_string()),
+            (eth.clone(), "ETHUSDT".to_string()),
+        ];
+
+        // BTCUSDT is polled successfully, but polling ETHUSDT fails
+        let fetch = |url: &str| {
+            let response = match url {
+                "BTCUSDT" => Ok(BinanceOpenInterest {
+                    symbol: url.to_string(),
+                    open_interest: num!(10659.509),
+                    time,
+                }),
+                _ => Err(SocketError::Sink),
+            };
+            async move { response }
+        };
+
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let polled = poll_once::<BinanceFuturesUsd, OpenInterests, TestPoller, _, _>(
+            &markets, fetch, &event_tx,
+        )
+        .await;
+        assert!(polled);
+
+        // Polled response is yielded as a normalised MarketEvent
+        let event = event_rx.try_recv().unwrap().unwrap();
+        assert_eq!(
+            event.exchange,
+            Exchange::from(ExchangeId::BinanceFuturesUsd)
+        );
+        assert_eq!(event.instrument, btc);
+        assert_eq!(
+            event.kind,
+            OpenInterest {
+                contracts: num!(10659.509),
+                notional: None,
+                time,
+            }
+        );
+
+        // Poll failure is yielded as a non-terminal DataError
+        let error = event_rx.try_recv().unwrap().unwrap_err();
+        assert!(!error.is_terminal());
+        assert!(event_rx.try_recv().is_err());
+
+        // Polling stops once the PollingStream is dropped
+        drop(event_rx);
+        let polled = poll_once::<BinanceFuturesUsd, OpenInterests, TestPoller, _, _>(
+            &markets, fetch, &event_tx,
+        )
+        .await;
+        assert!(!polled);
+    }
+}
@@ -2,12 +2,17 @@ use super::{consumer::consume, Streams};
 use crate::{
     error::DataError,
     event::MarketEvent,
-    exchange::{ExchangeId, StreamSelector},
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId, StreamSelector},
     subscription::{SubKind, Subscription},
     Identifier,
 };
-use barter_integration::{error::SocketError, Validator};
-use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
+use barter_integration::{error::SocketError, model::SubscriptionId, Validator};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+};
 use tokio::sync::mpsc;
 
 /// Defines the [`MultiStreamBuilder`](multi::MultiStreamBuilder) API for ergonomically
@@ -84,6 +89,13 @@ where
             subscriptions.sort();
             subscriptions.dedup();
 
+            // Ensure distinct Subscriptions do not share an exchange SubscriptionId
+            validate_subscription_ids(&subscriptions).map_err(|subscription_id| {
+                DataError::Socket(SocketError::Subscribe(format!(
+                    "distinct Subscriptions share the exchange SubscriptionId: {subscription_id}"
+                )))
+            })?;
+
             // Spawn a MarketStream consumer loop with these Subscriptions<Exchange, Kind>
             tokio::spawn(consume(subscriptions, exchange_tx));
 
@@ -161,6 +173,35 @@ where
     Ok(())
 }
 
+/// Validate that no two distinct [`Subscription`]s map to the same exchange
+/// [`SubscriptionId`],
+/// which would otherwise make their [`SubKind`] configurations indistinguishable (eg/ two
+/// trade aggregated [`Candles`](crate::subscription::candle::Candles) subscriptions with different
+/// intervals for the same market), returning the first shared
+/// [`SubscriptionId`] if found.
+fn validate_subscription_ids<Exchange, Kind>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+) -> Result<(), SubscriptionId>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    // Subscriptions are deduplicated, so any shared SubscriptionId belongs to distinct Subscriptions
+    let mut ids = HashSet::with_capacity(subscriptions.len());
+
+    for subscription in subscriptions {
+        let subscription_id =
+            ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription).id();
+
+        if !ids.insert(subscription_id.clone()) {
+            return Err(subscription_id);
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,4 +268,134 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_validate_candles() {
+        use crate::{
+            exchange::binance::{futures::BinanceFuturesUsd, spot::BinanceSpot},
+            subscription::candle::{CandleInterval, Candles},
+        };
+
+        struct TestCase {
+            input: Result<(), DataError>,
+            expected: Result<(), SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Valid BinanceSpot 1s Candles sub
+                input: validate(&[Subscription::from((
+                    BinanceSpot::default(),
+                    "base",
+                    "quote",
+                    InstrumentKind::Spot,
+                    Candles::new(CandleInterval::Second1),
+                ))]),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: Valid BinanceFuturesUsd 1m Candles sub
+                input: validate(&[Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "base",
+                    "quote",
+                    InstrumentKind::Perpetual,
+                    Candles::new(CandleInterval::Minute1),
+                ))]),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC2: Invalid BinanceFuturesUsd 1s Candles sub w/ unsupported CandleInterval
+                input: validate(&[Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "base",
+                    "quote",
+                    InstrumentKind::Perpetual,
+                    Candles::new(CandleInterval::Second1),
+                ))]),
+                expected: Err(SocketError::Unsupported {
+                    entity: "binance_futures_usd",
+                    item: "1s Candles".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            match (test.input, test.expected) {
+                (Ok(_), Ok(_)) => {
+                    // Test passed
+                }
+                (Err(DataError::Socket(actual)), Err(expected)) => {
+                    assert_eq!(
+                        actual.to_string(),
+                        expected.to_string(),
+                        "TC{} failed",
+                        index
+                    )
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_subscription_ids() {
+        use crate::subscription::candle::{CandleInterval, Candles};
+
+        struct TestCase {
+            input: Vec<Subscription<Coinbase, Candles>>,
+            expected: Result<(), SubscriptionId>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Valid trade aggregated Candles subs for distinct markets
+                input: vec![
+                    Subscription::from((
+                        Coinbase,
+                        "btc",
+                        "usd",
+                        InstrumentKind::Spot,
+                        Candles::new(CandleInterval::Minute1),
+                    )),
+                    Subscription::from((
+                        Coinbase,
+                        "eth",
+                        "usd",
+                        InstrumentKind::Spot,
+                        Candles::new(CandleInterval::Minute5),
+                    )),
+                ],
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: Invalid trade aggregated Candles subs w/ different intervals for one market
+                input: vec![
+                    Subscription::from((
+                        Coinbase,
+                        "btc",
+                        "usd",
+                        InstrumentKind::Spot,
+                        Candles::new(CandleInterval::Minute1),
+                    )),
+                    Subscription::from((
+                        Coinbase,
+                        "btc",
+                        "usd",
+                        InstrumentKind::Spot,
+                        Candles::new(CandleInterval::Minute5),
+                    )),
+                ],
+                expected: Err(SubscriptionId::from("matches|BTC-USD")),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = validate_subscription_ids(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
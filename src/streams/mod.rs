@@ -1,5 +1,19 @@
 use self::builder::{multi::MultiStreamBuilder, StreamBuilder};
-use crate::{exchange::ExchangeId, subscription::SubKind};
+use crate::{
+    aggregator::{
+        book::{consolidate_books, synthesise_books_l1, ConsolidatedOrderBook},
+        candle::{aggregate_candles, CandleAggregatorConfig},
+    },
+    event::MarketEvent,
+    exchange::ExchangeId,
+    subscription::{
+        book::{OrderBook, OrderBookL1},
+        candle::Candle,
+        trade::PublicTrade,
+        SubKind,
+    },
+};
+use barter_integration::model::instrument::symbol::Symbol;
 use std::collections::HashMap;
 use tokio::sync::mpsc;
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamMap};
@@ -70,3 +84,72 @@ impl<T> Streams<T> {
             })
     }
 }
+
+impl Streams<MarketEvent<PublicTrade>> {
+    /// Aggregate every exchange [`MarketEvent<PublicTrade>`] stream into a stream of closed
+    /// [`MarketEvent<Candle>`]s using the provided [`CandleAggregatorConfig`].
+    ///
+    /// Useful for exchanges that do not offer native candle streams.
+    pub fn candles(self, config: CandleAggregatorConfig) -> Streams<MarketEvent<Candle>> {
+        let streams = self
+            .streams
+            .into_iter()
+            .map(|(exchange, trade_rx)| {
+                let (candle_tx, candle_rx) = mpsc::unbounded_channel();
+                tokio::spawn(aggregate_candles(config, trade_rx, candle_tx));
+                (exchange, candle_rx)
+            })
+            .collect();
+
+        Streams { streams }
+    }
+}
+
+impl Streams<MarketEvent<OrderBook>> {
+    /// Synthesise every exchange [`MarketEvent<OrderBook>`] stream into a stream of
+    /// [`MarketEvent<OrderBookL1>`], only yielding when the best bid or ask changes.
+    ///
+    /// This adapts the `OrderBooksL2` stream of any exchange, for example where the native top
+    /// of book stream lacks an exchange timestamp (eg/ Binance `@bookTicker`).
+    pub fn order_books_l1(self) -> Streams<MarketEvent<OrderBookL1>> {
+        let streams = self
+            .streams
+            .into_iter()
+            .map(|(exchange, book_rx)| {
+                let (l1_tx, l1_rx) = mpsc::unbounded_channel();
+                tokio::spawn(synthesise_books_l1(book_rx, l1_tx));
+                (exchange, l1_rx)
+            })
+            .collect();
+
+        Streams { streams }
+    }
+
+    /// Consolidate every exchange [`MarketEvent<OrderBook>`] stream for the provided base/quote
+    /// pair into cross-exchange [`ConsolidatedOrderBook`] [`Streams`].
+    ///
+    /// A new [`ConsolidatedOrderBook`] is yielded after every exchange [`OrderBook`] update, via
+    /// the stream of the exchange that sent the update. Use [`Streams::join`] to receive every
+    /// [`ConsolidatedOrderBook`], or [`Streams::select`] to only receive those triggered by one
+    /// exchange.
+    pub fn consolidate<S>(self, base: S, quote: S) -> Streams<ConsolidatedOrderBook>
+    where
+        S: Into<Symbol>,
+    {
+        let (consolidated_txs, streams) = self
+            .streams
+            .keys()
+            .map(|exchange| {
+                let (consolidated_tx, consolidated_rx) = mpsc::unbounded_channel();
+                ((*exchange, consolidated_tx), (*exchange, consolidated_rx))
+            })
+            .unzip();
+
+        let (base, quote) = (base.into(), quote.into());
+        tokio::spawn(async move {
+            consolidate_books(base, quote, self.join_map().await, consolidated_txs).await
+        });
+
+        Streams { streams }
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     subscription::{Map, SubKind, Subscription, SubscriptionMeta},
     Identifier,
 };
-use barter_integration::model::SubscriptionId;
+use barter_integration::model::{instrument::Instrument, SubscriptionId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -61,3 +61,46 @@ impl SubscriptionMapper for WebSocketSubMapper {
         }
     }
 }
+
+/// Build the [`Map`] linking each validated [`SubscriptionId`] to the [`SubKind`] configuration
+/// of the Barter [`Subscription`] it was generated from.
+///
+/// Some exchanges (eg/ [`Bitfinex`](crate::exchange::bitfinex::Bitfinex)) replace the
+/// [`ExchangeSub`] [`SubscriptionId`] with an exchange assigned identifier during validation,
+/// so the [`Subscription`] associated with an unknown [`SubscriptionId`] is determined via the
+/// validated [`Instrument`].
+pub(crate) fn kind_map<Exchange, Kind>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+    instrument_map: &Map<Instrument>,
+) -> Map<Kind>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let kinds = subscriptions
+        .iter()
+        .map(|subscription| {
+            (
+                ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription).id(),
+                subscription,
+            )
+        })
+        .collect::<HashMap<SubscriptionId, &Subscription<Exchange, Kind>>>();
+
+    instrument_map
+        .0
+        .iter()
+        .filter_map(|(subscription_id, instrument)| {
+            kinds
+                .get(subscription_id)
+                .copied()
+                .or_else(|| {
+                    subscriptions
+                        .iter()
+                        .find(|subscription| subscription.instrument == *instrument)
+                })
+                .map(|subscription| (subscription_id.clone(), subscription.kind.clone()))
+        })
+        .collect()
+}
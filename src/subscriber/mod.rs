@@ -11,7 +11,7 @@ use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
     model::instrument::Instrument,
-    protocol::websocket::{connect, WebSocket},
+    protocol::websocket::{connect, WebSocket, WsMessage},
 };
 use futures::SinkExt;
 use serde::{Deserialize, Serialize};
@@ -26,13 +26,17 @@ pub mod mapper;
 pub mod validator;
 
 /// Defines how to connect to a socket and subscribe to market data streams.
+///
+/// Returns the subscribed [`WebSocket`] & validated [`Map<Instrument>`], alongside any market data
+/// [`WsMessage`]s received during [`SubscriptionValidator::validate`] that must still be
+/// transformed.
 #[async_trait]
 pub trait Subscriber {
     type SubMapper: SubscriptionMapper;
 
     async fn subscribe<Exchange, Kind>(
         subscriptions: &[Subscription<Exchange, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument>), SocketError>
+    ) -> Result<(WebSocket, Map<Instrument>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubKind + Send + Sync,
@@ -49,7 +53,7 @@ impl Subscriber for WebSocketSubscriber {
 
     async fn subscribe<Exchange, Kind>(
         subscriptions: &[Subscription<Exchange, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument>), SocketError>
+    ) -> Result<(WebSocket, Map<Instrument>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubKind + Send + Sync,
@@ -77,11 +81,11 @@ impl Subscriber for WebSocketSubscriber {
         }
 
         // Validate Subscription responses
-        let map =
+        let (map, buffered) =
             Exchange::SubValidator::validate::<Exchange, Kind>(instrument_map, &mut websocket)
                 .await?;
 
         info!(%exchange, "subscribed to WebSocket");
-        Ok((websocket, map))
+        Ok((websocket, map, buffered))
     }
 }
@@ -7,7 +7,7 @@ use barter_integration::{
     error::SocketError,
     model::instrument::Instrument,
     protocol::{
-        websocket::{WebSocket, WebSocketParser},
+        websocket::{WebSocket, WebSocketParser, WsMessage},
         StreamParser,
     },
     Validator,
@@ -18,6 +18,9 @@ use tracing::debug;
 
 /// Defines how to validate that actioned market data
 /// [`Subscription`](crate::subscription::Subscription)s were accepted by the exchange.
+///
+/// Returns the validated [`Map<Instrument>`] alongside any market data [`WsMessage`]s received
+/// during validation that must still be transformed (eg/ initial OrderBook snapshots).
 #[async_trait]
 pub trait SubscriptionValidator {
     type Parser: StreamParser;
@@ -25,7 +28,7 @@ pub trait SubscriptionValidator {
     async fn validate<Exchange, Kind>(
         instrument_map: Map<Instrument>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+    ) -> Result<(Map<Instrument>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send;
@@ -42,7 +45,7 @@ impl SubscriptionValidator for WebSocketSubValidator {
     async fn validate<Exchange, Kind>(
         instrument_map: Map<Instrument>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+    ) -> Result<(Map<Instrument>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send,
@@ -58,7 +61,7 @@ impl SubscriptionValidator for WebSocketSubValidator {
             // Break if all Subscriptions were a success
             if success_responses == expected_responses {
                 debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
-                break Ok(instrument_map);
+                break Ok((instrument_map, Vec::new()));
             }
 
             tokio::select! {
@@ -2,12 +2,17 @@ use super::SubKind;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
+    number::{self, Number, ZERO},
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use barter_macro::{DeSubKind, SerSubKind};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    collections::{btree_map::Entry, BTreeMap, HashMap},
+    time::Duration,
+};
 use tracing::debug;
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 1 [`OrderBook`]
@@ -34,7 +39,7 @@ impl OrderBookL1 {
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn mid_price(&self) -> f64 {
+    pub fn mid_price(&self) -> Number {
         mid_price(self.best_bid.price, self.best_ask.price)
     }
 
@@ -42,7 +47,7 @@ impl OrderBookL1 {
     /// with their associated amount.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn volume_weighed_mid_price(&self) -> f64 {
+    pub fn volume_weighed_mid_price(&self) -> Number {
         volume_weighted_mid_price(self.best_bid, self.best_ask)
     }
 }
@@ -51,23 +56,364 @@ impl OrderBookL1 {
 /// [`MarketEvent<T>`](crate::event::MarketEvent) events.
 ///
 /// Level 2 refers to the [`OrderBook`] aggregated by price.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
-pub struct OrderBooksL2;
+///
+/// By default every update yields a full depth [`OrderBook`] snapshot. Optionally:
+/// - `depth`: truncate each yielded [`OrderBook`] snapshot to the best N [`Level`]s per side.
+/// - `interval`: yield at most one [`OrderBook`] snapshot per interval, conflating the updates
+///   received in between into the next yielded snapshot. A conflated snapshot is flushed once the
+///   interval elapses, even if no further updates are received.
+/// - `snapshot_depth`: number of [`Level`]s requested in the initial HTTP [`OrderBook`] snapshot
+///   for exchanges that require one (eg/ Binance), otherwise the exchange default is used.
+///   Subscriptions to exchanges without a HTTP [`OrderBook`] snapshot reject a `snapshot_depth`.
+///
+/// ### Notes
+/// Serialises as `"order_books_l2"` when no options are configured, otherwise as
+/// `{ "depth": usize, "interval_ms": u64, "snapshot_depth": usize }` with every field optional.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct OrderBooksL2 {
+    pub depth: Option<usize>,
+    pub interval: Option<Duration>,
+    pub snapshot_depth: Option<usize>,
+}
 
 impl SubKind for OrderBooksL2 {
     type Event = OrderBook;
 }
 
-/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 3 [`OrderBook`]
-/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+impl OrderBooksL2 {
+    /// [`OrderBooksL2`] serialised identifier when no options are configured.
+    const ID: &'static str = "order_books_l2";
+
+    /// Construct a new [`OrderBooksL2`] with no options configured, yielding a full depth
+    /// [`OrderBook`] snapshot per update.
+    pub const fn new() -> Self {
+        Self {
+            depth: None,
+            interval: None,
+            snapshot_depth: None,
+        }
+    }
+
+    /// Truncate each yielded [`OrderBook`] snapshot to the best `depth` [`Level`]s per side.
+    pub fn with_depth(self, depth: usize) -> Self {
+        Self {
+            depth: Some(depth),
+            ..self
+        }
+    }
+
+    /// Yield at most one [`OrderBook`] snapshot per `interval`, conflating the updates received
+    /// in between.
+    pub fn with_interval(self, interval: Duration) -> Self {
+        Self {
+            interval: Some(interval),
+            ..self
+        }
+    }
+
+    /// Request `snapshot_depth` [`Level`]s in the initial HTTP [`OrderBook`] snapshot, for
+    /// exchanges that require one.
+    pub fn with_snapshot_depth(self, snapshot_depth: usize) -> Self {
+        Self {
+            snapshot_depth: Some(snapshot_depth),
+            ..self
+        }
+    }
+
+    /// Validate the configured `snapshot_depth` (if any) satisfies the exchange specific
+    /// `is_supported` HTTP [`OrderBook`] snapshot limits.
+    ///
+    /// Returns the unsupported configuration as an `Err` description, see
+    /// [`StreamSelector::validate_kind`](crate::exchange::StreamSelector::validate_kind).
+    pub fn validate_snapshot_depth(
+        &self,
+        is_supported: impl FnOnce(usize) -> bool,
+    ) -> Result<(), String> {
+        match self.snapshot_depth {
+            Some(snapshot_depth) if !is_supported(snapshot_depth) => {
+                Err(format!("OrderBooksL2 snapshot_depth {snapshot_depth}"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Validate no `snapshot_depth` is configured, for exchanges that send the initial
+    /// [`OrderBook`] snapshot via WebSocket rather than HTTP.
+    pub fn validate_no_snapshot_depth(&self) -> Result<(), String> {
+        self.validate_snapshot_depth(|_| false)
+    }
+}
+
+/// Configured [`OrderBooksL2`] options serialisation format.
+#[derive(Deserialize, Serialize)]
+struct OrderBooksL2Options {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    depth: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    interval_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    snapshot_depth: Option<usize>,
+}
+
+impl<'de> Deserialize<'de> for OrderBooksL2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Input {
+            Id(String),
+            Options(OrderBooksL2Options),
+        }
+
+        match Input::deserialize(deserializer)? {
+            Input::Id(id) if id == Self::ID => Ok(Self::default()),
+            Input::Id(id) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&id),
+                &Self::ID,
+            )),
+            Input::Options(options) => Ok(Self {
+                depth: options.depth,
+                interval: options.interval_ms.map(Duration::from_millis),
+                snapshot_depth: options.snapshot_depth,
+            }),
+        }
+    }
+}
+
+impl Serialize for OrderBooksL2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if *self == Self::default() {
+            return serializer.serialize_str(Self::ID);
+        }
+
+        OrderBooksL2Options {
+            depth: self.depth,
+            interval_ms: self
+                .interval
+                .map(|interval| interval.as_millis().try_into().unwrap_or(u64::MAX)),
+            snapshot_depth: self.snapshot_depth,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 3
+/// [`OrderBookL3Event`] [`MarketEvent<T>`](crate::event::MarketEvent) events.
 ///
 /// Level 3 refers to the non-aggregated [`OrderBook`]. This is a direct replication of the exchange
 /// [`OrderBook`].
+///
+/// Rather than a full [`OrderBookL3`] snapshot per update, an initial
+/// [`OrderBookL3Event::Snapshot`] is followed by [`OrderBookL3Event::Update`]s containing only
+/// the changed [`Order`]s.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
 pub struct OrderBooksL3;
 
 impl SubKind for OrderBooksL3 {
-    type Event = OrderBook;
+    type Event = OrderBookL3Event;
+}
+
+/// Normalised Barter level 3 [`OrderBookL3Event`] yielded by [`OrderBooksL3`] streams.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum OrderBookL3Event {
+    /// Full [`OrderBookL3`] snapshot that subsequent [`OrderBookL3Delta`]s are applied to.
+    Snapshot(OrderBookL3),
+    /// [`Order`]s that changed since the previous [`OrderBookL3Event`].
+    Update(OrderBookL3Delta),
+}
+
+impl OrderBookL3Event {
+    /// Return the exchange time this [`OrderBookL3Event`] was last updated.
+    pub fn last_update_time(&self) -> DateTime<Utc> {
+        match self {
+            Self::Snapshot(book) => book.last_update_time,
+            Self::Update(delta) => delta.last_update_time,
+        }
+    }
+}
+
+/// Normalised Barter [`OrderBookL3`] delta containing the [`Order`]s that changed since the
+/// previous [`OrderBookL3Event`].
+///
+/// Removed [`Order`]s have a zero amount, so a delta can be applied to a local [`OrderBookL3`]
+/// via [`OrderBookL3::apply`]. The `sequence` is the exchange sequence after the delta, if the
+/// exchange provides one.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OrderBookL3Delta {
+    pub last_update_time: DateTime<Utc>,
+    pub sequence: Option<u64>,
+    pub orders: Vec<Order>,
+}
+
+impl OrderBookL3Delta {
+    /// Determine if this [`OrderBookL3Delta`] contains no changed [`Order`]s.
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+}
+
+/// Normalised Barter level 3 [`OrderBook`] containing every individual resting [`Order`], keyed
+/// by the exchange order id.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct OrderBookL3 {
+    pub last_update_time: DateTime<Utc>,
+    pub orders: HashMap<String, Order>,
+}
+
+impl OrderBookL3 {
+    /// Construct a new [`Self`] with the [`Order`]s provided.
+    pub fn new<Iter>(last_update_time: DateTime<Utc>, orders: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = Order>,
+    {
+        Self {
+            last_update_time,
+            orders: orders
+                .into_iter()
+                .map(|order| (order.id.clone(), order))
+                .collect(),
+        }
+    }
+
+    /// Upsert an [`Order`] into this [`OrderBookL3`], replacing any existing [`Order`] with the
+    /// same id.
+    pub fn upsert(&mut self, order: Order) {
+        self.orders.insert(order.id.clone(), order);
+    }
+
+    /// Remove the [`Order`] with the provided id from this [`OrderBookL3`], if it exists.
+    pub fn remove(&mut self, id: &str) -> Option<Order> {
+        self.orders.remove(id)
+    }
+
+    /// Reduce the amount of the [`Order`] with the provided id (eg/ after a partial fill),
+    /// removing it if no amount remains.
+    ///
+    /// Returns the reduced [`Order`] if it exists, with a zero amount if it was removed.
+    pub fn reduce(&mut self, id: &str, amount: Number) -> Option<Order> {
+        let order = self.orders.get_mut(id)?;
+        order.amount -= amount;
+
+        if order.amount > ZERO {
+            return Some(order.clone());
+        }
+
+        self.orders.remove(id).map(|order| Order {
+            amount: ZERO,
+            ..order
+        })
+    }
+
+    /// Apply an [`OrderBookL3Delta`] to this [`OrderBookL3`], removing every [`Order`] with a
+    /// zero amount and upserting the rest.
+    pub fn apply(&mut self, delta: OrderBookL3Delta) {
+        self.last_update_time = delta.last_update_time;
+
+        for order in delta.orders {
+            if order.amount <= ZERO {
+                self.remove(&order.id);
+            } else {
+                self.upsert(order);
+            }
+        }
+    }
+
+    /// Return an iterator over the [`Order`]s of the provided [`Side`], in no particular order.
+    pub fn orders(&self, side: Side) -> impl Iterator<Item = &Order> {
+        self.orders.values().filter(move |order| order.side == side)
+    }
+
+    /// Aggregate this [`OrderBookL3`] into a level 2 [`OrderBook`] by summing the amount of every
+    /// [`Order`] at each price.
+    pub fn l2(&self) -> OrderBook {
+        let aggregate = |side: Side| {
+            let levels =
+                self.orders(side)
+                    .fold(BTreeMap::<LevelKey, Level>::new(), |mut levels, order| {
+                        levels
+                            .entry(LevelKey::new(side, order.price))
+                            .or_insert_with(|| Level::new(order.price, ZERO))
+                            .amount += order.amount;
+                        levels
+                    });
+
+            OrderBookSide { side, levels }
+        };
+
+        OrderBook {
+            last_update_time: self.last_update_time,
+            bids: aggregate(Side::Buy),
+            asks: aggregate(Side::Sell),
+        }
+    }
+}
+
+/// Normalised Barter individual resting [`OrderBookL3`] order.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Order {
+    pub id: String,
+    pub side: Side,
+    pub price: Number,
+    pub amount: Number,
+    pub time: DateTime<Utc>,
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 2
+/// [`OrderBookEvent`] [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Rather than a full [`OrderBook`] snapshot per update, an initial [`OrderBookEvent::Snapshot`]
+/// is followed by [`OrderBookEvent::Update`]s containing only the changed [`Level`]s.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct OrderBooksL2Delta;
+
+impl SubKind for OrderBooksL2Delta {
+    type Event = OrderBookEvent;
+}
+
+/// Normalised Barter level 2 [`OrderBookEvent`] yielded by [`OrderBooksL2Delta`] streams.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
+pub enum OrderBookEvent {
+    /// Full [`OrderBook`] snapshot that subsequent [`OrderBookDelta`]s are applied to.
+    Snapshot(OrderBook),
+    /// [`Level`]s that changed since the previous [`OrderBookEvent`].
+    Update(OrderBookDelta),
+}
+
+impl OrderBookEvent {
+    /// Return the exchange time this [`OrderBookEvent`] was last updated.
+    pub fn last_update_time(&self) -> DateTime<Utc> {
+        match self {
+            Self::Snapshot(book) => book.last_update_time,
+            Self::Update(delta) => delta.last_update_time,
+        }
+    }
+}
+
+/// Normalised Barter [`OrderBook`] delta containing the [`Level`]s that changed since the
+/// previous [`OrderBookEvent`].
+///
+/// Removed [`Level`]s have a zero amount, so a delta can be applied to a local [`OrderBook`]
+/// via [`OrderBookSide::upsert`]. The `prev_sequence` & `sequence` are the exchange update ids
+/// before & after the delta, if the exchange provides them.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
+pub struct OrderBookDelta {
+    pub last_update_time: DateTime<Utc>,
+    pub prev_sequence: Option<u64>,
+    pub sequence: Option<u64>,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+impl OrderBookDelta {
+    /// Determine if this [`OrderBookDelta`] contains no changed [`Level`]s.
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
 }
 
 /// Normalised Barter [`OrderBook`] snapshot.
@@ -79,19 +425,45 @@ pub struct OrderBook {
 }
 
 impl OrderBook {
-    /// Generate an [`OrderBook`] snapshot by cloning [`Self`] after sorting each [`OrderBookSide`].
-    pub fn snapshot(&mut self) -> Self {
-        // Sort OrderBook & Clone
-        self.bids.sort();
-        self.asks.sort();
+    /// Generate an [`OrderBook`] snapshot by cloning [`Self`].
+    ///
+    /// Each [`OrderBookSide`] is always ordered best [`Level`] first, so no sorting is required.
+    pub fn snapshot(&self) -> Self {
         self.clone()
     }
 
+    /// Generate an [`OrderBookL1`] from the best bid and ask [`Level`]s of this [`OrderBook`].
+    ///
+    /// Returns `None` if either side of the [`OrderBook`] is empty.
+    pub fn l1(&self) -> Option<OrderBookL1> {
+        Some(OrderBookL1 {
+            last_update_time: self.last_update_time,
+            best_bid: *self.bids.best()?,
+            best_ask: *self.asks.best()?,
+        })
+    }
+
+    /// Truncate both sides of this [`OrderBook`] to the best `depth` [`Level`]s.
+    pub fn truncate(&mut self, depth: usize) {
+        self.bids.truncate(depth);
+        self.asks.truncate(depth);
+    }
+
+    /// Generate an [`OrderBook`] snapshot of the best `depth` [`Level`]s of each side, without
+    /// cloning the remaining [`Level`]s.
+    pub fn snapshot_truncated(&self, depth: usize) -> Self {
+        Self {
+            last_update_time: self.last_update_time,
+            bids: self.bids.top(depth),
+            asks: self.asks.top(depth),
+        }
+    }
+
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn mid_price(&self) -> Option<f64> {
-        match (self.bids.levels.first(), self.asks.levels.first()) {
+    pub fn mid_price(&self) -> Option<Number> {
+        match (self.bids.best(), self.asks.best()) {
             (Some(best_bid), Some(best_ask)) => Some(mid_price(best_bid.price, best_ask.price)),
             (Some(best_bid), None) => Some(best_bid.price),
             (None, Some(best_ask)) => Some(best_ask.price),
@@ -103,8 +475,8 @@ impl OrderBook {
     /// with their associated amount.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn volume_weighed_mid_price(&self) -> Option<f64> {
-        match (self.bids.levels.first(), self.asks.levels.first()) {
+    pub fn volume_weighed_mid_price(&self) -> Option<Number> {
+        match (self.bids.best(), self.asks.best()) {
             (Some(best_bid), Some(best_ask)) => {
                 Some(volume_weighted_mid_price(*best_bid, *best_ask))
             }
@@ -113,13 +485,120 @@ impl OrderBook {
             (None, None) => None,
         }
     }
+
+    /// Calculate the bid-ask spread (best ask price - best bid price).
+    ///
+    /// Returns `None` if either side of the [`OrderBook`] is empty.
+    pub fn spread(&self) -> Option<Number> {
+        Some(self.asks.best()?.price - self.bids.best()?.price)
+    }
+
+    /// Calculate the bid-ask spread in basis points of the mid price.
+    ///
+    /// Returns `None` if either side of the [`OrderBook`] is empty, or the mid price is zero.
+    pub fn spread_bps(&self) -> Option<Number> {
+        let (best_bid, best_ask) = (self.bids.best()?, self.asks.best()?);
+        let mid_price = mid_price(best_bid.price, best_ask.price);
+        if mid_price == ZERO {
+            return None;
+        }
+
+        Some((best_ask.price - best_bid.price) / mid_price * bps_scale())
+    }
+
+    /// Calculate the total bid and ask amount priced within `bps` basis points of the mid price,
+    /// returned as `(bid_amount, ask_amount)`.
+    ///
+    /// Returns `None` if either side of the [`OrderBook`] is empty.
+    pub fn depth_within(&self, bps: Number) -> Option<(Number, Number)> {
+        let mid_price = mid_price(self.bids.best()?.price, self.asks.best()?.price);
+        Some((
+            self.bids.depth_within(mid_price, bps),
+            self.asks.depth_within(mid_price, bps),
+        ))
+    }
+
+    /// Calculate the order book imbalance of the best `levels` [`Level`]s on each side, defined
+    /// as `(bid_amount - ask_amount) / (bid_amount + ask_amount)`.
+    ///
+    /// The imbalance ranges from -1 (only asks) to 1 (only bids). Returns `None` if there is no
+    /// amount on either side.
+    pub fn imbalance(&self, levels: usize) -> Option<Number> {
+        let bid_amount = sum_amount(self.bids.levels().take(levels));
+        let ask_amount = sum_amount(self.asks.levels().take(levels));
+
+        let total_amount = bid_amount + ask_amount;
+        if total_amount == ZERO {
+            return None;
+        }
+
+        Some((bid_amount - ask_amount) / total_amount)
+    }
+
+    /// Calculate the volume weighted average price of an order of the provided [`Side`] &
+    /// quantity, if it were to be filled immediately against the opposite [`OrderBookSide`]
+    /// (ie/ [`Side::Buy`] orders consume asks).
+    ///
+    /// Returns `None` if the [`OrderBook`] is too thin to fill the quantity.
+    pub fn vwap_for_quantity(&self, side: Side, quantity: Number) -> Option<Number> {
+        self.taker_side(side).vwap_for_quantity(quantity)
+    }
+
+    /// Calculate the price impact in basis points of the mid price of an order of the provided
+    /// [`Side`] & notional, if it were to be filled immediately against the opposite
+    /// [`OrderBookSide`] (ie/ [`Side::Buy`] orders consume asks).
+    ///
+    /// A positive price impact is a cost relative to the mid price. Returns `None` if either
+    /// side of the [`OrderBook`] is empty, or it is too thin to fill the notional.
+    pub fn price_impact(&self, side: Side, notional: Number) -> Option<Number> {
+        let mid_price = mid_price(self.bids.best()?.price, self.asks.best()?.price);
+        if mid_price == ZERO {
+            return None;
+        }
+
+        let vwap = self.taker_side(side).vwap_for_notional(notional)?;
+        let impact = match side {
+            Side::Buy => vwap - mid_price,
+            Side::Sell => mid_price - vwap,
+        };
+
+        Some(impact / mid_price * bps_scale())
+    }
+
+    /// Generate the cumulative depth curve of the bids ([`Side::Buy`]) or asks ([`Side::Sell`])
+    /// of this [`OrderBook`], best first.
+    ///
+    /// See [`OrderBookSide::cumulative_depth`].
+    pub fn cumulative_depth(&self, side: Side) -> Vec<Level> {
+        match side {
+            Side::Buy => self.bids.cumulative_depth(),
+            Side::Sell => self.asks.cumulative_depth(),
+        }
+    }
+
+    /// Return the [`OrderBookSide`] an order of the provided [`Side`] would be filled against.
+    fn taker_side(&self, side: Side) -> &OrderBookSide {
+        match side {
+            Side::Buy => &self.asks,
+            Side::Sell => &self.bids,
+        }
+    }
 }
 
 /// Normalised Barter [`Level`]s for one [`Side`] of the [`OrderBook`].
+///
+/// [`Level`]s are stored in a [`BTreeMap`] keyed by a total ordered [`LevelKey`] that is
+/// oriented by [`Side`], so upserts are O(log n) and iteration always yields the best [`Level`]
+/// first without sorting.
+///
+/// ### Notes
+/// Serialises as `{ "side": Side, "levels": [Level] }`, with the [`Level`]s ordered best first.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
+#[serde(from = "OrderBookSideLevels")]
 pub struct OrderBookSide {
     side: Side,
-    levels: Vec<Level>,
+    #[serde(serialize_with = "se_levels")]
+    levels: BTreeMap<LevelKey, Level>,
 }
 
 impl OrderBookSide {
@@ -131,7 +610,13 @@ impl OrderBookSide {
     {
         Self {
             side,
-            levels: levels.into_iter().map(L::into).collect(),
+            levels: levels
+                .into_iter()
+                .map(|level| {
+                    let level = level.into();
+                    (LevelKey::new(side, level.price), level)
+                })
+                .collect(),
         }
     }
 
@@ -157,63 +642,264 @@ impl OrderBookSide {
     /// 2a) New value is > 0, insert new level
     /// 2b) New value is 0, log error and continue
     pub fn upsert_single<L>(&mut self, new_level: L)
+    where
+        L: Into<Level>,
+    {
+        self.upsert_single_delta(new_level);
+    }
+
+    /// Upsert a collection of [`Level`]s into this [`OrderBookSide`], returning the [`Level`]s
+    /// that changed it in the order they were applied.
+    ///
+    /// Removed [`Level`]s are returned with a zero amount, such that upserting the returned
+    /// [`Level`]s into a copy of the previous [`OrderBookSide`] yields [`Self`].
+    pub fn upsert_delta<Iter, L>(&mut self, levels: Iter) -> Vec<Level>
+    where
+        Iter: IntoIterator<Item = L>,
+        L: Into<Level>,
+    {
+        levels
+            .into_iter()
+            .filter_map(|level| self.upsert_single_delta(level))
+            .collect()
+    }
+
+    /// Upsert a single [`Level`] into this [`OrderBookSide`] (see [`Self::upsert_single`]),
+    /// returning the [`Level`] if it changed [`Self`].
+    pub fn upsert_single_delta<L>(&mut self, new_level: L) -> Option<Level>
     where
         L: Into<Level>,
     {
         let new_level = new_level.into();
 
-        match self
-            .levels
-            .iter_mut()
-            .enumerate()
-            .find(|(_index, level)| level.eq_price(new_level.price))
-        {
+        match self.levels.entry(LevelKey::new(self.side, new_level.price)) {
             // Scenario 1a: Level exists & new value is 0 => remove Level
-            Some((index, _)) if new_level.amount == 0.0 => {
-                self.levels.remove(index);
+            Entry::Occupied(level) if new_level.amount == ZERO => {
+                level.remove();
+                Some(new_level)
             }
 
             // Scenario 1b: Level exists & new value is > 0 => replace Level
-            Some((_, level)) => {
-                *level = new_level;
+            Entry::Occupied(mut level) => {
+                (level.insert(new_level).amount != new_level.amount).then_some(new_level)
             }
 
             // Scenario 2a: Level does not exist & new value > 0 => insert new Level
-            None if new_level.amount > 0.0 => self.levels.push(new_level),
+            Entry::Vacant(level) if new_level.amount > ZERO => {
+                level.insert(new_level);
+                Some(new_level)
+            }
 
             // Scenario 2b: Level does not exist & new value is 0 => log error & continue
-            _ => {
+            Entry::Vacant(_) => {
                 debug!(
                     ?new_level,
                     side = %self.side,
                     "Level to remove not found",
                 );
+                None
             }
-        };
+        }
     }
 
     /// Sort this [`OrderBookSide`] (bids are reversed).
-    pub fn sort(&mut self) {
-        // Sort Levels
-        self.levels.sort_unstable();
+    ///
+    /// No-op retained for backwards compatibility, since [`Level`]s are now always ordered best
+    /// first.
+    #[deprecated(
+        note = "OrderBookSide Levels are always ordered best first, so sorting is a no-op"
+    )]
+    pub fn sort(&mut self) {}
+
+    /// Return the [`Side`] of this [`OrderBookSide`].
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    /// Return the best [`Level`] of this [`OrderBookSide`] (highest bid or lowest ask).
+    pub fn best(&self) -> Option<&Level> {
+        self.levels.values().next()
+    }
+
+    /// Return an iterator over the [`Level`]s of this [`OrderBookSide`], best first.
+    pub fn levels(&self) -> impl DoubleEndedIterator<Item = &Level> + ExactSizeIterator {
+        self.levels.values()
+    }
+
+    /// Calculate the total amount of the [`Level`]s priced within `bps` basis points of the
+    /// provided reference price (below it for bids, above it for asks).
+    pub fn depth_within(&self, reference_price: Number, bps: Number) -> Number {
+        let threshold = reference_price * bps / bps_scale();
+        let within = |level: &&Level| match self.side {
+            Side::Buy => level.price >= reference_price - threshold,
+            Side::Sell => level.price <= reference_price + threshold,
+        };
+
+        sum_amount(self.levels().take_while(within))
+    }
+
+    /// Generate the cumulative depth curve of this [`OrderBookSide`], best first.
+    ///
+    /// Each returned [`Level`] amount is the total amount available at that price or better.
+    pub fn cumulative_depth(&self) -> Vec<Level> {
+        self.levels()
+            .scan(ZERO, |cumulative_amount, level| {
+                *cumulative_amount += level.amount;
+                Some(Level::new(level.price, *cumulative_amount))
+            })
+            .collect()
+    }
+
+    /// Calculate the volume weighted average price of filling the provided quantity against
+    /// this [`OrderBookSide`], best [`Level`] first.
+    ///
+    /// Returns `None` if the quantity is not positive, or exceeds the total amount available.
+    pub fn vwap_for_quantity(&self, quantity: Number) -> Option<Number> {
+        if quantity <= ZERO {
+            return None;
+        }
+
+        let mut remaining = quantity;
+        let mut notional = ZERO;
+        for level in self.levels() {
+            let fill = remaining.min(level.amount);
+            notional += fill * level.price;
+            remaining -= fill;
+
+            if remaining <= ZERO {
+                return Some(notional / quantity);
+            }
+        }
+
+        None
+    }
+
+    /// Calculate the volume weighted average price of filling the provided notional (price *
+    /// amount) against this [`OrderBookSide`], best [`Level`] first.
+    ///
+    /// Returns `None` if the notional is not positive, or exceeds the total notional available.
+    pub fn vwap_for_notional(&self, notional: Number) -> Option<Number> {
+        if notional <= ZERO {
+            return None;
+        }
+
+        let mut remaining = notional;
+        let mut quantity = ZERO;
+        for level in self.levels() {
+            let level_notional = level.price * level.amount;
+            if level_notional >= remaining {
+                quantity += remaining / level.price;
+                return Some(notional / quantity);
+            }
+
+            quantity += level.amount;
+            remaining -= level_notional;
+        }
+
+        None
+    }
+
+    /// Generate a new [`OrderBookSide`] containing the best `depth` [`Level`]s of this
+    /// [`OrderBookSide`].
+    pub fn top(&self, depth: usize) -> Self {
+        Self {
+            side: self.side,
+            levels: self
+                .levels
+                .iter()
+                .take(depth)
+                .map(|(key, level)| (*key, *level))
+                .collect(),
+        }
+    }
+
+    /// Truncate this [`OrderBookSide`] to the best `depth` [`Level`]s.
+    pub fn truncate(&mut self, depth: usize) {
+        while self.levels.len() > depth {
+            self.levels.pop_last();
+        }
+    }
+
+    /// Truncate this [`OrderBookSide`] to the best `depth` [`Level`]s, returning the removed
+    /// [`Level`]s with a zero amount.
+    pub fn truncate_delta(&mut self, depth: usize) -> Vec<Level> {
+        let mut removed = Vec::with_capacity(self.levels.len().saturating_sub(depth));
+        while self.levels.len() > depth {
+            if let Some((_, level)) = self.levels.pop_last() {
+                removed.push(Level::new(level.price, ZERO));
+            }
+        }
+        removed
+    }
+}
 
-        // Reverse Bids
-        if let Side::Buy = self.side {
-            self.levels.reverse();
+/// Total ordered [`OrderBookSide`] key derived from a [`Level`] price.
+///
+/// The key is oriented by [`Side`] (bid prices are negated) so the best [`Level`] is always
+/// the first entry of the [`BTreeMap`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LevelKey(Number);
+
+impl LevelKey {
+    pub(crate) fn new(side: Side, price: Number) -> Self {
+        match side {
+            Side::Buy => Self(-price),
+            Side::Sell => Self(price),
         }
     }
 }
 
+impl Ord for LevelKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        number::total_cmp(&self.0, &other.0)
+    }
+}
+
+impl PartialOrd for LevelKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for LevelKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for LevelKey {}
+
+/// Serialised representation of an [`OrderBookSide`] used for deserialisation.
+#[derive(Deserialize)]
+struct OrderBookSideLevels {
+    side: Side,
+    levels: Vec<Level>,
+}
+
+impl From<OrderBookSideLevels> for OrderBookSide {
+    fn from(side: OrderBookSideLevels) -> Self {
+        Self::new(side.side, side.levels)
+    }
+}
+
+/// Serialise the [`OrderBookSide`] [`Level`]s as a sequence ordered best first.
+fn se_levels<S>(levels: &BTreeMap<LevelKey, Level>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_seq(levels.values())
+}
+
 /// Normalised Barter OrderBook [`Level`].
 #[derive(Clone, Copy, PartialEq, Debug, Default, Deserialize, Serialize)]
 pub struct Level {
-    pub price: f64,
-    pub amount: f64,
+    pub price: Number,
+    pub amount: Number,
 }
 
 impl<T> From<(T, T)> for Level
 where
-    T: Into<f64>,
+    T: Into<Number>,
 {
     fn from((price, amount): (T, T)) -> Self {
         Self::new(price, amount)
@@ -241,7 +927,7 @@ impl Eq for Level {}
 impl Level {
     pub fn new<T>(price: T, amount: T) -> Self
     where
-        T: Into<f64>,
+        T: Into<Number>,
     {
         Self {
             price: price.into(),
@@ -249,26 +935,44 @@ impl Level {
         }
     }
 
-    pub fn eq_price(&self, price: f64) -> bool {
-        let diff = (price - self.price).abs();
-        f64::EPSILON > diff
+    /// Determine if the provided price is equal to the price of this [`Level`].
+    ///
+    /// ### Notes
+    /// - `f64` prices are compared within `f64::EPSILON`, whereas "decimal" feature prices are
+    ///   compared exactly.
+    pub fn eq_price(&self, price: Number) -> bool {
+        #[cfg(not(feature = "decimal"))]
+        return f64::EPSILON > (price - self.price).abs();
+
+        #[cfg(feature = "decimal")]
+        return price == self.price;
     }
 }
 
 // Todo: Add tests
 
+/// Number of basis points in one unit.
+fn bps_scale() -> Number {
+    Number::from(10_000u16)
+}
+
+/// Sum the amount of the provided [`Level`]s.
+fn sum_amount<'a>(levels: impl Iterator<Item = &'a Level>) -> Number {
+    levels.fold(ZERO, |total, level| total + level.amount)
+}
+
 /// Calculate the mid price by taking the average of the best bid and ask prices.
 ///
 /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-pub fn mid_price(best_bid_price: f64, best_ask_price: f64) -> f64 {
-    (best_bid_price + best_ask_price) / 2.0
+pub fn mid_price(best_bid_price: Number, best_ask_price: Number) -> Number {
+    (best_bid_price + best_ask_price) / Number::from(2u8)
 }
 
 /// Calculate the volume weighted mid price (micro-price), weighing the best bid and ask prices
 /// with their associated amount.
 ///
 /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-pub fn volume_weighted_mid_price(best_bid: Level, best_ask: Level) -> f64 {
+pub fn volume_weighted_mid_price(best_bid: Level, best_ask: Level) -> Number {
     ((best_bid.price * best_ask.amount) + (best_ask.price * best_bid.amount))
         / (best_bid.amount + best_ask.amount)
 }
@@ -285,9 +989,114 @@ impl From<(ExchangeId, Instrument, OrderBook)> for MarketIter<OrderBook> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl From<(ExchangeId, Instrument, OrderBookL3Event)> for MarketIter<OrderBookL3Event> {
+    fn from((exchange_id, instrument, event): (ExchangeId, Instrument, OrderBookL3Event)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: event.last_update_time(),
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: event,
+        })])
+    }
+}
+
+impl From<(ExchangeId, Instrument, OrderBookEvent)> for MarketIter<OrderBookEvent> {
+    fn from((exchange_id, instrument, event): (ExchangeId, Instrument, OrderBookEvent)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: event.last_update_time(),
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: event,
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod order_books_l2 {
+        use super::*;
+
+        #[test]
+        fn test_de_order_books_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OrderBooksL2, ()>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: identifier without options
+                    input: r#""order_books_l2""#,
+                    expected: Ok(OrderBooksL2::default()),
+                },
+                TestCase {
+                    // TC1: all options configured
+                    input: r#"{"depth": 20, "interval_ms": 250, "snapshot_depth": 1000}"#,
+                    expected: Ok(OrderBooksL2::default()
+                        .with_depth(20)
+                        .with_interval(Duration::from_millis(250))
+                        .with_snapshot_depth(1000)),
+                },
+                TestCase {
+                    // TC2: some options configured
+                    input: r#"{"depth": 20}"#,
+                    expected: Ok(OrderBooksL2::default().with_depth(20)),
+                },
+                TestCase {
+                    // TC3: invalid identifier
+                    input: r#""order_books_l3""#,
+                    expected: Err(()),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OrderBooksL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => assert_eq!(actual, expected, "TC{index} failed"),
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_ser_order_books_l2() {
+            struct TestCase {
+                input: OrderBooksL2,
+                expected: serde_json::Value,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: no options configured
+                    input: OrderBooksL2::default(),
+                    expected: serde_json::json!("order_books_l2"),
+                },
+                TestCase {
+                    // TC1: some options configured
+                    input: OrderBooksL2::default()
+                        .with_depth(20)
+                        .with_interval(Duration::from_millis(250)),
+                    expected: serde_json::json!({"depth": 20, "interval_ms": 250}),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::to_value(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+    }
 
     mod order_book_l1 {
         use super::*;
@@ -296,7 +1105,7 @@ mod tests {
         fn test_mid_price() {
             struct TestCase {
                 input: OrderBookL1,
-                expected: f64,
+                expected: Number,
             }
 
             let tests = vec![
@@ -304,28 +1113,28 @@ mod tests {
                     // TC0
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
-                        best_bid: Level::new(100, 999999),
-                        best_ask: Level::new(200, 1),
+                        best_bid: Level::new(num!(100), num!(999999)),
+                        best_ask: Level::new(num!(200), num!(1)),
                     },
-                    expected: 150.0,
+                    expected: num!(150.0),
                 },
                 TestCase {
                     // TC1
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
-                        best_bid: Level::new(50, 1),
-                        best_ask: Level::new(250, 999999),
+                        best_bid: Level::new(num!(50), num!(1)),
+                        best_ask: Level::new(num!(250), num!(999999)),
                     },
-                    expected: 150.0,
+                    expected: num!(150.0),
                 },
                 TestCase {
                     // TC2
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
-                        best_bid: Level::new(10, 999999),
-                        best_ask: Level::new(250, 999999),
+                        best_bid: Level::new(num!(10), num!(999999)),
+                        best_ask: Level::new(num!(250), num!(999999)),
                     },
-                    expected: 130.0,
+                    expected: num!(130.0),
                 },
             ];
 
@@ -338,7 +1147,7 @@ mod tests {
         fn test_volume_weighted_mid_price() {
             struct TestCase {
                 input: OrderBookL1,
-                expected: f64,
+                expected: Number,
             }
 
             let tests = vec![
@@ -346,28 +1155,28 @@ mod tests {
                     // TC0: volume the same so should be equal to non-weighted mid price
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
-                        best_bid: Level::new(100, 100),
-                        best_ask: Level::new(200, 100),
+                        best_bid: Level::new(num!(100), num!(100)),
+                        best_ask: Level::new(num!(200), num!(100)),
                     },
-                    expected: 150.0,
+                    expected: num!(150.0),
                 },
                 TestCase {
                     // TC1: volume affects mid-price
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
-                        best_bid: Level::new(100, 600),
-                        best_ask: Level::new(200, 1000),
+                        best_bid: Level::new(num!(100), num!(600)),
+                        best_ask: Level::new(num!(200), num!(1000)),
                     },
-                    expected: 137.5,
+                    expected: num!(137.5),
                 },
                 TestCase {
                     // TC2: volume the same and price the same
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
-                        best_bid: Level::new(1000, 999999),
-                        best_ask: Level::new(1000, 999999),
+                        best_bid: Level::new(num!(1000), num!(999999)),
+                        best_ask: Level::new(num!(1000), num!(999999)),
                     },
-                    expected: 1000.0,
+                    expected: num!(1000.0),
                 },
             ];
 
@@ -388,7 +1197,7 @@ mod tests {
         fn test_mid_price() {
             struct TestCase {
                 input: OrderBook,
-                expected: Option<f64>,
+                expected: Option<Number>,
             }
 
             let tests = vec![
@@ -396,14 +1205,8 @@ mod tests {
                     // TC0: no levels so 0.0 mid-price
                     input: OrderBook {
                         last_update_time: Default::default(),
-                        bids: OrderBookSide {
-                            side: Side::Buy,
-                            levels: vec![],
-                        },
-                        asks: OrderBookSide {
-                            side: Side::Sell,
-                            levels: vec![],
-                        },
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
                     },
                     expected: None,
                 },
@@ -411,46 +1214,52 @@ mod tests {
                     // TC1: no asks in the book so take best bid price
                     input: OrderBook {
                         last_update_time: Default::default(),
-                        bids: OrderBookSide {
-                            side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
-                        },
-                        asks: OrderBookSide {
-                            side: Side::Sell,
-                            levels: vec![],
-                        },
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![
+                                Level::new(num!(100.0), num!(100.0)),
+                                Level::new(num!(50.0), num!(100.0)),
+                            ],
+                        ),
+                        asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
                     },
-                    expected: Some(100.0),
+                    expected: Some(num!(100.0)),
                 },
                 TestCase {
                     // TC2: no bids in the book so take ask price
                     input: OrderBook {
                         last_update_time: Default::default(),
-                        bids: OrderBookSide {
-                            side: Side::Buy,
-                            levels: vec![],
-                        },
-                        asks: OrderBookSide {
-                            side: Side::Sell,
-                            levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
-                        },
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![
+                                Level::new(num!(50.0), num!(100.0)),
+                                Level::new(num!(100.0), num!(100.0)),
+                            ],
+                        ),
                     },
-                    expected: Some(50.0),
+                    expected: Some(num!(50.0)),
                 },
                 TestCase {
                     // TC3: best bid and ask amount is the same, so regular mid-price
                     input: OrderBook {
                         last_update_time: Default::default(),
-                        bids: OrderBookSide {
-                            side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
-                        },
-                        asks: OrderBookSide {
-                            side: Side::Sell,
-                            levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
-                        },
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![
+                                Level::new(num!(100.0), num!(100.0)),
+                                Level::new(num!(50.0), num!(100.0)),
+                            ],
+                        ),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![
+                                Level::new(num!(200.0), num!(100.0)),
+                                Level::new(num!(300.0), num!(100.0)),
+                            ],
+                        ),
                     },
-                    expected: Some(150.0),
+                    expected: Some(num!(150.0)),
                 },
             ];
 
@@ -463,7 +1272,7 @@ mod tests {
         fn test_volume_weighted_mid_price() {
             struct TestCase {
                 input: OrderBook,
-                expected: Option<f64>,
+                expected: Option<Number>,
             }
 
             let tests = vec![
@@ -471,14 +1280,8 @@ mod tests {
                     // TC0: no levels so 0.0 mid-price
                     input: OrderBook {
                         last_update_time: Default::default(),
-                        bids: OrderBookSide {
-                            side: Side::Buy,
-                            levels: vec![],
-                        },
-                        asks: OrderBookSide {
-                            side: Side::Sell,
-                            levels: vec![],
-                        },
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
                     },
                     expected: None,
                 },
@@ -486,61 +1289,73 @@ mod tests {
                     // TC1: no asks in the book so take best bid price
                     input: OrderBook {
                         last_update_time: Default::default(),
-                        bids: OrderBookSide {
-                            side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
-                        },
-                        asks: OrderBookSide {
-                            side: Side::Sell,
-                            levels: vec![],
-                        },
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![
+                                Level::new(num!(100.0), num!(100.0)),
+                                Level::new(num!(50.0), num!(100.0)),
+                            ],
+                        ),
+                        asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
                     },
-                    expected: Some(100.0),
+                    expected: Some(num!(100.0)),
                 },
                 TestCase {
                     // TC2: no bids in the book so take ask price
                     input: OrderBook {
                         last_update_time: Default::default(),
-                        bids: OrderBookSide {
-                            side: Side::Buy,
-                            levels: vec![],
-                        },
-                        asks: OrderBookSide {
-                            side: Side::Sell,
-                            levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
-                        },
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![
+                                Level::new(num!(50.0), num!(100.0)),
+                                Level::new(num!(100.0), num!(100.0)),
+                            ],
+                        ),
                     },
-                    expected: Some(50.0),
+                    expected: Some(num!(50.0)),
                 },
                 TestCase {
                     // TC3: best bid and ask amount is the same, so regular mid-price
                     input: OrderBook {
                         last_update_time: Default::default(),
-                        bids: OrderBookSide {
-                            side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
-                        },
-                        asks: OrderBookSide {
-                            side: Side::Sell,
-                            levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
-                        },
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![
+                                Level::new(num!(100.0), num!(100.0)),
+                                Level::new(num!(50.0), num!(100.0)),
+                            ],
+                        ),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![
+                                Level::new(num!(200.0), num!(100.0)),
+                                Level::new(num!(300.0), num!(100.0)),
+                            ],
+                        ),
                     },
-                    expected: Some(150.0),
+                    expected: Some(num!(150.0)),
                 },
                 TestCase {
                     // TC4: valid volume weighted mid-price
                     input: OrderBook {
                         last_update_time: Default::default(),
-                        bids: OrderBookSide {
-                            side: Side::Buy,
-                            levels: vec![Level::new(100.0, 3000.0), Level::new(50.0, 100.0)],
-                        },
-                        asks: OrderBookSide {
-                            side: Side::Sell,
-                            levels: vec![Level::new(200.0, 1000.0), Level::new(300.0, 100.0)],
-                        },
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![
+                                Level::new(num!(100.0), num!(3000.0)),
+                                Level::new(num!(50.0), num!(100.0)),
+                            ],
+                        ),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![
+                                Level::new(num!(200.0), num!(1000.0)),
+                                Level::new(num!(300.0), num!(100.0)),
+                            ],
+                        ),
                     },
-                    expected: Some(175.0),
+                    expected: Some(num!(175.0)),
                 },
             ];
 
@@ -552,6 +1367,507 @@ mod tests {
                 )
             }
         }
+
+        fn book(bids: Vec<(Number, Number)>, asks: Vec<(Number, Number)>) -> OrderBook {
+            OrderBook {
+                last_update_time: Default::default(),
+                bids: OrderBookSide::new(Side::Buy, bids),
+                asks: OrderBookSide::new(Side::Sell, asks),
+            }
+        }
+
+        fn thick_book() -> OrderBook {
+            book(
+                vec![
+                    (num!(99.0), num!(1.0)),
+                    (num!(98.0), num!(2.0)),
+                    (num!(97.0), num!(3.0)),
+                ],
+                vec![
+                    (num!(101.0), num!(1.0)),
+                    (num!(102.0), num!(2.0)),
+                    (num!(103.0), num!(4.0)),
+                ],
+            )
+        }
+
+        #[test]
+        fn test_spread_bps() {
+            struct TestCase {
+                input: OrderBook,
+                expected: Option<Number>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: no asks so no spread
+                    input: book(vec![(num!(99.0), num!(1.0))], vec![]),
+                    expected: None,
+                },
+                TestCase {
+                    // TC1: spread of 2 around a mid price of 100
+                    input: thick_book(),
+                    expected: Some(num!(200.0)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(test.input.spread_bps(), test.expected, "TC{index} failed")
+            }
+        }
+
+        #[test]
+        fn test_depth_within() {
+            struct TestCase {
+                input: OrderBook,
+                bps: Number,
+                expected: Option<(Number, Number)>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: no bids so no mid price
+                    input: book(vec![], vec![(num!(101.0), num!(1.0))]),
+                    bps: num!(100.0),
+                    expected: None,
+                },
+                TestCase {
+                    // TC1: only best Levels within 1%
+                    input: thick_book(),
+                    bps: num!(100.0),
+                    expected: Some((num!(1.0), num!(1.0))),
+                },
+                TestCase {
+                    // TC2: all Levels within 3%
+                    input: thick_book(),
+                    bps: num!(300.0),
+                    expected: Some((num!(6.0), num!(7.0))),
+                },
+                TestCase {
+                    // TC3: no Levels within 0.5%
+                    input: thick_book(),
+                    bps: num!(50.0),
+                    expected: Some((num!(0.0), num!(0.0))),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.depth_within(test.bps),
+                    test.expected,
+                    "TC{index} failed"
+                )
+            }
+        }
+
+        #[test]
+        fn test_imbalance() {
+            struct TestCase {
+                input: OrderBook,
+                levels: usize,
+                expected: Option<Number>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: empty book so no imbalance
+                    input: book(vec![], vec![]),
+                    levels: 5,
+                    expected: None,
+                },
+                TestCase {
+                    // TC1: only bids
+                    input: book(vec![(num!(99.0), num!(1.0))], vec![]),
+                    levels: 5,
+                    expected: Some(num!(1.0)),
+                },
+                TestCase {
+                    // TC2: balanced best two Levels
+                    input: thick_book(),
+                    levels: 2,
+                    expected: Some(num!(0.0)),
+                },
+                TestCase {
+                    // TC3: ask heavy across all Levels
+                    input: thick_book(),
+                    levels: 3,
+                    expected: Some(num!(-1.0) / num!(13.0)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.imbalance(test.levels),
+                    test.expected,
+                    "TC{index} failed"
+                )
+            }
+        }
+
+        #[test]
+        fn test_vwap_for_quantity() {
+            struct TestCase {
+                input: OrderBook,
+                side: Side,
+                quantity: Number,
+                expected: Option<Number>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: buy filled within the best ask Level
+                    input: thick_book(),
+                    side: Side::Buy,
+                    quantity: num!(0.5),
+                    expected: Some(num!(101.0)),
+                },
+                TestCase {
+                    // TC1: buy filled across two ask Levels
+                    input: thick_book(),
+                    side: Side::Buy,
+                    quantity: num!(2.0),
+                    expected: Some(num!(101.5)),
+                },
+                TestCase {
+                    // TC2: sell filled across two bid Levels
+                    input: thick_book(),
+                    side: Side::Sell,
+                    quantity: num!(3.0),
+                    expected: Some(num!(295.0) / num!(3.0)),
+                },
+                TestCase {
+                    // TC3: buy quantity exceeds available asks
+                    input: thick_book(),
+                    side: Side::Buy,
+                    quantity: num!(10.0),
+                    expected: None,
+                },
+                TestCase {
+                    // TC4: zero quantity
+                    input: thick_book(),
+                    side: Side::Sell,
+                    quantity: num!(0.0),
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.vwap_for_quantity(test.side, test.quantity),
+                    test.expected,
+                    "TC{index} failed"
+                )
+            }
+        }
+
+        #[test]
+        fn test_price_impact() {
+            struct TestCase {
+                input: OrderBook,
+                side: Side,
+                notional: Number,
+                expected: Option<Number>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: no bids so no mid price
+                    input: book(vec![], vec![(num!(101.0), num!(1.0))]),
+                    side: Side::Buy,
+                    notional: num!(101.0),
+                    expected: None,
+                },
+                TestCase {
+                    // TC1: buy filled at the best ask, 1% above the mid price
+                    input: thick_book(),
+                    side: Side::Buy,
+                    notional: num!(101.0),
+                    expected: Some(num!(100.0)),
+                },
+                TestCase {
+                    // TC2: sell filled at the best bid, 1% below the mid price
+                    input: thick_book(),
+                    side: Side::Sell,
+                    notional: num!(99.0),
+                    expected: Some(num!(100.0)),
+                },
+                TestCase {
+                    // TC3: buy notional exceeds available asks
+                    input: thick_book(),
+                    side: Side::Buy,
+                    notional: num!(1_000_000.0),
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.price_impact(test.side, test.notional),
+                    test.expected,
+                    "TC{index} failed"
+                )
+            }
+        }
+
+        #[test]
+        fn test_cumulative_depth() {
+            struct TestCase {
+                input: OrderBook,
+                side: Side,
+                expected: Vec<Level>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: no bids
+                    input: book(vec![], vec![(num!(101.0), num!(1.0))]),
+                    side: Side::Buy,
+                    expected: vec![],
+                },
+                TestCase {
+                    // TC1: bids accumulated best first
+                    input: thick_book(),
+                    side: Side::Buy,
+                    expected: vec![
+                        Level::new(num!(99.0), num!(1.0)),
+                        Level::new(num!(98.0), num!(3.0)),
+                        Level::new(num!(97.0), num!(6.0)),
+                    ],
+                },
+                TestCase {
+                    // TC2: asks accumulated best first
+                    input: thick_book(),
+                    side: Side::Sell,
+                    expected: vec![
+                        Level::new(num!(101.0), num!(1.0)),
+                        Level::new(num!(102.0), num!(3.0)),
+                        Level::new(num!(103.0), num!(7.0)),
+                    ],
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.cumulative_depth(test.side),
+                    test.expected,
+                    "TC{index} failed"
+                )
+            }
+        }
+
+        #[test]
+        fn test_l1() {
+            struct TestCase {
+                input: OrderBook,
+                expected: Option<OrderBookL1>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: no asks in the book so no OrderBookL1
+                    input: OrderBook {
+                        last_update_time: Default::default(),
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![Level::new(num!(100.0), num!(1.0))],
+                        ),
+                        asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                    },
+                    expected: None,
+                },
+                TestCase {
+                    // TC1: best bid and ask Levels
+                    input: OrderBook {
+                        last_update_time: Default::default(),
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![
+                                Level::new(num!(50.0), num!(1.0)),
+                                Level::new(num!(100.0), num!(2.0)),
+                            ],
+                        ),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![
+                                Level::new(num!(300.0), num!(3.0)),
+                                Level::new(num!(200.0), num!(4.0)),
+                            ],
+                        ),
+                    },
+                    expected: Some(OrderBookL1 {
+                        last_update_time: Default::default(),
+                        best_bid: Level::new(num!(100.0), num!(2.0)),
+                        best_ask: Level::new(num!(200.0), num!(4.0)),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(test.input.l1(), test.expected, "TC{index} failed")
+            }
+        }
+    }
+
+    mod order_book_l3 {
+        use super::*;
+
+        fn order(id: &str, side: Side, price: Number, amount: Number) -> Order {
+            Order {
+                id: id.to_string(),
+                side,
+                price,
+                amount,
+                time: Default::default(),
+            }
+        }
+
+        #[test]
+        fn test_reduce() {
+            struct TestCase {
+                id: &'static str,
+                amount: Number,
+                expected_reduced: Option<Order>,
+                expected: Option<Number>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: partial fill reduces the Order amount
+                    id: "a",
+                    amount: num!(0.5),
+                    expected_reduced: Some(order("a", Side::Buy, num!(100.0), num!(1.5))),
+                    expected: Some(num!(1.5)),
+                },
+                TestCase {
+                    // TC1: full fill removes the Order
+                    id: "a",
+                    amount: num!(2.0),
+                    expected_reduced: Some(order("a", Side::Buy, num!(100.0), num!(0.0))),
+                    expected: None,
+                },
+                TestCase {
+                    // TC2: unknown Order id is ignored
+                    id: "unknown",
+                    amount: num!(1.0),
+                    expected_reduced: None,
+                    expected: Some(num!(2.0)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let mut book = OrderBookL3::new(
+                    Default::default(),
+                    vec![order("a", Side::Buy, num!(100.0), num!(2.0))],
+                );
+                let reduced = book.reduce(test.id, test.amount);
+                assert_eq!(reduced, test.expected_reduced, "TC{index} failed");
+                let actual = book.orders.get("a").map(|order| order.amount);
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+
+        #[test]
+        fn test_apply() {
+            struct TestCase {
+                input: Vec<Order>,
+                expected: Vec<Order>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: empty delta does not change the OrderBookL3
+                    input: vec![],
+                    expected: vec![order("a", Side::Buy, num!(100.0), num!(2.0))],
+                },
+                TestCase {
+                    // TC1: new & existing Orders are upserted
+                    input: vec![
+                        order("a", Side::Buy, num!(100.0), num!(1.0)),
+                        order("b", Side::Sell, num!(101.0), num!(3.0)),
+                    ],
+                    expected: vec![
+                        order("a", Side::Buy, num!(100.0), num!(1.0)),
+                        order("b", Side::Sell, num!(101.0), num!(3.0)),
+                    ],
+                },
+                TestCase {
+                    // TC2: Order w/ zero amount is removed
+                    input: vec![order("a", Side::Buy, num!(100.0), num!(0.0))],
+                    expected: vec![],
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let mut book = OrderBookL3::new(
+                    Default::default(),
+                    vec![order("a", Side::Buy, num!(100.0), num!(2.0))],
+                );
+                book.apply(OrderBookL3Delta {
+                    last_update_time: Default::default(),
+                    sequence: None,
+                    orders: test.input,
+                });
+
+                let mut actual = book.orders.into_values().collect::<Vec<_>>();
+                actual.sort_by(|a, b| a.id.cmp(&b.id));
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+
+        #[test]
+        fn test_l2() {
+            struct TestCase {
+                input: OrderBookL3,
+                expected: OrderBook,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: empty OrderBookL3
+                    input: OrderBookL3::default(),
+                    expected: OrderBook {
+                        last_update_time: Default::default(),
+                        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                        asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                    },
+                },
+                TestCase {
+                    // TC1: Orders at the same price are aggregated into a single Level
+                    input: OrderBookL3::new(
+                        Default::default(),
+                        vec![
+                            order("a", Side::Buy, num!(100.0), num!(1.0)),
+                            order("b", Side::Buy, num!(100.0), num!(2.0)),
+                            order("c", Side::Buy, num!(99.0), num!(1.5)),
+                            order("d", Side::Sell, num!(101.0), num!(1.0)),
+                            order("e", Side::Sell, num!(102.0), num!(3.0)),
+                            order("f", Side::Sell, num!(101.0), num!(0.5)),
+                        ],
+                    ),
+                    expected: OrderBook {
+                        last_update_time: Default::default(),
+                        bids: OrderBookSide::new(
+                            Side::Buy,
+                            vec![
+                                Level::new(num!(100.0), num!(3.0)),
+                                Level::new(num!(99.0), num!(1.5)),
+                            ],
+                        ),
+                        asks: OrderBookSide::new(
+                            Side::Sell,
+                            vec![
+                                Level::new(num!(101.0), num!(1.5)),
+                                Level::new(num!(102.0), num!(3.0)),
+                            ],
+                        ),
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(test.input.l2(), test.expected, "TC{index} failed");
+            }
+        }
     }
 
     mod order_book_side {
@@ -570,40 +1886,56 @@ mod tests {
                     // TC0: Level exists & new value is 0 => remove Level
                     book_side: OrderBookSide::new(
                         Side::Buy,
-                        vec![Level::new(80, 1), Level::new(90, 1), Level::new(100, 1)],
+                        vec![
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                        ],
                     ),
-                    new_level: Level::new(100, 0),
+                    new_level: Level::new(num!(100), num!(0)),
                     expected: OrderBookSide::new(
                         Side::Buy,
-                        vec![Level::new(80, 1), Level::new(90, 1)],
+                        vec![Level::new(num!(80), num!(1)), Level::new(num!(90), num!(1))],
                     ),
                 },
                 TestCase {
                     // TC1: Level exists & new value is > 0 => replace Level
                     book_side: OrderBookSide::new(
                         Side::Buy,
-                        vec![Level::new(80, 1), Level::new(90, 1), Level::new(100, 1)],
+                        vec![
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                        ],
                     ),
-                    new_level: Level::new(100, 10),
+                    new_level: Level::new(num!(100), num!(10)),
                     expected: OrderBookSide::new(
                         Side::Buy,
-                        vec![Level::new(80, 1), Level::new(90, 1), Level::new(100, 10)],
+                        vec![
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(10)),
+                        ],
                     ),
                 },
                 TestCase {
                     // TC2: Level does not exist & new value > 0 => insert new Level
                     book_side: OrderBookSide::new(
                         Side::Buy,
-                        vec![Level::new(80, 1), Level::new(90, 1), Level::new(100, 1)],
+                        vec![
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                        ],
                     ),
-                    new_level: Level::new(110, 1),
+                    new_level: Level::new(num!(110), num!(1)),
                     expected: OrderBookSide::new(
                         Side::Buy,
                         vec![
-                            Level::new(80, 1),
-                            Level::new(90, 1),
-                            Level::new(100, 1),
-                            Level::new(110, 1),
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                            Level::new(num!(110), num!(1)),
                         ],
                     ),
                 },
@@ -611,12 +1943,20 @@ mod tests {
                     // TC3: Level does not exist & new value is 0 => no change
                     book_side: OrderBookSide::new(
                         Side::Buy,
-                        vec![Level::new(80, 1), Level::new(90, 1), Level::new(100, 1)],
+                        vec![
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                        ],
                     ),
-                    new_level: Level::new(110, 0),
+                    new_level: Level::new(num!(110), num!(0)),
                     expected: OrderBookSide::new(
                         Side::Buy,
-                        vec![Level::new(80, 1), Level::new(90, 1), Level::new(100, 1)],
+                        vec![
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                        ],
                     ),
                 },
             ];
@@ -628,10 +1968,10 @@ mod tests {
         }
 
         #[test]
-        fn test_sort_bids() {
+        fn test_levels_bids_best_first() {
             struct TestCase {
                 input: OrderBookSide,
-                expected: OrderBookSide,
+                expected: Vec<Level>,
             }
 
             let tests = vec![
@@ -640,162 +1980,473 @@ mod tests {
                     input: OrderBookSide::new(
                         Side::Buy,
                         vec![
-                            Level::new(80, 1),
-                            Level::new(90, 1),
-                            Level::new(100, 1),
-                            Level::new(110, 1),
-                            Level::new(120, 1),
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                            Level::new(num!(110), num!(1)),
+                            Level::new(num!(120), num!(1)),
                         ],
                     ),
-                    expected: OrderBookSide::new(
+                    expected: vec![
+                        Level::new(num!(120), num!(1)),
+                        Level::new(num!(110), num!(1)),
+                        Level::new(num!(100), num!(1)),
+                        Level::new(num!(90), num!(1)),
+                        Level::new(num!(80), num!(1)),
+                    ],
+                },
+                TestCase {
+                    // TC1: sorted correctly from partially sorted
+                    input: OrderBookSide::new(
                         Side::Buy,
                         vec![
-                            Level::new(120, 1),
-                            Level::new(110, 1),
-                            Level::new(100, 1),
-                            Level::new(90, 1),
-                            Level::new(80, 1),
+                            Level::new(num!(120), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(110), num!(1)),
+                            Level::new(num!(100), num!(1)),
                         ],
                     ),
+                    expected: vec![
+                        Level::new(num!(120), num!(1)),
+                        Level::new(num!(110), num!(1)),
+                        Level::new(num!(100), num!(1)),
+                        Level::new(num!(90), num!(1)),
+                        Level::new(num!(80), num!(1)),
+                    ],
                 },
                 TestCase {
-                    // TC1: sorted correctly from partially sorted
+                    // TC1: sorted correctly from already sorted
                     input: OrderBookSide::new(
                         Side::Buy,
                         vec![
-                            Level::new(120, 1),
-                            Level::new(90, 1),
-                            Level::new(80, 1),
-                            Level::new(110, 1),
-                            Level::new(100, 1),
+                            Level::new(num!(120), num!(1)),
+                            Level::new(num!(110), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(80), num!(1)),
                         ],
                     ),
-                    expected: OrderBookSide::new(
-                        Side::Buy,
+                    expected: vec![
+                        Level::new(num!(120), num!(1)),
+                        Level::new(num!(110), num!(1)),
+                        Level::new(num!(100), num!(1)),
+                        Level::new(num!(90), num!(1)),
+                        Level::new(num!(80), num!(1)),
+                    ],
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = test.input.levels().copied().collect::<Vec<_>>();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_levels_asks_best_first() {
+            struct TestCase {
+                input: OrderBookSide,
+                expected: Vec<Level>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: sorted correctly from already sorted
+                    input: OrderBookSide::new(
+                        Side::Sell,
                         vec![
-                            Level::new(120, 1),
-                            Level::new(110, 1),
-                            Level::new(100, 1),
-                            Level::new(90, 1),
-                            Level::new(80, 1),
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                            Level::new(num!(110), num!(1)),
+                            Level::new(num!(120), num!(1)),
                         ],
                     ),
+                    expected: vec![
+                        Level::new(num!(80), num!(1)),
+                        Level::new(num!(90), num!(1)),
+                        Level::new(num!(100), num!(1)),
+                        Level::new(num!(110), num!(1)),
+                        Level::new(num!(120), num!(1)),
+                    ],
                 },
                 TestCase {
-                    // TC1: sorted correctly from already sorted
+                    // TC1: sorted correctly from partially sorted
+                    input: OrderBookSide::new(
+                        Side::Sell,
+                        vec![
+                            Level::new(num!(120), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(110), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                        ],
+                    ),
+                    expected: vec![
+                        Level::new(num!(80), num!(1)),
+                        Level::new(num!(90), num!(1)),
+                        Level::new(num!(100), num!(1)),
+                        Level::new(num!(110), num!(1)),
+                        Level::new(num!(120), num!(1)),
+                    ],
+                },
+                TestCase {
+                    // TC1: sorted correctly from reverse sorted
                     input: OrderBookSide::new(
+                        Side::Sell,
+                        vec![
+                            Level::new(num!(120), num!(1)),
+                            Level::new(num!(110), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(80), num!(1)),
+                        ],
+                    ),
+                    expected: vec![
+                        Level::new(num!(80), num!(1)),
+                        Level::new(num!(90), num!(1)),
+                        Level::new(num!(100), num!(1)),
+                        Level::new(num!(110), num!(1)),
+                        Level::new(num!(120), num!(1)),
+                    ],
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = test.input.levels().copied().collect::<Vec<_>>();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_upsert_delta() {
+            struct TestCase {
+                book_side: OrderBookSide,
+                new_levels: Vec<Level>,
+                expected_delta: Vec<Level>,
+                expected_book_side: OrderBookSide,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: replacing a Level with an identical amount is not a change
+                    book_side: OrderBookSide::new(Side::Buy, vec![Level::new(num!(100), num!(1))]),
+                    new_levels: vec![Level::new(num!(100), num!(1))],
+                    expected_delta: vec![],
+                    expected_book_side: OrderBookSide::new(
+                        Side::Buy,
+                        vec![Level::new(num!(100), num!(1))],
+                    ),
+                },
+                TestCase {
+                    // TC1: bid Levels inserted, amended & removed, yielded in applied order
+                    book_side: OrderBookSide::new(
                         Side::Buy,
                         vec![
-                            Level::new(120, 1),
-                            Level::new(110, 1),
-                            Level::new(100, 1),
-                            Level::new(90, 1),
-                            Level::new(80, 1),
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
                         ],
                     ),
-                    expected: OrderBookSide::new(
+                    new_levels: vec![
+                        Level::new(num!(70), num!(1)),
+                        Level::new(num!(80), num!(0)),
+                        Level::new(num!(90), num!(5)),
+                        Level::new(num!(110), num!(1)),
+                    ],
+                    expected_delta: vec![
+                        Level::new(num!(70), num!(1)),
+                        Level::new(num!(80), num!(0)),
+                        Level::new(num!(90), num!(5)),
+                        Level::new(num!(110), num!(1)),
+                    ],
+                    expected_book_side: OrderBookSide::new(
                         Side::Buy,
                         vec![
-                            Level::new(120, 1),
-                            Level::new(110, 1),
-                            Level::new(100, 1),
-                            Level::new(90, 1),
-                            Level::new(80, 1),
+                            Level::new(num!(70), num!(1)),
+                            Level::new(num!(90), num!(5)),
+                            Level::new(num!(100), num!(1)),
+                            Level::new(num!(110), num!(1)),
                         ],
                     ),
                 },
+                TestCase {
+                    // TC2: removal of a non-existent ask Level is not a change
+                    book_side: OrderBookSide::new(Side::Sell, vec![Level::new(num!(100), num!(1))]),
+                    new_levels: vec![
+                        Level::new(num!(110), num!(0)),
+                        Level::new(num!(100), num!(0)),
+                    ],
+                    expected_delta: vec![Level::new(num!(100), num!(0))],
+                    expected_book_side: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                },
             ];
 
             for (index, mut test) in tests.into_iter().enumerate() {
-                test.input.sort();
-                assert_eq!(test.input, test.expected, "TC{} failed", index);
+                let mut applied = test.book_side.clone();
+
+                let actual = test.book_side.upsert_delta(test.new_levels);
+                assert_eq!(actual, test.expected_delta, "TC{index} failed");
+                assert_eq!(test.book_side, test.expected_book_side, "TC{index} failed");
+
+                applied.upsert(actual);
+                assert_eq!(applied, test.expected_book_side, "TC{index} failed");
             }
         }
 
         #[test]
-        fn test_sort_asks() {
+        fn test_truncate_delta() {
             struct TestCase {
-                input: OrderBookSide,
-                expected: OrderBookSide,
+                book_side: OrderBookSide,
+                depth: usize,
+                expected_delta: Vec<Level>,
             }
 
             let tests = vec![
                 TestCase {
-                    // TC0: sorted correctly from already sorted
-                    input: OrderBookSide::new(
-                        Side::Sell,
+                    // TC0: side within depth is unchanged
+                    book_side: OrderBookSide::new(Side::Buy, vec![Level::new(num!(100), num!(1))]),
+                    depth: 1,
+                    expected_delta: vec![],
+                },
+                TestCase {
+                    // TC1: worst bid Levels beyond depth are removed
+                    book_side: OrderBookSide::new(
+                        Side::Buy,
                         vec![
-                            Level::new(80, 1),
-                            Level::new(90, 1),
-                            Level::new(100, 1),
-                            Level::new(110, 1),
-                            Level::new(120, 1),
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
                         ],
                     ),
-                    expected: OrderBookSide::new(
+                    depth: 1,
+                    expected_delta: vec![
+                        Level::new(num!(80), num!(0)),
+                        Level::new(num!(90), num!(0)),
+                    ],
+                },
+                TestCase {
+                    // TC2: worst ask Levels beyond depth are removed
+                    book_side: OrderBookSide::new(
                         Side::Sell,
                         vec![
-                            Level::new(80, 1),
-                            Level::new(90, 1),
-                            Level::new(100, 1),
-                            Level::new(110, 1),
-                            Level::new(120, 1),
+                            Level::new(num!(100), num!(1)),
+                            Level::new(num!(110), num!(1)),
                         ],
                     ),
+                    depth: 1,
+                    expected_delta: vec![Level::new(num!(110), num!(0))],
+                },
+            ];
+
+            for (index, mut test) in tests.into_iter().enumerate() {
+                let actual = test.book_side.truncate_delta(test.depth);
+                assert_eq!(actual, test.expected_delta, "TC{index} failed");
+                assert_eq!(
+                    test.book_side.levels().len(),
+                    test.depth,
+                    "TC{index} failed"
+                );
+            }
+        }
+
+        #[test]
+        fn test_cumulative_depth() {
+            struct TestCase {
+                input: OrderBookSide,
+                expected: Vec<Level>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: empty side
+                    input: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                    expected: vec![],
                 },
                 TestCase {
-                    // TC1: sorted correctly from partially sorted
+                    // TC1: bids accumulated best first
                     input: OrderBookSide::new(
-                        Side::Sell,
+                        Side::Buy,
                         vec![
-                            Level::new(120, 1),
-                            Level::new(90, 1),
-                            Level::new(80, 1),
-                            Level::new(110, 1),
-                            Level::new(100, 1),
+                            Level::new(num!(97), num!(3)),
+                            Level::new(num!(99), num!(1)),
+                            Level::new(num!(98), num!(2)),
                         ],
                     ),
-                    expected: OrderBookSide::new(
+                    expected: vec![
+                        Level::new(num!(99), num!(1)),
+                        Level::new(num!(98), num!(3)),
+                        Level::new(num!(97), num!(6)),
+                    ],
+                },
+                TestCase {
+                    // TC2: asks accumulated best first
+                    input: OrderBookSide::new(
                         Side::Sell,
                         vec![
-                            Level::new(80, 1),
-                            Level::new(90, 1),
-                            Level::new(100, 1),
-                            Level::new(110, 1),
-                            Level::new(120, 1),
+                            Level::new(num!(103), num!(4)),
+                            Level::new(num!(101), num!(1)),
+                            Level::new(num!(102), num!(2)),
                         ],
                     ),
+                    expected: vec![
+                        Level::new(num!(101), num!(1)),
+                        Level::new(num!(102), num!(3)),
+                        Level::new(num!(103), num!(7)),
+                    ],
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.cumulative_depth(),
+                    test.expected,
+                    "TC{index} failed"
+                );
+            }
+        }
+
+        #[test]
+        fn test_vwap_for_notional() {
+            struct TestCase {
+                input: OrderBookSide,
+                notional: Number,
+                expected: Option<Number>,
+            }
+
+            let asks = OrderBookSide::new(
+                Side::Sell,
+                vec![
+                    Level::new(num!(101), num!(1)),
+                    Level::new(num!(102), num!(2)),
+                    Level::new(num!(103), num!(4)),
+                ],
+            );
+
+            let tests = vec![
+                TestCase {
+                    // TC0: filled exactly by the best Level
+                    input: asks.clone(),
+                    notional: num!(101.0),
+                    expected: Some(num!(101.0)),
                 },
                 TestCase {
-                    // TC1: sorted correctly from reverse sorted
+                    // TC1: filled exactly by the best two Levels
+                    input: asks.clone(),
+                    notional: num!(305.0),
+                    expected: Some(num!(305.0) / num!(3.0)),
+                },
+                TestCase {
+                    // TC2: notional exceeds available Levels
+                    input: asks.clone(),
+                    notional: num!(1000.0),
+                    expected: None,
+                },
+                TestCase {
+                    // TC3: zero notional
+                    input: asks,
+                    notional: num!(0.0),
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.vwap_for_notional(test.notional),
+                    test.expected,
+                    "TC{index} failed"
+                );
+            }
+        }
+
+        #[test]
+        fn test_truncate() {
+            struct TestCase {
+                input: OrderBookSide,
+                depth: usize,
+                expected: OrderBookSide,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: bids truncated to the best Levels
                     input: OrderBookSide::new(
-                        Side::Sell,
+                        Side::Buy,
                         vec![
-                            Level::new(120, 1),
-                            Level::new(110, 1),
-                            Level::new(100, 1),
-                            Level::new(90, 1),
-                            Level::new(80, 1),
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
                         ],
                     ),
+                    depth: 2,
                     expected: OrderBookSide::new(
+                        Side::Buy,
+                        vec![
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
+                        ],
+                    ),
+                },
+                TestCase {
+                    // TC1: asks truncated to the best Levels
+                    input: OrderBookSide::new(
                         Side::Sell,
                         vec![
-                            Level::new(80, 1),
-                            Level::new(90, 1),
-                            Level::new(100, 1),
-                            Level::new(110, 1),
-                            Level::new(120, 1),
+                            Level::new(num!(80), num!(1)),
+                            Level::new(num!(90), num!(1)),
+                            Level::new(num!(100), num!(1)),
                         ],
                     ),
+                    depth: 1,
+                    expected: OrderBookSide::new(Side::Sell, vec![Level::new(num!(80), num!(1))]),
+                },
+                TestCase {
+                    // TC2: depth greater than number of Levels is unchanged
+                    input: OrderBookSide::new(Side::Sell, vec![Level::new(num!(80), num!(1))]),
+                    depth: 5,
+                    expected: OrderBookSide::new(Side::Sell, vec![Level::new(num!(80), num!(1))]),
                 },
             ];
 
             for (index, mut test) in tests.into_iter().enumerate() {
-                test.input.sort();
-                assert_eq!(test.input, test.expected, "TC{} failed", index);
+                assert_eq!(
+                    test.input.top(test.depth),
+                    test.expected,
+                    "TC{index} failed"
+                );
+                test.input.truncate(test.depth);
+                assert_eq!(test.input, test.expected, "TC{index} failed");
             }
         }
+
+        #[test]
+        fn test_serde_order_book_side() {
+            let side = OrderBookSide::new(
+                Side::Buy,
+                vec![
+                    Level::new(num!(90), num!(2)),
+                    Level::new(num!(100), num!(1)),
+                    Level::new(num!(80), num!(3)),
+                ],
+            );
+
+            let serialised = serde_json::to_value(&side).unwrap();
+            assert_eq!(
+                serialised,
+                serde_json::json!({
+                    "side": Side::Buy,
+                    "levels": [
+                        {"price": num!(100), "amount": num!(1)},
+                        {"price": num!(90), "amount": num!(2)},
+                        {"price": num!(80), "amount": num!(3)}
+                    ]
+                })
+            );
+
+            let deserialised = serde_json::from_value::<OrderBookSide>(serialised).unwrap();
+            assert_eq!(deserialised, side);
+        }
     }
 
     mod level {
@@ -812,56 +2463,56 @@ mod tests {
             let tests = vec![
                 TestCase {
                     // TC0: Input One has higher price and higher quantity -> Greater
-                    input_one: Level::new(100, 100),
-                    input_two: Level::new(10, 10),
+                    input_one: Level::new(num!(100), num!(100)),
+                    input_two: Level::new(num!(10), num!(10)),
                     expected: Some(Ordering::Greater),
                 },
                 TestCase {
                     // TC1: Input One has higher price but same quantity -> Greater
-                    input_one: Level::new(100, 100),
-                    input_two: Level::new(10, 100),
+                    input_one: Level::new(num!(100), num!(100)),
+                    input_two: Level::new(num!(10), num!(100)),
                     expected: Some(Ordering::Greater),
                 },
                 TestCase {
                     // TC2: Input One has higher price but lower quantity -> Greater
-                    input_one: Level::new(100, 10),
-                    input_two: Level::new(10, 100),
+                    input_one: Level::new(num!(100), num!(10)),
+                    input_two: Level::new(num!(10), num!(100)),
                     expected: Some(Ordering::Greater),
                 },
                 TestCase {
                     // TC3: Input One has same price and higher quantity -> Greater
-                    input_one: Level::new(10, 200),
-                    input_two: Level::new(10, 100),
+                    input_one: Level::new(num!(10), num!(200)),
+                    input_two: Level::new(num!(10), num!(100)),
                     expected: Some(Ordering::Greater),
                 },
                 TestCase {
                     // TC4: Input One has same price and same quantity -> Equal
-                    input_one: Level::new(100, 100),
-                    input_two: Level::new(100, 100),
+                    input_one: Level::new(num!(100), num!(100)),
+                    input_two: Level::new(num!(100), num!(100)),
                     expected: Some(Ordering::Equal),
                 },
                 TestCase {
                     // TC5: Input One has same price but lower quantity -> Less
-                    input_one: Level::new(10, 50),
-                    input_two: Level::new(10, 100),
+                    input_one: Level::new(num!(10), num!(50)),
+                    input_two: Level::new(num!(10), num!(100)),
                     expected: Some(Ordering::Less),
                 },
                 TestCase {
                     // TC6: Input One has lower price but higher quantity -> Less
-                    input_one: Level::new(10, 100),
-                    input_two: Level::new(100, 50),
+                    input_one: Level::new(num!(10), num!(100)),
+                    input_two: Level::new(num!(100), num!(50)),
                     expected: Some(Ordering::Less),
                 },
                 TestCase {
                     // TC7: Input One has lower price and same quantity -> Less
-                    input_one: Level::new(50, 100),
-                    input_two: Level::new(100, 100),
+                    input_one: Level::new(num!(50), num!(100)),
+                    input_two: Level::new(num!(100), num!(100)),
                     expected: Some(Ordering::Less),
                 },
                 TestCase {
                     // TC8: Input One has lower price and lower quantity -> Less
-                    input_one: Level::new(50, 50),
-                    input_two: Level::new(100, 100),
+                    input_one: Level::new(num!(50), num!(50)),
+                    input_two: Level::new(num!(100), num!(100)),
                     expected: Some(Ordering::Less),
                 },
             ];
@@ -894,20 +2545,20 @@ mod tests {
             let tests = vec![
                 TestCase {
                     // TC0: Input Level has higher price
-                    level: Level::new(50, 100),
-                    input_level: Level::new(100, 100),
+                    level: Level::new(num!(50), num!(100)),
+                    input_level: Level::new(num!(100), num!(100)),
                     expected: false,
                 },
                 TestCase {
                     // TC1: Input Level an equal price
-                    level: Level::new(50, 100),
-                    input_level: Level::new(50, 100),
+                    level: Level::new(num!(50), num!(100)),
+                    input_level: Level::new(num!(50), num!(100)),
                     expected: true,
                 },
                 TestCase {
                     // TC2: Input Level has lower price
-                    level: Level::new(50, 100),
-                    input_level: Level::new(10, 100),
+                    level: Level::new(num!(50), num!(100)),
+                    input_level: Level::new(num!(10), num!(100)),
                     expected: false,
                 },
             ];
@@ -1,24 +1,311 @@
 use super::SubKind;
-use chrono::{DateTime, Utc};
+use crate::{aggregator::candle::CandleClock, number::Number};
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Offset of the first Monday (1970-01-05) from the Unix epoch (a Thursday), used to align
+/// [`CandleInterval::Week1`] periods.
+const WEEK_ALIGNMENT_OFFSET_SECS: i64 = 4 * 24 * 60 * 60;
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Candle`]
 /// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// By default only closed [`Candle`]s are yielded. Set `in_progress` to also receive updates
+/// to the [`Candle`] that is currently forming.
+///
+/// The `clock` & `emit_empty` configuration only applies to exchanges without native candle
+/// streams, where [`Candle`]s are aggregated from trades by the
+/// [`TradeCandleTransformer`](crate::transformer::candle::TradeCandleTransformer).
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
-pub struct Candles;
+pub struct Candles {
+    pub interval: CandleInterval,
+    #[serde(default)]
+    pub in_progress: bool,
+    #[serde(default)]
+    pub clock: CandleClock,
+    #[serde(default)]
+    pub emit_empty: bool,
+}
 
 impl SubKind for Candles {
     type Event = Candle;
 }
 
+impl Candles {
+    /// Construct a new [`Self`] that yields only closed [`Candle`]s of the provided
+    /// [`CandleInterval`].
+    pub fn new(interval: CandleInterval) -> Self {
+        Self {
+            interval,
+            in_progress: false,
+            clock: CandleClock::Exchange,
+            emit_empty: false,
+        }
+    }
+
+    /// Construct a new [`Self`] that yields closed and in-progress [`Candle`] updates of the
+    /// provided [`CandleInterval`].
+    pub fn in_progress(interval: CandleInterval) -> Self {
+        Self {
+            interval,
+            in_progress: true,
+            clock: CandleClock::Exchange,
+            emit_empty: false,
+        }
+    }
+
+    /// Configure [`Self`] to close trade aggregated [`Candle`]s using the provided
+    /// [`CandleClock`].
+    pub fn with_clock(self, clock: CandleClock) -> Self {
+        Self { clock, ..self }
+    }
+
+    /// Configure [`Self`] to emit empty trade aggregated [`Candle`]s for periods that contain no
+    /// trades.
+    pub fn with_empty(self) -> Self {
+        Self {
+            emit_empty: true,
+            ..self
+        }
+    }
+}
+
+/// Normalised Barter [`CandleInterval`] defining the duration of time each [`Candle`] spans.
+///
+/// Each exchange [`Connector`](crate::exchange::Connector) maps a [`CandleInterval`] to it's
+/// native channel identifier, and rejects unsupported [`CandleInterval`]s when the
+/// [`Subscription`](super::Subscription) is validated.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum CandleInterval {
+    #[serde(rename = "1s")]
+    Second1,
+    #[serde(rename = "1m")]
+    Minute1,
+    #[serde(rename = "3m")]
+    Minute3,
+    #[serde(rename = "5m")]
+    Minute5,
+    #[serde(rename = "15m")]
+    Minute15,
+    #[serde(rename = "30m")]
+    Minute30,
+    #[serde(rename = "1h")]
+    Hour1,
+    #[serde(rename = "2h")]
+    Hour2,
+    #[serde(rename = "4h")]
+    Hour4,
+    #[serde(rename = "6h")]
+    Hour6,
+    #[serde(rename = "8h")]
+    Hour8,
+    #[serde(rename = "12h")]
+    Hour12,
+    #[serde(rename = "1d")]
+    Day1,
+    #[serde(rename = "3d")]
+    Day3,
+    #[serde(rename = "1w")]
+    Week1,
+    #[serde(rename = "1M")]
+    Month1,
+}
+
+impl CandleInterval {
+    /// Return the &str representation of this [`CandleInterval`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CandleInterval::Second1 => "1s",
+            CandleInterval::Minute1 => "1m",
+            CandleInterval::Minute3 => "3m",
+            CandleInterval::Minute5 => "5m",
+            CandleInterval::Minute15 => "15m",
+            CandleInterval::Minute30 => "30m",
+            CandleInterval::Hour1 => "1h",
+            CandleInterval::Hour2 => "2h",
+            CandleInterval::Hour4 => "4h",
+            CandleInterval::Hour6 => "6h",
+            CandleInterval::Hour8 => "8h",
+            CandleInterval::Hour12 => "12h",
+            CandleInterval::Day1 => "1d",
+            CandleInterval::Day3 => "3d",
+            CandleInterval::Week1 => "1w",
+            CandleInterval::Month1 => "1M",
+        }
+    }
+
+    /// Return the fixed [`Duration`] of this [`CandleInterval`], or `None` for the variable length
+    /// [`CandleInterval::Month1`].
+    pub fn duration(&self) -> Option<Duration> {
+        let seconds = match self {
+            CandleInterval::Second1 => 1,
+            CandleInterval::Minute1 => 60,
+            CandleInterval::Minute3 => 3 * 60,
+            CandleInterval::Minute5 => 5 * 60,
+            CandleInterval::Minute15 => 15 * 60,
+            CandleInterval::Minute30 => 30 * 60,
+            CandleInterval::Hour1 => 60 * 60,
+            CandleInterval::Hour2 => 2 * 60 * 60,
+            CandleInterval::Hour4 => 4 * 60 * 60,
+            CandleInterval::Hour6 => 6 * 60 * 60,
+            CandleInterval::Hour8 => 8 * 60 * 60,
+            CandleInterval::Hour12 => 12 * 60 * 60,
+            CandleInterval::Day1 => 24 * 60 * 60,
+            CandleInterval::Day3 => 3 * 24 * 60 * 60,
+            CandleInterval::Week1 => 7 * 24 * 60 * 60,
+            CandleInterval::Month1 => return None,
+        };
+
+        Some(Duration::seconds(seconds))
+    }
+
+    /// Determine the open time of the [`CandleInterval`] period containing the provided time.
+    ///
+    /// Periods are aligned to the Unix epoch in UTC, with the exception of
+    /// [`CandleInterval::Week1`] (aligned to Monday) and [`CandleInterval::Month1`] (aligned to
+    /// the first day of the calendar month), matching the conventions used by exchanges.
+    pub fn open_time(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let duration = match self.duration() {
+            Some(duration) => duration,
+            None => {
+                return Utc
+                    .with_ymd_and_hms(time.year(), time.month(), 1, 0, 0, 0)
+                    .unwrap()
+            }
+        };
+
+        let offset_ms = match self {
+            CandleInterval::Week1 => WEEK_ALIGNMENT_OFFSET_SECS * 1000,
+            _ => 0,
+        };
+
+        let duration_ms = duration.num_milliseconds();
+        let open_ms =
+            (time.timestamp_millis() - offset_ms).div_euclid(duration_ms) * duration_ms + offset_ms;
+
+        Utc.timestamp_millis_opt(open_ms).unwrap()
+    }
+
+    /// Determine the open time of the [`CandleInterval`] period following the period that
+    /// contains the provided time.
+    pub fn next_open_time(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let open_time = self.open_time(time);
+
+        match self.duration() {
+            Some(duration) => open_time + duration,
+            None if open_time.month() == 12 => Utc
+                .with_ymd_and_hms(open_time.year() + 1, 1, 1, 0, 0, 0)
+                .unwrap(),
+            None => Utc
+                .with_ymd_and_hms(open_time.year(), open_time.month() + 1, 1, 0, 0, 0)
+                .unwrap(),
+        }
+    }
+
+    /// Determine the close time of the [`CandleInterval`] period containing the provided time.
+    ///
+    /// The close time is inclusive, one millisecond before the open time of the next period.
+    pub fn close_time(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        self.next_open_time(time) - Duration::milliseconds(1)
+    }
+}
+
+impl Display for CandleInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Normalised Barter OHLCV [`Candle`] model.
+///
+/// The `interval` is `None` for information-driven candles that are not closed on a time
+/// boundary, such as a [`Bar`](crate::aggregator::bar::Bar).
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Candle {
+    pub interval: Option<CandleInterval>,
+    pub open_time: DateTime<Utc>,
     pub close_time: DateTime<Utc>,
-    pub open: f64,
-    pub high: f64,
-    pub low: f64,
-    pub close: f64,
-    pub volume: f64,
+    pub open: Number,
+    pub high: Number,
+    pub low: Number,
+    pub close: Number,
+    pub volume: Number,
     pub trade_count: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candle_interval_period() {
+        struct TestCase {
+            interval: CandleInterval,
+            time: DateTime<Utc>,
+            expected_open: DateTime<Utc>,
+            expected_next_open: DateTime<Utc>,
+        }
+
+        let time = |y, m, d, h, min, s| Utc.with_ymd_and_hms(y, m, d, h, min, s).unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: Minute1 floors to the start of the minute
+                interval: CandleInterval::Minute1,
+                time: time(2023, 1, 1, 12, 30, 45),
+                expected_open: time(2023, 1, 1, 12, 30, 0),
+                expected_next_open: time(2023, 1, 1, 12, 31, 0),
+            },
+            TestCase {
+                // TC1: time on a period boundary is the open time of that period
+                interval: CandleInterval::Hour4,
+                time: time(2023, 1, 1, 8, 0, 0),
+                expected_open: time(2023, 1, 1, 8, 0, 0),
+                expected_next_open: time(2023, 1, 1, 12, 0, 0),
+            },
+            TestCase {
+                // TC2: Week1 is aligned to Monday
+                interval: CandleInterval::Week1,
+                time: time(2023, 1, 1, 12, 0, 0),
+                expected_open: time(2022, 12, 26, 0, 0, 0),
+                expected_next_open: time(2023, 1, 2, 0, 0, 0),
+            },
+            TestCase {
+                // TC3: Month1 is aligned to the calendar month
+                interval: CandleInterval::Month1,
+                time: time(2023, 2, 15, 12, 0, 0),
+                expected_open: time(2023, 2, 1, 0, 0, 0),
+                expected_next_open: time(2023, 3, 1, 0, 0, 0),
+            },
+            TestCase {
+                // TC4: Month1 rolls over the year
+                interval: CandleInterval::Month1,
+                time: time(2022, 12, 31, 23, 59, 59),
+                expected_open: time(2022, 12, 1, 0, 0, 0),
+                expected_next_open: time(2023, 1, 1, 0, 0, 0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                test.interval.open_time(test.time),
+                test.expected_open,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                test.interval.next_open_time(test.time),
+                test.expected_next_open,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                test.interval.close_time(test.time),
+                test.expected_next_open - Duration::milliseconds(1),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
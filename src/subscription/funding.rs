@@ -0,0 +1,30 @@
+use super::SubKind;
+use crate::number::Number;
+use barter_macro::{DeSubKind, SerSubKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`FundingRate`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events for perpetual
+/// [`Instrument`](barter_integration::model::instrument::Instrument)s.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct FundingRates;
+
+impl SubKind for FundingRates {
+    type Event = FundingRate;
+}
+
+/// Normalised Barter perpetual [`FundingRate`] model.
+///
+/// ### Notes
+/// - `rate` is the funding rate that applies at the `next_funding_time`, as last published by
+///   the exchange.
+/// - `predicted_rate`, `next_funding_time` & `mark_price` are only populated if the exchange
+///   provides them in the same message.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingRate {
+    pub rate: Number,
+    pub predicted_rate: Option<Number>,
+    pub next_funding_time: Option<DateTime<Utc>>,
+    pub mark_price: Option<Number>,
+}
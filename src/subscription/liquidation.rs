@@ -1,4 +1,5 @@
 use super::SubKind;
+use crate::number::{Number, ZERO};
 use barter_integration::model::Side;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -13,10 +14,166 @@ impl SubKind for Liquidations {
 }
 
 /// Normalised Barter [`Liquidation`] model.
+///
+/// The `side` is the side of the liquidation order, ie/ [`Side::Sell`] when a long position is
+/// liquidated.
+///
+/// ### Notes
+/// - `average_price`, `filled_quantity` & `status` are only populated if the exchange provides
+///   them, and default to `None` when deserialising [`Liquidation`]s recorded without them.
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Liquidation {
     pub side: Side,
-    pub price: f64,
-    pub quantity: f64,
+    pub price: Number,
+    pub quantity: Number,
     pub time: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub average_price: Option<Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filled_quantity: Option<Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<LiquidationStatus>,
+}
+
+impl Liquidation {
+    /// Compute the notional value of the [`Liquidation`].
+    ///
+    /// Uses the `average_price` & `filled_quantity` if the liquidation order has been (partially)
+    /// filled, otherwise the order `price` & `quantity`.
+    pub fn notional(&self) -> Number {
+        match (self.average_price, self.filled_quantity) {
+            (Some(average_price), Some(filled_quantity)) if filled_quantity != ZERO => {
+                average_price * filled_quantity
+            }
+            _ => self.price * self.quantity,
+        }
+    }
+}
+
+/// Normalised Barter [`Liquidation`] order status.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LiquidationStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+    Expired,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_liquidation() {
+            struct TestCase {
+                input: &'static str,
+                expected: Liquidation,
+            }
+
+            let time = datetime_utc_from_epoch_duration(Duration::from_millis(1665523974217));
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input Liquidation recorded without the optional fields is deserialised
+                    input: r#"
+                    {
+                        "side": "sell",
+                        "price": 18917.15,
+                        "quantity": 0.009,
+                        "time": "2022-10-11T21:32:54.217Z"
+                    }
+                    "#,
+                    expected: Liquidation {
+                        side: Side::Sell,
+                        price: num!(18917.15),
+                        quantity: num!(0.009),
+                        time,
+                        average_price: None,
+                        filled_quantity: None,
+                        status: None,
+                    },
+                },
+                TestCase {
+                    // TC1: input Liquidation with the optional fields is deserialised
+                    input: r#"
+                    {
+                        "side": "sell",
+                        "price": 18917.15,
+                        "quantity": 0.009,
+                        "time": "2022-10-11T21:32:54.217Z",
+                        "average_price": 18990.0,
+                        "filled_quantity": 0.009,
+                        "status": "filled"
+                    }
+                    "#,
+                    expected: Liquidation {
+                        side: Side::Sell,
+                        price: num!(18917.15),
+                        quantity: num!(0.009),
+                        time,
+                        average_price: Some(num!(18990.0)),
+                        filled_quantity: Some(num!(0.009)),
+                        status: Some(LiquidationStatus::Filled),
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<Liquidation>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_liquidation_notional() {
+        struct TestCase {
+            average_price: Option<Number>,
+            filled_quantity: Option<Number>,
+            expected: Number,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: unknown fill uses the order price & quantity
+                average_price: None,
+                filled_quantity: None,
+                expected: Number::from(200),
+            },
+            TestCase {
+                // TC1: unfilled order uses the order price & quantity
+                average_price: Some(Number::from(90)),
+                filled_quantity: Some(ZERO),
+                expected: Number::from(200),
+            },
+            TestCase {
+                // TC2: partially filled order uses the average price & filled quantity
+                average_price: Some(Number::from(90)),
+                filled_quantity: Some(Number::from(1)),
+                expected: Number::from(90),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let liquidation = Liquidation {
+                side: Side::Buy,
+                price: Number::from(100),
+                quantity: Number::from(2),
+                time: Utc::now(),
+                average_price: test.average_price,
+                filled_quantity: test.filled_quantity,
+                status: None,
+            };
+
+            assert_eq!(liquidation.notional(), test.expected, "TC{} failed", index);
+        }
+    }
 }
@@ -0,0 +1,30 @@
+use super::SubKind;
+use crate::number::Number;
+use barter_macro::{DeSubKind, SerSubKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`MarkPrice`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events for derivative
+/// [`Instrument`](barter_integration::model::instrument::Instrument)s.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct MarkPrices;
+
+impl SubKind for MarkPrices {
+    type Event = MarkPrice;
+}
+
+/// Normalised Barter [`MarkPrice`] model.
+///
+/// ### Notes
+/// - `index` is the underlying index price, and is only populated once the exchange has
+///   provided it.
+/// - `estimated_settle` is the estimated settlement price, and is only populated if the exchange
+///   provides it.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MarkPrice {
+    pub mark: Number,
+    pub index: Option<Number>,
+    pub estimated_settle: Option<Number>,
+    pub time: DateTime<Utc>,
+}
@@ -20,9 +20,21 @@ pub mod book;
 /// Candle [`SubKind`] and the associated Barter output data model.
 pub mod candle;
 
+/// Funding rate [`SubKind`] and the associated Barter output data model.
+pub mod funding;
+
 /// Liquidation [`SubKind`] and the associated Barter output data model.
 pub mod liquidation;
 
+/// Mark price [`SubKind`] and the associated Barter output data model.
+pub mod mark_price;
+
+/// Open interest [`SubKind`] and the associated Barter output data model.
+pub mod open_interest;
+
+/// Ticker [`SubKind`] and the associated Barter output data model.
+pub mod ticker;
+
 /// Public trade [`SubKind`] and the associated Barter output data model.
 pub mod trade;
 
@@ -103,14 +115,20 @@ where
         let exchange = Exchange::ID;
 
         // Validate the Exchange supports the Subscription InstrumentKind
-        if exchange.supports(self.instrument.kind) {
-            Ok(self)
-        } else {
-            Err(SocketError::Unsupported {
+        if !exchange.supports(self.instrument.kind) {
+            return Err(SocketError::Unsupported {
                 entity: exchange.as_str(),
                 item: self.instrument.kind.to_string(),
-            })
+            });
         }
+
+        // Validate the Exchange supports the Subscription SubKind configuration
+        Exchange::validate_kind(&self.kind).map_err(|item| SocketError::Unsupported {
+            entity: exchange.as_str(),
+            item,
+        })?;
+
+        Ok(self)
     }
 }
 
@@ -365,6 +383,122 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_validate_order_books_l2_snapshot_depth() {
+            use crate::{
+                exchange::binance::{futures::BinanceFuturesUsd, spot::BinanceSpot},
+                subscription::book::OrderBooksL2,
+            };
+
+            struct TestCase {
+                input: Result<(), SocketError>,
+                expected_valid: bool,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: BinanceSpot default snapshot_depth is valid
+                    input: Subscription::from((
+                        BinanceSpot::default(),
+                        "base",
+                        "quote",
+                        InstrumentKind::Spot,
+                        OrderBooksL2::default(),
+                    ))
+                    .validate()
+                    .map(|_| ()),
+                    expected_valid: true,
+                },
+                TestCase {
+                    // TC1: BinanceSpot snapshot_depth within 1..=5000 is valid
+                    input: Subscription::from((
+                        BinanceSpot::default(),
+                        "base",
+                        "quote",
+                        InstrumentKind::Spot,
+                        OrderBooksL2::new().with_snapshot_depth(5000),
+                    ))
+                    .validate()
+                    .map(|_| ()),
+                    expected_valid: true,
+                },
+                TestCase {
+                    // TC2: BinanceSpot snapshot_depth above 5000 is invalid
+                    input: Subscription::from((
+                        BinanceSpot::default(),
+                        "base",
+                        "quote",
+                        InstrumentKind::Spot,
+                        OrderBooksL2::new().with_snapshot_depth(5001),
+                    ))
+                    .validate()
+                    .map(|_| ()),
+                    expected_valid: false,
+                },
+                TestCase {
+                    // TC3: BinanceFuturesUsd supported snapshot_depth is valid
+                    input: Subscription::from((
+                        BinanceFuturesUsd::default(),
+                        "base",
+                        "quote",
+                        InstrumentKind::Perpetual,
+                        OrderBooksL2::new().with_snapshot_depth(1000),
+                    ))
+                    .validate()
+                    .map(|_| ()),
+                    expected_valid: true,
+                },
+                TestCase {
+                    // TC4: BinanceFuturesUsd unsupported snapshot_depth is invalid
+                    input: Subscription::from((
+                        BinanceFuturesUsd::default(),
+                        "base",
+                        "quote",
+                        InstrumentKind::Perpetual,
+                        OrderBooksL2::new().with_snapshot_depth(200),
+                    ))
+                    .validate()
+                    .map(|_| ()),
+                    expected_valid: false,
+                },
+                TestCase {
+                    // TC5: Okx without a HTTP OrderBook snapshot rejects any snapshot_depth
+                    input: Subscription::from((
+                        Okx,
+                        "base",
+                        "quote",
+                        InstrumentKind::Spot,
+                        OrderBooksL2::new().with_snapshot_depth(100),
+                    ))
+                    .validate()
+                    .map(|_| ()),
+                    expected_valid: false,
+                },
+                TestCase {
+                    // TC6: Okx without snapshot_depth is valid
+                    input: Subscription::from((
+                        Okx,
+                        "base",
+                        "quote",
+                        InstrumentKind::Spot,
+                        OrderBooksL2::new().with_depth(10),
+                    ))
+                    .validate()
+                    .map(|_| ()),
+                    expected_valid: true,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.is_ok(),
+                    test.expected_valid,
+                    "TC{index} failed with: {:?}",
+                    test.input
+                );
+            }
+        }
     }
 
     mod instrument_map {
@@ -0,0 +1,28 @@
+use super::SubKind;
+use crate::number::Number;
+use barter_macro::{DeSubKind, SerSubKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`OpenInterest`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events for derivative
+/// [`Instrument`](barter_integration::model::instrument::Instrument)s.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct OpenInterests;
+
+impl SubKind for OpenInterests {
+    type Event = OpenInterest;
+}
+
+/// Normalised Barter [`OpenInterest`] model.
+///
+/// ### Notes
+/// - `contracts` is the total number of outstanding contracts, in the exchange's contract units.
+/// - `notional` is the quote currency value of the outstanding contracts, and is only populated
+///   if the exchange provides it.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OpenInterest {
+    pub contracts: Number,
+    pub notional: Option<Number>,
+    pub time: DateTime<Utc>,
+}
@@ -0,0 +1,82 @@
+use super::SubKind;
+use crate::number::{Number, ONE_HUNDRED, ZERO};
+use barter_macro::{DeSubKind, SerSubKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields rolling 24h [`Ticker`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct Tickers;
+
+impl SubKind for Tickers {
+    type Event = Ticker;
+}
+
+/// Normalised Barter rolling 24h [`Ticker`] model.
+///
+/// ### Notes
+/// - `open` is only populated if the exchange provides it, or it can be derived from the
+///   24h price change.
+/// - `volume_quote` is only populated if the exchange provides it.
+/// - `change_percent` is provided by the exchange, or computed from `open` & `last` if not.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Ticker {
+    pub last: Number,
+    pub open: Option<Number>,
+    pub high: Number,
+    pub low: Number,
+    pub volume_base: Number,
+    pub volume_quote: Option<Number>,
+    pub change_percent: Option<Number>,
+    pub time: DateTime<Utc>,
+}
+
+impl Ticker {
+    /// Compute the percentage change from the `open` price to the `last` price.
+    ///
+    /// Returns `None` if the `open` price is zero.
+    pub fn change_percent(open: Number, last: Number) -> Option<Number> {
+        (open != ZERO).then(|| (last - open) / open * ONE_HUNDRED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticker_change_percent() {
+        struct TestCase {
+            open: Number,
+            last: Number,
+            expected: Option<Number>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: price increase
+                open: Number::from(100),
+                last: Number::from(110),
+                expected: Some(Number::from(10)),
+            },
+            TestCase {
+                // TC1: price decrease
+                open: Number::from(200),
+                last: Number::from(150),
+                expected: Some(Number::from(-25)),
+            },
+            TestCase {
+                // TC2: zero open price
+                open: ZERO,
+                last: Number::from(150),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Ticker::change_percent(test.open, test.last);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
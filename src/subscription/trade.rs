@@ -1,4 +1,5 @@
 use super::SubKind;
+use crate::number::Number;
 use barter_integration::model::Side;
 use barter_macro::{DeSubKind, SerSubKind};
 use serde::{Deserialize, Serialize};
@@ -16,7 +17,7 @@ impl SubKind for PublicTrades {
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct PublicTrade {
     pub id: String,
-    pub price: f64,
-    pub amount: f64,
+    pub price: Number,
+    pub amount: Number,
     pub side: Side,
 }
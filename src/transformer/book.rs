@@ -2,18 +2,30 @@ use crate::{
     error::DataError,
     event::{MarketEvent, MarketIter},
     exchange::Connector,
-    subscription::{book::OrderBook, Map, SubKind},
-    transformer::ExchangeTransformer,
+    number::{Number, ZERO},
+    subscription::{
+        book::{
+            LevelKey, OrderBook, OrderBookDelta, OrderBookEvent, OrderBookL1, OrderBookL3,
+            OrderBookL3Delta, OrderBookL3Event, OrderBooksL1, OrderBooksL2, OrderBooksL3,
+        },
+        Map, SubKind,
+    },
+    transformer::{ExchangeTransformer, TimedTransformer},
     Identifier,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    model::{instrument::Instrument, SubscriptionId},
+    model::{instrument::Instrument, Side, SubscriptionId},
     protocol::websocket::WsMessage,
     Transformer,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
+use std::{
+    collections::{hash_map::Entry, BTreeMap, HashMap, HashSet},
+    marker::PhantomData,
+    time::Duration,
+};
 use tokio::sync::mpsc;
 
 /// Defines how to apply a [`Self::Update`] to an [`Self::OrderBook`].
@@ -26,90 +38,447 @@ where
     type Update;
 
     /// Initialises the [`InstrumentOrderBook`] for the provided [`Instrument`]. This often requires
-    /// a HTTP call to receive a starting [`OrderBook`] snapshot.
+    /// a HTTP call to receive a starting [`OrderBook`] snapshot, in which case the optional
+    /// `snapshot_depth` is the number of levels to request.
     async fn init<Exchange, Kind>(
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
         instrument: Instrument,
-    ) -> Result<InstrumentOrderBook<Self>, DataError>
+        snapshot_depth: Option<usize>,
+    ) -> Result<InstrumentOrderBook<Self, Self::OrderBook>, DataError>
     where
         Exchange: Send,
         Kind: Send;
+}
 
-    /// Apply the [`Self::Update`] to the provided mutable [`Self::OrderBook`].
+/// Defines how to apply a [`Self::Update`](OrderBookUpdater::Update) to an [`OrderBook`],
+/// returning the [`OrderBookChange`] it caused rather than a full [`OrderBook`] snapshot.
+pub trait OrderBookDeltaUpdater
+where
+    Self: OrderBookUpdater<OrderBook = OrderBook>,
+{
+    /// Apply the [`Self::Update`](OrderBookUpdater::Update) to the provided mutable
+    /// [`OrderBook`], returning the [`OrderBookChange`] it caused.
+    ///
+    /// Returns `None` if the update was ignored (eg/ a heartbeat or an outdated update).
+    #[allow(clippy::result_large_err)]
+    fn update_delta(
+        &mut self,
+        book: &mut OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookChange>, DataError>;
+
+    /// Apply the [`Self::Update`](OrderBookUpdater::Update) to the provided mutable
+    /// [`OrderBook`], returning a full [`OrderBook`] snapshot if it changed.
+    #[allow(clippy::result_large_err)]
     fn update(
         &mut self,
-        book: &mut Self::OrderBook,
+        book: &mut OrderBook,
         update: Self::Update,
-    ) -> Result<Option<Self::OrderBook>, DataError>;
+    ) -> Result<Option<OrderBook>, DataError> {
+        Ok(self.update_delta(book, update)?.map(|_| book.snapshot()))
+    }
+}
+
+/// Change applied to an [`OrderBook`] by an [`OrderBookDeltaUpdater`].
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum OrderBookChange {
+    /// The [`OrderBook`] was replaced by an exchange snapshot.
+    Snapshot,
+    /// The [`OrderBook`] [`Level`](crate::subscription::book::Level)s changed by the
+    /// [`OrderBookDelta`].
+    Delta(OrderBookDelta),
 }
 
-/// [`OrderBook`] for an [`Instrument`] with an exchange specific [`OrderBookUpdater`] to define
-/// how to update it.
+/// Defines how to apply a [`Self::Update`](OrderBookUpdater::Update) to an [`OrderBookL3`],
+/// returning the [`OrderBookL3Change`] it caused rather than a full [`OrderBookL3`] snapshot.
+pub trait OrderBookL3DeltaUpdater
+where
+    Self: OrderBookUpdater<OrderBook = OrderBookL3>,
+{
+    /// Apply the [`Self::Update`](OrderBookUpdater::Update) to the provided mutable
+    /// [`OrderBookL3`], returning the [`OrderBookL3Change`] it caused.
+    ///
+    /// Returns `None` if the update was ignored (eg/ a heartbeat or an outdated update).
+    #[allow(clippy::result_large_err)]
+    fn update_delta(
+        &mut self,
+        book: &mut OrderBookL3,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookL3Change>, DataError>;
+
+    /// Apply the [`Self::Update`](OrderBookUpdater::Update) to the provided mutable
+    /// [`OrderBookL3`], returning a full [`OrderBookL3`] snapshot if it changed.
+    #[allow(clippy::result_large_err)]
+    fn update(
+        &mut self,
+        book: &mut OrderBookL3,
+        update: Self::Update,
+    ) -> Result<Option<OrderBookL3>, DataError> {
+        Ok(self.update_delta(book, update)?.map(|_| book.clone()))
+    }
+}
+
+/// Change applied to an [`OrderBookL3`] by an [`OrderBookL3DeltaUpdater`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum OrderBookL3Change {
+    /// The [`OrderBookL3`] was replaced by an exchange snapshot.
+    Snapshot,
+    /// The [`OrderBookL3`] [`Order`](crate::subscription::book::Order)s changed by the
+    /// [`OrderBookL3Delta`].
+    Delta(OrderBookL3Delta),
+}
+
+/// [`OrderBook`] (or [`OrderBookL3`]) for an [`Instrument`] with an exchange specific
+/// [`OrderBookUpdater`] to define how to update it.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
-pub struct InstrumentOrderBook<Updater> {
+pub struct InstrumentOrderBook<Updater, Book = OrderBook> {
     pub instrument: Instrument,
     pub updater: Updater,
-    pub book: OrderBook,
+    pub book: Book,
+}
+
+/// Raw exchange price & amount strings of each [`Level`](crate::subscription::book::Level) on one
+/// side of an [`OrderBook`], ordered best first.
+///
+/// Maintained alongside the [`OrderBook`] by exchange [`OrderBookUpdater`]s that validate a
+/// checksum generated from the exact strings received (eg/ Okx & Kraken), since formatting a
+/// [`Number`] does not reproduce them.
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub(crate) struct RawLevels {
+    side: Side,
+    levels: BTreeMap<LevelKey, RawLevel>,
+}
+
+/// Raw exchange price & amount strings of a [`Level`](crate::subscription::book::Level).
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub(crate) struct RawLevel {
+    pub price: String,
+    pub amount: String,
+}
+
+impl RawLevels {
+    /// Construct a new empty [`Self`] for the provided [`Side`].
+    pub(crate) fn new(side: Side) -> Self {
+        Self {
+            side,
+            levels: BTreeMap::new(),
+        }
+    }
+
+    /// Upsert the [`RawLevel`] at the provided price, removing it if the amount is zero.
+    pub(crate) fn upsert(&mut self, price: Number, amount: Number, level: RawLevel) {
+        let key = LevelKey::new(self.side, price);
+        if amount == ZERO {
+            self.levels.remove(&key);
+        } else {
+            self.levels.insert(key, level);
+        }
+    }
+
+    /// Remove every [`RawLevel`].
+    pub(crate) fn clear(&mut self) {
+        self.levels.clear();
+    }
+
+    /// Remove every [`RawLevel`] beyond the provided depth.
+    pub(crate) fn truncate(&mut self, depth: usize) {
+        while self.levels.len() > depth {
+            self.levels.pop_last();
+        }
+    }
+
+    /// Return an iterator over the [`RawLevel`]s, ordered best first.
+    pub(crate) fn levels(&self) -> impl Iterator<Item = &RawLevel> {
+        self.levels.values()
+    }
 }
 
 /// Standard generic [`ExchangeTransformer`] to translate exchange specific OrderBook types into
-/// normalised Barter OrderBook types. Requires an exchange specific [`OrderBookUpdater`]
+/// normalised Barter OrderBook types. Requires an exchange specific [`OrderBookDeltaUpdater`]
 /// implementation.
+///
+/// Each yielded [`OrderBook`] is truncated & conflated according to the [`OrderBooksL2`]
+/// configuration of the associated [`Subscription`](crate::subscription::Subscription). The
+/// maintained [`OrderBook`] is only cloned (to the configured depth) when an [`OrderBook`] is
+/// yielded, not for conflated updates.
+///
+/// Conflated [`OrderBook`]s are flushed by [`TimedTransformer::tick`] once their interval has
+/// elapsed, so the latest [`OrderBook`] is yielded even if no further updates are received.
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct MultiBookTransformer<Exchange, Kind, Updater> {
     pub book_map: Map<InstrumentOrderBook<Updater>>,
-    phantom: PhantomData<(Exchange, Kind)>,
+    pub kind_map: Map<Kind>,
+    pub emitted_map: Map<DateTime<Utc>>,
+    pub pending: HashSet<SubscriptionId>,
+    phantom: PhantomData<Exchange>,
 }
 
 #[async_trait]
-impl<Exchange, Kind, Updater> ExchangeTransformer<Exchange, Kind>
-    for MultiBookTransformer<Exchange, Kind, Updater>
+impl<Exchange, Updater> ExchangeTransformer<Exchange, OrderBooksL2>
+    for MultiBookTransformer<Exchange, OrderBooksL2, Updater>
 where
     Exchange: Connector + Send,
-    Kind: SubKind<Event = OrderBook> + Send,
-    Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send,
+    Updater: OrderBookDeltaUpdater + Send,
     Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
 {
     async fn new(
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
         map: Map<Instrument>,
+        kind_map: Map<OrderBooksL2>,
     ) -> Result<Self, DataError> {
-        // Initialise InstrumentOrderBooks for all Subscriptions
-        let (sub_ids, init_book_requests): (Vec<_>, Vec<_>) = map
+        let book_map =
+            init_book_map::<Exchange, OrderBooksL2, Updater>(ws_sink_tx, map, |sub_id| {
+                kind_map.0.get(sub_id).and_then(|kind| kind.snapshot_depth)
+            })
+            .await?;
+
+        Ok(Self {
+            book_map,
+            kind_map,
+            emitted_map: Map(Default::default()),
+            pending: HashSet::new(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<Exchange, Updater> Transformer for MultiBookTransformer<Exchange, OrderBooksL2, Updater>
+where
+    Exchange: Connector,
+    Updater: OrderBookDeltaUpdater,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+{
+    type Error = DataError;
+    type Input = Updater::Update;
+    type Output = MarketEvent<OrderBook>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, update: Self::Input) -> Self::OutputIter {
+        // Determine if the update has an identifiable SubscriptionId
+        let subscription_id = match update.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Retrieve the InstrumentOrderBook associated with this update (snapshot or delta)
+        let book = match self.book_map.find_mut(&subscription_id) {
+            Ok(book) => book,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // De-structure for ease
+        let InstrumentOrderBook {
+            instrument,
+            book,
+            updater,
+        } = book;
+
+        // Apply update (snapshot or delta) to OrderBook using the exchange specific validation
+        match updater.update_delta(book, update) {
+            Ok(Some(_)) => {}
+            Ok(None) => return vec![],
+            Err(error) => return vec![Err(error)],
+        }
+
+        let kind = self
+            .kind_map
             .0
-            .into_iter()
-            .map(|(sub_id, instrument)| {
-                (
-                    sub_id,
-                    Updater::init::<Exchange, Kind>(ws_sink_tx.clone(), instrument),
-                )
+            .get(&subscription_id)
+            .copied()
+            .unwrap_or_default();
+
+        // Conflate updates received within the configured interval of the last emission
+        if let Some(interval) = kind.interval {
+            let now = Utc::now();
+            if is_conflated(self.emitted_map.0.get(&subscription_id), now, interval) {
+                self.pending.insert(subscription_id);
+                return vec![];
+            }
+            self.pending.remove(&subscription_id);
+            self.emitted_map.0.insert(subscription_id, now);
+        }
+
+        book_event::<Exchange>(instrument, book, kind.depth)
+    }
+}
+
+impl<Exchange, Updater> TimedTransformer for MultiBookTransformer<Exchange, OrderBooksL2, Updater>
+where
+    Exchange: Connector,
+    Updater: OrderBookDeltaUpdater,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+{
+    fn tick_interval(&self) -> Option<Duration> {
+        // Only conflated OrderBooks need flushing, so tick at the shortest configured interval
+        self.kind_map
+            .0
+            .values()
+            .filter_map(|kind| kind.interval)
+            .min()
+    }
+
+    fn tick(&mut self, now: DateTime<Utc>) -> Self::OutputIter {
+        // Determine the conflated OrderBooks whose interval has elapsed since the last emission
+        let flushed = self
+            .pending
+            .iter()
+            .filter(|subscription_id| {
+                let interval = self
+                    .kind_map
+                    .0
+                    .get(*subscription_id)
+                    .and_then(|kind| kind.interval)
+                    .unwrap_or_default();
+
+                !is_conflated(self.emitted_map.0.get(*subscription_id), now, interval)
             })
-            .unzip();
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut outputs = Vec::with_capacity(flushed.len());
+        for subscription_id in flushed {
+            self.pending.remove(&subscription_id);
 
-        // Await all initial OrderBook snapshot requests
-        let init_order_books = futures::future::join_all(init_book_requests)
-            .await
-            .into_iter()
-            .collect::<Result<Vec<InstrumentOrderBook<Updater>>, DataError>>()?;
+            let Some(InstrumentOrderBook {
+                instrument, book, ..
+            }) = self.book_map.0.get(&subscription_id)
+            else {
+                continue;
+            };
+
+            let depth = self
+                .kind_map
+                .0
+                .get(&subscription_id)
+                .and_then(|kind| kind.depth);
+
+            outputs.extend(book_event::<Exchange>(instrument, book, depth));
+            self.emitted_map.0.insert(subscription_id, now);
+        }
+
+        outputs
+    }
+}
+
+/// Determine if an [`OrderBook`] last emitted at `last_emitted` is still conflated at `now`,
+/// ie/ the configured `interval` has not yet elapsed.
+fn is_conflated(
+    last_emitted: Option<&DateTime<Utc>>,
+    now: DateTime<Utc>,
+    interval: Duration,
+) -> bool {
+    last_emitted.is_some_and(|last_emitted| {
+        (now - *last_emitted)
+            .to_std()
+            .map_or(true, |elapsed| elapsed < interval)
+    })
+}
+
+/// Generate the [`MarketEvent<OrderBook>`] of an [`OrderBook`] snapshot, truncated to the
+/// optional `depth`.
+fn book_event<Exchange>(
+    instrument: &Instrument,
+    book: &OrderBook,
+    depth: Option<usize>,
+) -> Vec<Result<MarketEvent<OrderBook>, DataError>>
+where
+    Exchange: Connector,
+{
+    // Only clone the configured depth of the OrderBook
+    let snapshot = match depth {
+        Some(depth) => book.snapshot_truncated(depth),
+        None => book.snapshot(),
+    };
+
+    MarketIter::<OrderBook>::from((Exchange::ID, instrument.clone(), snapshot)).0
+}
+
+/// Initialise an [`InstrumentOrderBook`] for every [`Instrument`] in the provided [`Map`],
+/// awaiting all initial [`OrderBook`] snapshot requests.
+///
+/// The provided `snapshot_depth` function determines the number of levels to request in the
+/// initial [`OrderBook`] snapshot of each [`SubscriptionId`].
+async fn init_book_map<Exchange, Kind, Updater>(
+    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+    map: Map<Instrument>,
+    snapshot_depth: impl Fn(&SubscriptionId) -> Option<usize>,
+) -> Result<Map<InstrumentOrderBook<Updater, Updater::OrderBook>>, DataError>
+where
+    Exchange: Send,
+    Kind: Send,
+    Updater: OrderBookUpdater,
+{
+    // Initialise InstrumentOrderBooks for all Subscriptions
+    let (sub_ids, init_book_requests): (Vec<_>, Vec<_>) = map
+        .0
+        .into_iter()
+        .map(|(sub_id, instrument)| {
+            let snapshot_depth = snapshot_depth(&sub_id);
+            (
+                sub_id,
+                Updater::init::<Exchange, Kind>(ws_sink_tx.clone(), instrument, snapshot_depth),
+            )
+        })
+        .unzip();
+
+    // Await all initial OrderBook snapshot requests
+    let init_order_books = futures::future::join_all(init_book_requests)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, DataError>>()?;
+
+    // Construct OrderBookMap if all requests successful
+    Ok(sub_ids
+        .into_iter()
+        .zip(init_order_books.into_iter())
+        .collect::<Map<_>>())
+}
+
+/// Generic [`ExchangeTransformer`] to translate exchange specific OrderBook types into
+/// normalised Barter [`OrderBookEvent`]s. Requires an exchange specific [`OrderBookUpdater`]
+/// implementation.
+///
+/// The first [`OrderBook`] generated for each [`Instrument`] (and any exchange snapshot that
+/// replaces it) is yielded as an [`OrderBookEvent::Snapshot`]. Every subsequent change is yielded
+/// as an [`OrderBookEvent::Update`] containing only the
+/// [`Level`](crate::subscription::book::Level)s reported by the [`OrderBookDeltaUpdater`].
+/// Updates that change no [`Level`](crate::subscription::book::Level)s are not yielded.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct MultiBookDeltaTransformer<Exchange, Kind, Updater> {
+    pub book_map: Map<InstrumentOrderBook<Updater>>,
+    pub snapshot_emitted: HashSet<SubscriptionId>,
+    phantom: PhantomData<(Exchange, Kind)>,
+}
 
-        // Construct OrderBookMap if all requests successful
-        let book_map = sub_ids
-            .into_iter()
-            .zip(init_order_books.into_iter())
-            .collect::<Map<InstrumentOrderBook<Updater>>>();
+#[async_trait]
+impl<Exchange, Kind, Updater> ExchangeTransformer<Exchange, Kind>
+    for MultiBookDeltaTransformer<Exchange, Kind, Updater>
+where
+    Exchange: Connector + Send,
+    Kind: SubKind<Event = OrderBookEvent> + Send,
+    Updater: OrderBookDeltaUpdater + Send,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        map: Map<Instrument>,
+        _: Map<Kind>,
+    ) -> Result<Self, DataError> {
+        let book_map = init_book_map::<Exchange, Kind, Updater>(ws_sink_tx, map, |_| None).await?;
 
         Ok(Self {
             book_map,
-            phantom: PhantomData::default(),
+            snapshot_emitted: HashSet::new(),
+            phantom: PhantomData,
         })
     }
 }
 
-impl<Exchange, Kind, Updater> Transformer for MultiBookTransformer<Exchange, Kind, Updater>
+impl<Exchange, Kind, Updater> Transformer for MultiBookDeltaTransformer<Exchange, Kind, Updater>
 where
     Exchange: Connector,
-    Kind: SubKind<Event = OrderBook>,
-    Updater: OrderBookUpdater<OrderBook = Kind::Event>,
+    Kind: SubKind<Event = OrderBookEvent>,
+    Updater: OrderBookDeltaUpdater,
     Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
 {
     type Error = DataError;
@@ -137,13 +506,697 @@ where
             updater,
         } = book;
 
-        // Apply update (snapshot or delta) to OrderBook & generate Market<OrderBook> snapshot
-        match updater.update(book, update) {
-            Ok(Some(book)) => {
-                MarketIter::<OrderBook>::from((Exchange::ID, instrument.clone(), book)).0
+        // Apply update (snapshot or delta) to OrderBook using the exchange specific validation
+        let change = match updater.update_delta(book, update) {
+            Ok(Some(change)) => change,
+            Ok(None) => return vec![],
+            Err(error) => return vec![Err(error)],
+        };
+
+        // Yield a full OrderBook snapshot until one has been emitted, and after exchange snapshots
+        let event = match change {
+            OrderBookChange::Delta(delta) if self.snapshot_emitted.contains(&subscription_id) => {
+                if delta.is_empty() {
+                    return vec![];
+                }
+                OrderBookEvent::Update(delta)
             }
-            Ok(None) => vec![],
-            Err(error) => vec![Err(error)],
+            _ => {
+                self.snapshot_emitted.insert(subscription_id);
+                OrderBookEvent::Snapshot(book.snapshot())
+            }
+        };
+
+        MarketIter::<OrderBookEvent>::from((Exchange::ID, instrument.clone(), event)).0
+    }
+}
+
+/// Generic [`ExchangeTransformer`] to translate exchange specific level 3 OrderBook types into
+/// normalised Barter [`OrderBookL3Event`]s. Requires an exchange specific
+/// [`OrderBookL3DeltaUpdater`] implementation.
+///
+/// The first [`OrderBookL3`] generated for each [`Instrument`] (and any exchange snapshot that
+/// replaces it) is yielded as an [`OrderBookL3Event::Snapshot`]. Every subsequent change is
+/// yielded as an [`OrderBookL3Event::Update`] containing only the
+/// [`Order`](crate::subscription::book::Order)s reported by the [`OrderBookL3DeltaUpdater`].
+///
+/// Use [`OrderBookL3::l2`] to aggregate a maintained [`OrderBookL3`] into a level 2
+/// [`OrderBook`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MultiBookL3Transformer<Exchange, Updater> {
+    pub book_map: Map<InstrumentOrderBook<Updater, OrderBookL3>>,
+    pub snapshot_emitted: HashSet<SubscriptionId>,
+    phantom: PhantomData<Exchange>,
+}
+
+#[async_trait]
+impl<Exchange, Updater> ExchangeTransformer<Exchange, OrderBooksL3>
+    for MultiBookL3Transformer<Exchange, Updater>
+where
+    Exchange: Connector + Send,
+    Updater: OrderBookL3DeltaUpdater + Send,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        map: Map<Instrument>,
+        _: Map<OrderBooksL3>,
+    ) -> Result<Self, DataError> {
+        let book_map =
+            init_book_map::<Exchange, OrderBooksL3, Updater>(ws_sink_tx, map, |_| None).await?;
+
+        Ok(Self {
+            book_map,
+            snapshot_emitted: HashSet::new(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<Exchange, Updater> Transformer for MultiBookL3Transformer<Exchange, Updater>
+where
+    Exchange: Connector,
+    Updater: OrderBookL3DeltaUpdater,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+{
+    type Error = DataError;
+    type Input = Updater::Update;
+    type Output = MarketEvent<OrderBookL3Event>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, update: Self::Input) -> Self::OutputIter {
+        // Determine if the update has an identifiable SubscriptionId
+        let subscription_id = match update.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Retrieve the InstrumentOrderBook associated with this update (snapshot or delta)
+        let book = match self.book_map.find_mut(&subscription_id) {
+            Ok(book) => book,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // De-structure for ease
+        let InstrumentOrderBook {
+            instrument,
+            book,
+            updater,
+        } = book;
+
+        // Apply update (snapshot or delta) to OrderBookL3 using the exchange specific validation
+        let change = match updater.update_delta(book, update) {
+            Ok(Some(change)) => change,
+            Ok(None) => return vec![],
+            Err(error) => return vec![Err(error)],
+        };
+
+        // Yield a full OrderBookL3 snapshot until one has been emitted, and after exchange snapshots
+        let event = match change {
+            OrderBookL3Change::Delta(delta) if self.snapshot_emitted.contains(&subscription_id) => {
+                if delta.is_empty() {
+                    return vec![];
+                }
+                OrderBookL3Event::Update(delta)
+            }
+            _ => {
+                self.snapshot_emitted.insert(subscription_id);
+                OrderBookL3Event::Snapshot(book.clone())
+            }
+        };
+
+        MarketIter::<OrderBookL3Event>::from((Exchange::ID, instrument.clone(), event)).0
+    }
+}
+
+/// Generic [`ExchangeTransformer`] that synthesises normalised Barter [`OrderBookL1`]s from an
+/// exchange OrderBook Level2 channel. Requires an exchange specific [`OrderBookDeltaUpdater`]
+/// implementation.
+///
+/// The full depth [`OrderBook`] is maintained by the [`OrderBookDeltaUpdater`], and an
+/// [`OrderBookL1`] is read from its best bid & ask [`Level`](crate::subscription::book::Level)s
+/// after every update, without cloning the [`OrderBook`]. An [`OrderBookL1`] is only yielded
+/// when the best bid or ask changes, and both sides of the [`OrderBook`] are non-empty.
+///
+/// Any [`Streams<MarketEvent<OrderBook>>`](crate::streams::Streams) can be adapted in a similar
+/// way using `Streams::order_books_l1` (see [`OrderBookL1Synthesiser`]).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OrderBookL1Transformer<Exchange, Updater> {
+    pub book_map: Map<InstrumentOrderBook<Updater>>,
+    pub emitted_map: HashMap<SubscriptionId, OrderBookL1>,
+    phantom: PhantomData<Exchange>,
+}
+
+#[async_trait]
+impl<Exchange, Updater> ExchangeTransformer<Exchange, OrderBooksL1>
+    for OrderBookL1Transformer<Exchange, Updater>
+where
+    Exchange: Connector + Send,
+    Updater: OrderBookDeltaUpdater + Send,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        map: Map<Instrument>,
+        _: Map<OrderBooksL1>,
+    ) -> Result<Self, DataError> {
+        let book_map =
+            init_book_map::<Exchange, OrderBooksL1, Updater>(ws_sink_tx, map, |_| None).await?;
+
+        Ok(Self {
+            book_map,
+            emitted_map: HashMap::new(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<Exchange, Updater> Transformer for OrderBookL1Transformer<Exchange, Updater>
+where
+    Exchange: Connector,
+    Updater: OrderBookDeltaUpdater,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+{
+    type Error = DataError;
+    type Input = Updater::Update;
+    type Output = MarketEvent<OrderBookL1>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, update: Self::Input) -> Self::OutputIter {
+        // Determine if the update has an identifiable SubscriptionId
+        let subscription_id = match update.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Retrieve the InstrumentOrderBook associated with this update (snapshot or delta)
+        let book = match self.book_map.find_mut(&subscription_id) {
+            Ok(book) => book,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // De-structure for ease
+        let InstrumentOrderBook {
+            instrument,
+            book,
+            updater,
+        } = book;
+
+        // Apply update (snapshot or delta) to OrderBook using the exchange specific validation
+        match updater.update_delta(book, update) {
+            Ok(Some(_)) => {}
+            Ok(None) => return vec![],
+            Err(error) => return vec![Err(error)],
+        }
+
+        // Only yield an OrderBookL1 if the best bid or ask has changed
+        let Some(l1) = book.l1() else {
+            return vec![];
+        };
+        if !insert_if_changed(&mut self.emitted_map, subscription_id, l1) {
+            return vec![];
+        }
+
+        vec![Ok(MarketEvent {
+            exchange_time: l1.last_update_time,
+            received_time: Utc::now(),
+            exchange: barter_integration::model::Exchange::from(Exchange::ID),
+            instrument: instrument.clone(),
+            kind: l1,
+        })]
+    }
+}
+
+/// Synthesises [`MarketEvent<OrderBookL1>`]s from a stream of [`MarketEvent<OrderBook>`]s.
+///
+/// An [`OrderBookL1`] is only yielded when the best bid or ask [`Level`](crate::subscription::book::Level)
+/// of an [`Exchange`](barter_integration::model::Exchange) [`Instrument`] [`OrderBook`] changes,
+/// and both sides of the [`OrderBook`] are non-empty.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct OrderBookL1Synthesiser {
+    pub emitted_map: HashMap<(barter_integration::model::Exchange, Instrument), OrderBookL1>,
+}
+
+impl OrderBookL1Synthesiser {
+    /// Synthesise a [`MarketEvent<OrderBookL1>`] from the provided [`MarketEvent<OrderBook>`] if
+    /// the best bid or ask has changed since the last one yielded.
+    pub fn synthesise(
+        &mut self,
+        event: MarketEvent<OrderBook>,
+    ) -> Option<MarketEvent<OrderBookL1>> {
+        let l1 = event.kind.l1()?;
+
+        let key = (event.exchange.clone(), event.instrument.clone());
+        if !insert_if_changed(&mut self.emitted_map, key, l1) {
+            return None;
+        }
+
+        Some(MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: l1,
+        })
+    }
+}
+
+/// Insert the [`OrderBookL1`] as the last emitted for the provided key if its best bid or ask
+/// differs from the last emitted [`OrderBookL1`].
+///
+/// Returns true if the [`OrderBookL1`] was inserted, and should therefore be yielded.
+fn insert_if_changed<Key>(
+    emitted_map: &mut HashMap<Key, OrderBookL1>,
+    key: Key,
+    l1: OrderBookL1,
+) -> bool
+where
+    Key: Eq + std::hash::Hash,
+{
+    match emitted_map.entry(key) {
+        Entry::Occupied(emitted)
+            if emitted.get().best_bid == l1.best_bid && emitted.get().best_ask == l1.best_ask =>
+        {
+            false
+        }
+        Entry::Occupied(mut emitted) => {
+            emitted.insert(l1);
+            true
+        }
+        Entry::Vacant(emitted) => {
+            emitted.insert(l1);
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::num;
+    use crate::{
+        exchange::coinbase::Coinbase,
+        subscription::book::{Level, OrderBookSide, OrderBooksL2Delta},
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange, Side};
+
+    /// [`OrderBookDeltaUpdater`] that applies each [`TestBookUpdate`] as-is.
+    struct TestBookUpdater;
+
+    /// Snapshot or delta [`Level`]s applied by the [`TestBookUpdater`].
+    #[derive(Deserialize)]
+    struct TestBookUpdate {
+        snapshot: bool,
+        bids: Vec<Level>,
+        asks: Vec<Level>,
+    }
+
+    impl Identifier<Option<SubscriptionId>> for TestBookUpdate {
+        fn id(&self) -> Option<SubscriptionId> {
+            Some(SubscriptionId::from("subscription_id"))
+        }
+    }
+
+    #[async_trait]
+    impl OrderBookUpdater for TestBookUpdater {
+        type OrderBook = OrderBook;
+        type Update = TestBookUpdate;
+
+        async fn init<Exchange, Kind>(
+            _: mpsc::UnboundedSender<WsMessage>,
+            instrument: Instrument,
+            _: Option<usize>,
+        ) -> Result<InstrumentOrderBook<Self>, DataError>
+        where
+            Exchange: Send,
+            Kind: Send,
+        {
+            Ok(InstrumentOrderBook {
+                instrument,
+                updater: Self,
+                book: book(vec![], vec![]),
+            })
+        }
+    }
+
+    impl OrderBookDeltaUpdater for TestBookUpdater {
+        fn update_delta(
+            &mut self,
+            book: &mut OrderBook,
+            update: Self::Update,
+        ) -> Result<Option<OrderBookChange>, DataError> {
+            if update.snapshot {
+                book.bids = OrderBookSide::new(Side::Buy, update.bids);
+                book.asks = OrderBookSide::new(Side::Sell, update.asks);
+                return Ok(Some(OrderBookChange::Snapshot));
+            }
+
+            Ok(Some(OrderBookChange::Delta(OrderBookDelta {
+                last_update_time: book.last_update_time,
+                prev_sequence: None,
+                sequence: None,
+                bids: book.bids.upsert_delta(update.bids),
+                asks: book.asks.upsert_delta(update.asks),
+            })))
+        }
+    }
+
+    fn book(bids: Vec<Level>, asks: Vec<Level>) -> OrderBook {
+        OrderBook {
+            last_update_time: Default::default(),
+            bids: OrderBookSide::new(Side::Buy, bids),
+            asks: OrderBookSide::new(Side::Sell, asks),
+        }
+    }
+
+    #[test]
+    fn test_order_book_l1_transformer() {
+        struct TestCase {
+            input: TestBookUpdate,
+            expected: Option<OrderBookL1>,
+        }
+
+        let mut transformer = OrderBookL1Transformer::<Coinbase, _> {
+            book_map: Map(HashMap::from([(
+                SubscriptionId::from("subscription_id"),
+                InstrumentOrderBook {
+                    instrument: Instrument::from(("base", "quote", InstrumentKind::Spot)),
+                    updater: TestBookUpdater,
+                    book: book(vec![], vec![]),
+                },
+            )])),
+            emitted_map: HashMap::new(),
+            phantom: PhantomData,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: no asks so no OrderBookL1
+                input: TestBookUpdate {
+                    snapshot: true,
+                    bids: vec![Level::new(num!(100.0), num!(1.0))],
+                    asks: vec![],
+                },
+                expected: None,
+            },
+            TestCase {
+                // TC1: first OrderBookL1
+                input: TestBookUpdate {
+                    snapshot: false,
+                    bids: vec![],
+                    asks: vec![Level::new(num!(110.0), num!(1.0))],
+                },
+                expected: Some(OrderBookL1 {
+                    last_update_time: Default::default(),
+                    best_bid: Level::new(num!(100.0), num!(1.0)),
+                    best_ask: Level::new(num!(110.0), num!(1.0)),
+                }),
+            },
+            TestCase {
+                // TC2: only non-best Levels changed so no OrderBookL1
+                input: TestBookUpdate {
+                    snapshot: false,
+                    bids: vec![Level::new(num!(90.0), num!(1.0))],
+                    asks: vec![Level::new(num!(120.0), num!(1.0))],
+                },
+                expected: None,
+            },
+            TestCase {
+                // TC3: best ask amount changed
+                input: TestBookUpdate {
+                    snapshot: false,
+                    bids: vec![],
+                    asks: vec![Level::new(num!(110.0), num!(2.0))],
+                },
+                expected: Some(OrderBookL1 {
+                    last_update_time: Default::default(),
+                    best_bid: Level::new(num!(100.0), num!(1.0)),
+                    best_ask: Level::new(num!(110.0), num!(2.0)),
+                }),
+            },
+            TestCase {
+                // TC4: best bid removed, so next best bid is the best bid
+                input: TestBookUpdate {
+                    snapshot: false,
+                    bids: vec![Level::new(num!(100.0), num!(0.0))],
+                    asks: vec![],
+                },
+                expected: Some(OrderBookL1 {
+                    last_update_time: Default::default(),
+                    best_bid: Level::new(num!(90.0), num!(1.0)),
+                    best_ask: Level::new(num!(110.0), num!(2.0)),
+                }),
+            },
+            TestCase {
+                // TC5: snapshot w/ unchanged best bid & ask so no OrderBookL1
+                input: TestBookUpdate {
+                    snapshot: true,
+                    bids: vec![Level::new(num!(90.0), num!(1.0))],
+                    asks: vec![Level::new(num!(110.0), num!(2.0))],
+                },
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|result| result.unwrap().kind)
+                .next();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_order_book_l1_synthesiser() {
+        struct TestCase {
+            input: MarketEvent<OrderBook>,
+            expected: Option<Exchange>,
+        }
+
+        fn event(exchange: &'static str, base: &'static str) -> MarketEvent<OrderBook> {
+            MarketEvent {
+                exchange_time: Default::default(),
+                received_time: Default::default(),
+                exchange: Exchange::from(exchange),
+                instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+                kind: book(
+                    vec![Level::new(num!(100.0), num!(1.0))],
+                    vec![Level::new(num!(110.0), num!(1.0))],
+                ),
+            }
+        }
+
+        let mut synthesiser = OrderBookL1Synthesiser::default();
+
+        let tests = vec![
+            TestCase {
+                // TC0: first OrderBookL1 of exchange_a btc_usdt
+                input: event("exchange_a", "btc"),
+                expected: Some(Exchange::from("exchange_a")),
+            },
+            TestCase {
+                // TC1: unchanged best bid & ask of exchange_a btc_usdt so no OrderBookL1
+                input: event("exchange_a", "btc"),
+                expected: None,
+            },
+            TestCase {
+                // TC2: first OrderBookL1 of exchange_b btc_usdt, despite same best bid & ask
+                input: event("exchange_b", "btc"),
+                expected: Some(Exchange::from("exchange_b")),
+            },
+            TestCase {
+                // TC3: first OrderBookL1 of exchange_a eth_usdt, despite same best bid & ask
+                input: event("exchange_a", "eth"),
+                expected: Some(Exchange::from("exchange_a")),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = synthesiser
+                .synthesise(test.input)
+                .map(|event| event.exchange);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_multi_book_delta_transformer() {
+        struct TestCase {
+            input: TestBookUpdate,
+            expected: Option<OrderBookEvent>,
+        }
+
+        let instrument = Instrument::from(("base", "quote", InstrumentKind::Spot));
+        let mut transformer = MultiBookDeltaTransformer::<Coinbase, OrderBooksL2Delta, _> {
+            book_map: Map(HashMap::from([(
+                SubscriptionId::from("subscription_id"),
+                InstrumentOrderBook {
+                    instrument,
+                    updater: TestBookUpdater,
+                    book: book(vec![Level::new(num!(100), num!(1))], vec![]),
+                },
+            )])),
+            snapshot_emitted: HashSet::new(),
+            phantom: PhantomData,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: first change yields a full OrderBook snapshot
+                input: TestBookUpdate {
+                    snapshot: false,
+                    bids: vec![],
+                    asks: vec![Level::new(num!(110), num!(1))],
+                },
+                expected: Some(OrderBookEvent::Snapshot(book(
+                    vec![Level::new(num!(100), num!(1))],
+                    vec![Level::new(num!(110), num!(1))],
+                ))),
+            },
+            TestCase {
+                // TC1: subsequent change yields only the changed Levels
+                input: TestBookUpdate {
+                    snapshot: false,
+                    bids: vec![
+                        Level::new(num!(100), num!(0)),
+                        Level::new(num!(90), num!(2)),
+                    ],
+                    asks: vec![Level::new(num!(110), num!(1))],
+                },
+                expected: Some(OrderBookEvent::Update(OrderBookDelta {
+                    last_update_time: Default::default(),
+                    prev_sequence: None,
+                    sequence: None,
+                    bids: vec![
+                        Level::new(num!(100), num!(0)),
+                        Level::new(num!(90), num!(2)),
+                    ],
+                    asks: vec![],
+                })),
+            },
+            TestCase {
+                // TC2: change of no Levels is not yielded
+                input: TestBookUpdate {
+                    snapshot: false,
+                    bids: vec![Level::new(num!(90), num!(2))],
+                    asks: vec![],
+                },
+                expected: None,
+            },
+            TestCase {
+                // TC3: exchange snapshot yields a full OrderBook snapshot
+                input: TestBookUpdate {
+                    snapshot: true,
+                    bids: vec![Level::new(num!(80), num!(1))],
+                    asks: vec![Level::new(num!(120), num!(1))],
+                },
+                expected: Some(OrderBookEvent::Snapshot(book(
+                    vec![Level::new(num!(80), num!(1))],
+                    vec![Level::new(num!(120), num!(1))],
+                ))),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|result| result.unwrap().kind)
+                .next();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_multi_book_transformer_conflation() {
+        enum Input {
+            Update(TestBookUpdate),
+            Tick(chrono::Duration),
+        }
+
+        struct TestCase {
+            input: Input,
+            expected: Option<OrderBook>,
+        }
+
+        let subscription_id = SubscriptionId::from("subscription_id");
+        let mut transformer = MultiBookTransformer::<Coinbase, OrderBooksL2, _> {
+            book_map: Map(HashMap::from([(
+                subscription_id.clone(),
+                InstrumentOrderBook {
+                    instrument: Instrument::from(("base", "quote", InstrumentKind::Spot)),
+                    updater: TestBookUpdater,
+                    book: book(vec![], vec![]),
+                },
+            )])),
+            kind_map: Map(HashMap::from([(
+                subscription_id,
+                OrderBooksL2::new()
+                    .with_depth(1)
+                    .with_interval(Duration::from_millis(500)),
+            )])),
+            emitted_map: Map(HashMap::new()),
+            pending: HashSet::new(),
+            phantom: PhantomData,
+        };
+
+        let start = Utc::now();
+
+        let tests = vec![
+            TestCase {
+                // TC0: first update is yielded, truncated to depth
+                input: Input::Update(TestBookUpdate {
+                    snapshot: true,
+                    bids: vec![
+                        Level::new(num!(100), num!(1)),
+                        Level::new(num!(90), num!(1)),
+                    ],
+                    asks: vec![Level::new(num!(110), num!(1))],
+                }),
+                expected: Some(book(
+                    vec![Level::new(num!(100), num!(1))],
+                    vec![Level::new(num!(110), num!(1))],
+                )),
+            },
+            TestCase {
+                // TC1: update within the interval is conflated
+                input: Input::Update(TestBookUpdate {
+                    snapshot: false,
+                    bids: vec![Level::new(num!(100), num!(2))],
+                    asks: vec![],
+                }),
+                expected: None,
+            },
+            TestCase {
+                // TC2: tick before the interval has elapsed does not flush the conflated OrderBook
+                input: Input::Tick(chrono::Duration::milliseconds(100)),
+                expected: None,
+            },
+            TestCase {
+                // TC3: tick after the interval has elapsed flushes the conflated OrderBook
+                input: Input::Tick(chrono::Duration::milliseconds(1000)),
+                expected: Some(book(
+                    vec![Level::new(num!(100), num!(2))],
+                    vec![Level::new(num!(110), num!(1))],
+                )),
+            },
+            TestCase {
+                // TC4: tick with no conflated OrderBook yields nothing
+                input: Input::Tick(chrono::Duration::milliseconds(2000)),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let output = match test.input {
+                Input::Update(update) => transformer.transform(update),
+                Input::Tick(elapsed) => transformer.tick(start + elapsed),
+            };
+
+            let actual = output.into_iter().map(|result| result.unwrap().kind).next();
+            assert_eq!(actual, test.expected, "TC{index} failed");
         }
     }
 }
@@ -0,0 +1,372 @@
+use super::{ExchangeTransformer, TimedTransformer};
+use crate::{
+    aggregator::candle::{CandleAggregator, CandleAggregatorConfig, CandleClock},
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{Connector, ExchangeId},
+    subscription::{
+        candle::{Candle, Candles},
+        trade::PublicTrade,
+        Map,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{marker::PhantomData, time::Duration};
+use tokio::sync::mpsc;
+
+/// Interval at which a [`TradeCandleTransformer`] closes [`CandleClock::WallClock`] [`Candle`]s
+/// whose period has elapsed without receiving further trades.
+const WALL_CLOCK_TICK_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Determines if an exchange specific candle update represents a closed [`Candle`], or an update
+/// to the [`Candle`] that is currently forming.
+pub trait CandleStatus {
+    fn is_closed(&self) -> bool;
+}
+
+/// Standard generic [`ExchangeTransformer`] to translate exchange specific candle types into
+/// normalised Barter [`Candle`]s.
+///
+/// In-progress candle updates are only yielded for [`Subscription`](crate::subscription::Subscription)s
+/// that opted in via [`Candles::in_progress`].
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct CandleTransformer<Exchange, Input> {
+    instrument_map: Map<Instrument>,
+    kind_map: Map<Candles>,
+    phantom: PhantomData<(Exchange, Input)>,
+}
+
+#[async_trait]
+impl<Exchange, Input> ExchangeTransformer<Exchange, Candles> for CandleTransformer<Exchange, Input>
+where
+    Exchange: Connector + Send,
+    Input: Identifier<Option<SubscriptionId>> + CandleStatus + for<'de> Deserialize<'de> + Send,
+    MarketIter<Candle>: From<(ExchangeId, Instrument, Input)>,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+        kind_map: Map<Candles>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map,
+            kind_map,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<Exchange, Input> Transformer for CandleTransformer<Exchange, Input>
+where
+    Exchange: Connector,
+    Input: Identifier<Option<SubscriptionId>> + CandleStatus + for<'de> Deserialize<'de>,
+    MarketIter<Candle>: From<(ExchangeId, Instrument, Input)>,
+{
+    type Error = DataError;
+    type Input = Input;
+    type Output = MarketEvent<Candle>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Determine if the message has an identifiable SubscriptionId
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Find Instrument associated with Input
+        let instrument = match self.instrument_map.find(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // Skip in-progress candle updates unless the Subscription opted in to receive them
+        if !input.is_closed() && !self.wants_in_progress(&subscription_id) {
+            return vec![];
+        }
+
+        MarketIter::<Candle>::from((Exchange::ID, instrument, input)).0
+    }
+}
+
+impl<Exchange, Input> CandleTransformer<Exchange, Input> {
+    /// Determine if the [`Subscription`](crate::subscription::Subscription) associated with the
+    /// provided [`SubscriptionId`] opted in to receive in-progress [`Candle`] updates.
+    fn wants_in_progress(&self, subscription_id: &SubscriptionId) -> bool {
+        self.kind_map
+            .0
+            .get(subscription_id)
+            .is_some_and(|kind| kind.in_progress)
+    }
+}
+
+/// Fallback [`ExchangeTransformer`] for exchanges without native candle streams, which aggregates
+/// exchange specific trade types into normalised Barter [`Candle`]s using a [`CandleAggregator`].
+///
+/// ### Notes
+/// - Trades are assigned to periods using the [`Candles`] [`CandleClock`]. With
+///   [`CandleClock::Exchange`] a [`Candle`] is only closed once a trade belonging to a later
+///   period is received, whereas with [`CandleClock::WallClock`] it is closed on the first
+///   [`tick`](TimedTransformer::tick) after it's close time.
+/// - Each [`Candles`] [`Subscription`](crate::subscription::Subscription) shares the underlying
+///   trades channel, so only one [`CandleInterval`](crate::subscription::candle::CandleInterval)
+///   per market is supported per connection.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TradeCandleTransformer<Exchange, Input> {
+    instrument_map: Map<Instrument>,
+    kind_map: Map<Candles>,
+    aggregators: Map<CandleAggregator>,
+    phantom: PhantomData<(Exchange, Input)>,
+}
+
+#[async_trait]
+impl<Exchange, Input> ExchangeTransformer<Exchange, Candles>
+    for TradeCandleTransformer<Exchange, Input>
+where
+    Exchange: Connector + Send,
+    Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Send,
+    MarketIter<PublicTrade>: From<(ExchangeId, Instrument, Input)>,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+        kind_map: Map<Candles>,
+    ) -> Result<Self, DataError> {
+        let aggregators = kind_map
+            .0
+            .iter()
+            .map(|(subscription_id, kind)| {
+                let config = CandleAggregatorConfig::new(kind.interval, kind.clock);
+                let config = if kind.emit_empty {
+                    config.with_empty()
+                } else {
+                    config
+                };
+                (subscription_id.clone(), CandleAggregator::new(config))
+            })
+            .collect();
+
+        Ok(Self {
+            instrument_map,
+            kind_map,
+            aggregators,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<Exchange, Input> Transformer for TradeCandleTransformer<Exchange, Input>
+where
+    Exchange: Connector,
+    Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    MarketIter<PublicTrade>: From<(ExchangeId, Instrument, Input)>,
+{
+    type Error = DataError;
+    type Input = Input;
+    type Output = MarketEvent<Candle>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Determine if the message has an identifiable SubscriptionId
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Find Instrument & CandleAggregator associated with Input
+        let instrument = match self.instrument_map.find(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+        let aggregator = match self.aggregators.find_mut(&subscription_id) {
+            Ok(aggregator) => aggregator,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+        let in_progress = self
+            .kind_map
+            .0
+            .get(&subscription_id)
+            .is_some_and(|kind| kind.in_progress);
+
+        let mut candles = Vec::new();
+        for trade in MarketIter::<PublicTrade>::from((Exchange::ID, instrument, input)).0 {
+            let trade = match trade {
+                Ok(trade) => trade,
+                Err(error) => {
+                    candles.push(Err(error));
+                    continue;
+                }
+            };
+
+            let time = match aggregator.config().clock {
+                CandleClock::Exchange => trade.exchange_time,
+                CandleClock::WallClock => trade.received_time,
+            };
+            let closed = aggregator.update(time, &trade.kind);
+            let forming = aggregator.current().filter(|_| in_progress);
+
+            // Closed Candles are stamped with their close time, the forming Candle with the trade time
+            candles.extend(
+                closed
+                    .into_iter()
+                    .chain(forming.copied())
+                    .map(|candle| MarketEvent {
+                        exchange_time: candle.close_time.min(time),
+                        received_time: Utc::now(),
+                        exchange: trade.exchange.clone(),
+                        instrument: trade.instrument.clone(),
+                        kind: candle,
+                    })
+                    .map(Ok),
+            );
+        }
+
+        candles
+    }
+}
+
+impl<Exchange, Input> TimedTransformer for TradeCandleTransformer<Exchange, Input>
+where
+    Exchange: Connector,
+    Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    MarketIter<PublicTrade>: From<(ExchangeId, Instrument, Input)>,
+{
+    fn tick_interval(&self) -> Option<Duration> {
+        // Only CandleClock::WallClock Candles are closed without receiving further trades
+        self.aggregators
+            .0
+            .values()
+            .any(|aggregator| aggregator.config().clock == CandleClock::WallClock)
+            .then_some(WALL_CLOCK_TICK_INTERVAL)
+    }
+
+    fn tick(&mut self, now: DateTime<Utc>) -> Self::OutputIter {
+        self.aggregators
+            .0
+            .iter_mut()
+            .filter(|(_, aggregator)| aggregator.config().clock == CandleClock::WallClock)
+            .filter_map(|(subscription_id, aggregator)| {
+                self.instrument_map
+                    .0
+                    .get(subscription_id)
+                    .map(|instrument| (instrument, aggregator.poll(now)))
+            })
+            .flat_map(|(instrument, closed)| {
+                closed.into_iter().map(move |candle| MarketEvent {
+                    exchange_time: candle.close_time,
+                    received_time: now,
+                    exchange: Exchange::ID.into(),
+                    instrument: instrument.clone(),
+                    kind: candle,
+                })
+            })
+            .map(Ok)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::coinbase::{trade::CoinbaseTrade, Coinbase},
+        subscription::candle::CandleInterval,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_trade_candle_transformer_tick() {
+        struct TestCase {
+            kind: Candles,
+            tick_offset: chrono::Duration,
+            expected_tick_interval: Option<Duration>,
+            expected_trade_counts: Vec<u64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: CandleClock::Exchange Candles are not closed by ticks
+                kind: Candles::new(CandleInterval::Minute1),
+                tick_offset: chrono::Duration::minutes(1),
+                expected_tick_interval: None,
+                expected_trade_counts: vec![],
+            },
+            TestCase {
+                // TC1: CandleClock::WallClock Candle is closed once it's period has elapsed
+                kind: Candles::new(CandleInterval::Minute1).with_clock(CandleClock::WallClock),
+                tick_offset: chrono::Duration::minutes(1),
+                expected_tick_interval: Some(WALL_CLOCK_TICK_INTERVAL),
+                expected_trade_counts: vec![1],
+            },
+            TestCase {
+                // TC2: CandleClock::WallClock Candle is not closed before it's period has elapsed
+                kind: Candles::new(CandleInterval::Hour1).with_clock(CandleClock::WallClock),
+                tick_offset: chrono::Duration::zero(),
+                expected_tick_interval: Some(WALL_CLOCK_TICK_INTERVAL),
+                expected_trade_counts: vec![],
+            },
+            TestCase {
+                // TC3: CandleClock::WallClock w/ empty Candles closes trailing empty periods
+                kind: Candles::new(CandleInterval::Minute1)
+                    .with_clock(CandleClock::WallClock)
+                    .with_empty(),
+                tick_offset: chrono::Duration::minutes(3),
+                expected_tick_interval: Some(WALL_CLOCK_TICK_INTERVAL),
+                expected_trade_counts: vec![1, 0, 0],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let subscription_id = SubscriptionId::from("matches|BTC-USD");
+            let config = CandleAggregatorConfig::new(test.kind.interval, test.kind.clock);
+            let config = if test.kind.emit_empty {
+                config.with_empty()
+            } else {
+                config
+            };
+
+            let mut transformer = TradeCandleTransformer::<Coinbase, CoinbaseTrade> {
+                instrument_map: Map([(
+                    subscription_id.clone(),
+                    Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                )]
+                .into()),
+                kind_map: Map([(subscription_id.clone(), test.kind)].into()),
+                aggregators: Map([(subscription_id, CandleAggregator::new(config))].into()),
+                phantom: PhantomData,
+            };
+
+            let trade = serde_json::from_str::<CoinbaseTrade>(
+                r#"{"product_id":"BTC-USD","trade_id":1,"time":"2023-01-01T12:00:30Z","size":"1.0","price":"100.0","side":"buy"}"#,
+            )
+            .unwrap();
+            assert!(
+                transformer.transform(trade).is_empty(),
+                "TC{} failed",
+                index
+            );
+
+            assert_eq!(
+                transformer.tick_interval(),
+                test.expected_tick_interval,
+                "TC{} failed",
+                index
+            );
+
+            let actual = transformer
+                .tick(Utc::now() + test.tick_offset)
+                .into_iter()
+                .map(|candle| candle.unwrap().kind.trade_count)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected_trade_counts, "TC{} failed", index);
+        }
+    }
+}
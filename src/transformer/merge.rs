@@ -0,0 +1,200 @@
+use super::ExchangeTransformer;
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{Connector, ExchangeId},
+    subscription::{Map, SubKind},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::hash_map::Entry, marker::PhantomData};
+use tokio::sync::mpsc;
+
+/// Defines how to merge a partial exchange update, that only contains the fields that changed,
+/// into the latest known state.
+pub trait Merge {
+    /// Merge the `update` into [`Self`], overwriting every field the `update` contains.
+    fn merge(&mut self, update: Self);
+}
+
+/// Generic stateful [`ExchangeTransformer`] for exchange channels that send an initial snapshot
+/// followed by partial updates (eg/ Bybit tickers & Bitmex instrument).
+///
+/// Each update is [`Merge`]d into the latest state of the associated
+/// [`Subscription`](crate::subscription::Subscription), and the merged state is translated into
+/// normalised Barter types.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct MergeTransformer<Exchange, Kind, Input> {
+    instrument_map: Map<Instrument>,
+    state_map: Map<Input>,
+    phantom: PhantomData<(Exchange, Kind)>,
+}
+
+#[async_trait]
+impl<Exchange, Kind, Input> ExchangeTransformer<Exchange, Kind>
+    for MergeTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector + Send,
+    Kind: SubKind + Send,
+    Input: Identifier<Option<SubscriptionId>> + Merge + Clone + for<'de> Deserialize<'de> + Send,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input)>,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+        _: Map<Kind>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map,
+            state_map: Map(Default::default()),
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<Exchange, Kind, Input> Transformer for MergeTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+    Input: Identifier<Option<SubscriptionId>> + Merge + Clone + for<'de> Deserialize<'de>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input)>,
+{
+    type Error = DataError;
+    type Input = Input;
+    type Output = MarketEvent<Kind::Event>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Determine if the message has an identifiable SubscriptionId
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Find Instrument associated with Input
+        let instrument = match self.instrument_map.find(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // Merge Input into the latest state of the Subscription
+        let state = match self.state_map.0.entry(subscription_id) {
+            Entry::Occupied(mut state) => {
+                state.get_mut().merge(input);
+                state.get().clone()
+            }
+            Entry::Vacant(state) => state.insert(input).clone(),
+        };
+
+        MarketIter::<Kind::Event>::from((Exchange::ID, instrument, state)).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::coinbase::Coinbase;
+    use chrono::Utc;
+
+    #[derive(Clone, Debug, Deserialize)]
+    struct Update {
+        subscription_id: SubscriptionId,
+        price: Option<f64>,
+        amount: Option<f64>,
+    }
+
+    impl Identifier<Option<SubscriptionId>> for Update {
+        fn id(&self) -> Option<SubscriptionId> {
+            Some(self.subscription_id.clone())
+        }
+    }
+
+    impl Merge for Update {
+        fn merge(&mut self, update: Self) {
+            self.price = update.price.or(self.price);
+            self.amount = update.amount.or(self.amount);
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct State;
+
+    impl SubKind for State {
+        type Event = (Option<f64>, Option<f64>);
+    }
+
+    impl From<(ExchangeId, Instrument, Update)> for MarketIter<(Option<f64>, Option<f64>)> {
+        fn from((exchange_id, instrument, update): (ExchangeId, Instrument, Update)) -> Self {
+            Self(vec![Ok(MarketEvent {
+                exchange_time: Utc::now(),
+                received_time: Utc::now(),
+                exchange: exchange_id.into(),
+                instrument,
+                kind: (update.price, update.amount),
+            })])
+        }
+    }
+
+    #[test]
+    fn test_merge_transformer() {
+        struct TestCase {
+            input: Update,
+            expected: (Option<f64>, Option<f64>),
+        }
+
+        let subscription_id = SubscriptionId::from("tickers|BTCUSDT");
+        let update = |price, amount| Update {
+            subscription_id: subscription_id.clone(),
+            price,
+            amount,
+        };
+
+        let mut transformer = MergeTransformer::<Coinbase, State, Update> {
+            instrument_map: Map([(
+                subscription_id.clone(),
+                Instrument::from((
+                    "btc",
+                    "usdt",
+                    barter_integration::model::instrument::kind::InstrumentKind::Perpetual,
+                )),
+            )]
+            .into_iter()
+            .collect()),
+            state_map: Map(Default::default()),
+            phantom: PhantomData,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: initial snapshot is yielded as is
+                input: update(Some(100.0), Some(1.0)),
+                expected: (Some(100.0), Some(1.0)),
+            },
+            TestCase {
+                // TC1: partial update only overwrites the fields it contains
+                input: update(Some(101.0), None),
+                expected: (Some(101.0), Some(1.0)),
+            },
+            TestCase {
+                // TC2: subsequent partial update is merged into the merged state
+                input: update(None, Some(2.0)),
+                expected: (Some(101.0), Some(2.0)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.unwrap().kind)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, vec![test.expected], "TC{index} failed");
+        }
+    }
+}
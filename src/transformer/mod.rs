@@ -7,11 +7,20 @@ use async_trait::async_trait;
 use barter_integration::{
     model::instrument::Instrument, protocol::websocket::WsMessage, Transformer,
 };
+use chrono::{DateTime, Utc};
+use std::time::Duration;
 use tokio::sync::mpsc;
 
 /// Generic OrderBook [`ExchangeTransformer`]s.
 pub mod book;
 
+/// Generic Candle [`ExchangeTransformer`] that filters in-progress candle updates.
+pub mod candle;
+
+/// Generic stateful [`ExchangeTransformer`] that merges partial exchange updates into the latest
+/// known state before transforming.
+pub mod merge;
+
 /// Generic stateless [`ExchangeTransformer`] often used for transforming
 /// [`PublicTrades`](crate::subscription::trade::PublicTrades) streams.
 pub mod stateless;
@@ -27,8 +36,33 @@ where
     /// Construct a new [`Self`].
     ///
     /// The [`mpsc::UnboundedSender`] can be used by [`Self`] to send messages back to the exchange.
+    ///
+    /// The `kind_map` contains the [`SubKind`] configuration of each actioned
+    /// [`Subscription`](crate::subscription::Subscription), keyed by the [`SubscriptionId`]
+    /// generated by the [`SubscriptionMapper`](crate::subscriber::mapper::SubscriptionMapper).
+    ///
+    /// [`SubscriptionId`]: barter_integration::model::SubscriptionId
     async fn new(
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
         instrument_map: Map<Instrument>,
+        kind_map: Map<Kind>,
     ) -> Result<Self, DataError>;
 }
+
+/// [`Transformer`] that also generates outputs as time passes, independently of the messages
+/// received from the exchange (eg/ closing wall-clock [`Candle`]s when no trades are received).
+///
+/// Driven by a [`TimedExchangeWsStream`](crate::TimedExchangeWsStream).
+///
+/// [`Candle`]: crate::subscription::candle::Candle
+pub trait TimedTransformer
+where
+    Self: Transformer,
+{
+    /// Interval at which [`Self::tick`] should be called, or `None` if [`Self`] does not
+    /// require ticks.
+    fn tick_interval(&self) -> Option<Duration>;
+
+    /// Generate any outputs that are due at the provided time.
+    fn tick(&mut self, now: DateTime<Utc>) -> Self::OutputIter;
+}
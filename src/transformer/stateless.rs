@@ -38,6 +38,7 @@ where
     async fn new(
         _: mpsc::UnboundedSender<WsMessage>,
         instrument_map: Map<Instrument>,
+        _: Map<Kind>,
     ) -> Result<Self, DataError> {
         Ok(Self {
             instrument_map,